@@ -1,59 +1,394 @@
 use anyhow::{Result, anyhow};
-use reqwest::{self, header::{HeaderMap, HeaderName, HeaderValue}, Client, Method, StatusCode};
+use reqwest::{self, header::{HeaderMap, HeaderName, HeaderValue, AUTHORIZATION, USER_AGENT}, Client, Method, StatusCode};
 use serde::{Serialize, de::DeserializeOwned, Deserialize};
 use std::time::Duration;
-use std::{fs, io, path::{PathBuf, Path}};
+use std::{fs, io, io::Write, path::{PathBuf, Path}};
 use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::Instant;
+use console::style;
 use dirs;
+use crate::commands::audit::is_secret_arg;
+
+// How long metadata responses (environments, regions, ...) are reused by default
+const DEFAULT_METADATA_TTL_SECONDS: i64 = 30;
+
+// Statuses retried when the `retry_status_codes` setting is absent
+pub const DEFAULT_RETRY_STATUS_CODES: &[u16] = &[429, 500, 502, 503, 504];
+// Retries of a failing idempotent request when `max_retries` isn't configured
+const DEFAULT_MAX_RETRIES: u32 = 3;
+// First retry delay; each further retry doubles it, up to the cap
+const RETRY_BASE_DELAY_MS: u64 = 500;
+const RETRY_MAX_DELAY_MS: u64 = 10_000;
+
+fn default_max_retries() -> u32 {
+    DEFAULT_MAX_RETRIES
+}
+
+/// Delay before retry number `retry` (1-based): exponential backoff with up to
+/// 50% random jitter, so clients that failed together don't retry together
+pub fn retry_delay(retry: u32) -> Duration {
+    let backoff = RETRY_BASE_DELAY_MS
+        .saturating_mul(1 << retry.saturating_sub(1).min(16))
+        .min(RETRY_MAX_DELAY_MS);
+    let entropy = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.subsec_nanos() as u64);
+    Duration::from_millis(backoff + entropy % (backoff / 2 + 1))
+}
+
+/// A non-success response from the API. Callers that need to tell statuses
+/// apart (e.g. an endpoint the server doesn't have) can downcast to this.
+/// When the body is the usual `{ status, message, data }` envelope, the
+/// message and any field-level details are pulled out for display; the raw
+/// body is kept and shown with `-vv`.
+#[derive(Debug)]
+pub struct ApiError {
+    pub status: StatusCode,
+    pub body: String,
+    pub message: Option<String>,
+    pub details: Vec<String>,
+}
+
+impl std::fmt::Display for ApiError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.message {
+            Some(message) => {
+                write!(f, "{}", message)?;
+                if !self.details.is_empty() {
+                    write!(f, ": {}", self.details.join("; "))?;
+                }
+                write!(f, " ({})", self.status)
+            }
+            None => write!(f, "API error: {} - {}", self.status, self.body),
+        }
+    }
+}
+
+impl std::error::Error for ApiError {}
+
+impl ApiError {
+    pub fn new(status: StatusCode, body: String) -> Self {
+        let envelope = serde_json::from_str::<serde_json::Value>(&body).ok();
+        let message = envelope.as_ref()
+            .and_then(|envelope| envelope.get("message").or_else(|| envelope.get("error")))
+            .and_then(|message| message.as_str())
+            .map(str::trim)
+            .filter(|message| !message.is_empty())
+            .map(String::from);
+        let mut details = Vec::new();
+        if let Some(data) = envelope.as_ref().and_then(|envelope| envelope.get("data")) {
+            collect_error_details(data, None, &mut details);
+        }
+        Self { status, body, message, details }
+    }
+    
+    /// Status of `err` if it came from an API response
+    pub fn status_of(err: &anyhow::Error) -> Option<StatusCode> {
+        err.downcast_ref::<ApiError>().map(|err| err.status)
+    }
+}
+
+// Field-level problems in an error envelope's `data`, as "field: problem".
+// Servers send these as `{ field: problem }`, `{ errors: ... }`, or a list of
+// `{ field, message }` objects.
+fn collect_error_details(
+    data: &serde_json::Value,
+    field: Option<&str>,
+    details: &mut Vec<String>,
+) {
+    let prefixed = |text: &str| match field {
+        Some(field) => format!("{}: {}", field, text),
+        None => text.to_string(),
+    };
+    match data {
+        serde_json::Value::String(text) => details.push(prefixed(text)),
+        serde_json::Value::Array(items) => {
+            for item in items {
+                collect_error_details(item, field, details);
+            }
+        }
+        serde_json::Value::Object(fields) => {
+            if let Some(message) = fields.get("message").and_then(|m| m.as_str()) {
+                let field = fields.get("field").and_then(|f| f.as_str()).or(field);
+                details.push(match field {
+                    Some(field) => format!("{}: {}", field, message),
+                    None => message.to_string(),
+                });
+                return;
+            }
+            for (name, value) in fields {
+                let name = if name == "errors" { field } else { Some(name.as_str()) };
+                collect_error_details(value, name, details);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Replace the values of secret-looking fields (passwords, tokens, keys...)
+/// so request/response bodies can be printed with `-vv`
+pub fn redact_secrets(value: &mut serde_json::Value) {
+    match value {
+        serde_json::Value::Object(fields) => {
+            for (name, field) in fields.iter_mut() {
+                if is_secret_arg(name) && !field.is_null() {
+                    *field = serde_json::Value::String("<redacted>".to_string());
+                } else {
+                    redact_secrets(field);
+                }
+            }
+        }
+        serde_json::Value::Array(items) => items.iter_mut().for_each(redact_secrets),
+        _ => {}
+    }
+}
+
+fn bearer_header(api_key: &str) -> Result<HeaderValue> {
+    HeaderValue::from_str(&format!("Bearer {}", api_key))
+        .map_err(|_| anyhow!("API key contains characters not allowed in a header"))
+}
+
+// Body text for `-vv` output: JSON with secrets redacted, or the raw text
+fn redacted_body(body: &str) -> String {
+    match serde_json::from_str::<serde_json::Value>(body) {
+        Ok(mut value) => {
+            redact_secrets(&mut value);
+            value.to_string()
+        }
+        Err(_) => body.to_string(),
+    }
+}
+
+// Header value for `-vv` output. Credentials are masked, keeping the scheme
+// of an Authorization header, so traces are safe to paste into an issue.
+fn redacted_header(name: &HeaderName, value: &HeaderValue) -> String {
+    if *name == AUTHORIZATION {
+        let value = value.to_str().unwrap_or_default();
+        return match value.split_once(' ') {
+            Some((scheme, _)) => format!("{} <redacted>", scheme),
+            None => "<redacted>".to_string(),
+        };
+    }
+    if is_secret_arg(name.as_str()) {
+        return "<redacted>".to_string();
+    }
+    String::from_utf8_lossy(value.as_bytes()).into_owned()
+}
+
+/// Write `contents` to `path` atomically: the data goes to a temporary file in
+/// the same directory which is then renamed over the target, so an interrupted
+/// write never leaves a half-written file behind. Existing permissions are
+/// kept; new files are only readable by their owner.
+pub fn write_file_atomic(path: &Path, contents: &[u8]) -> io::Result<()> {
+    let dir = match path.parent() {
+        Some(dir) if !dir.as_os_str().is_empty() => dir,
+        _ => Path::new("."),
+    };
+    
+    let mut temp_file = tempfile::NamedTempFile::new_in(dir)?;
+    temp_file.write_all(contents)?;
+    temp_file.as_file().sync_all()?;
+    if let Ok(metadata) = fs::metadata(path) {
+        temp_file.as_file().set_permissions(metadata.permissions())?;
+    }
+    
+    temp_file.persist(path).map_err(|err| err.error)?;
+    Ok(())
+}
+
+/// An advisory lock on `<path>.lock`: exclusive for writers, shared for
+/// readers, so concurrent `omni` runs take turns with a config file. The lock
+/// lives beside `path` because atomic writes replace the file itself. Readers
+/// don't create the lock file; with none there is nothing to wait for, as
+/// nothing has written through a lock yet. Released when dropped.
+pub struct FileLock {
+    _file: Option<fs::File>,
+}
+
+impl FileLock {
+    pub fn acquire(path: &Path, exclusive: bool) -> io::Result<Self> {
+        let lock_path = PathBuf::from(format!("{}.lock", path.display()));
+        let lock_file = if exclusive {
+            fs::OpenOptions::new().create(true).truncate(false).write(true).open(&lock_path)
+        } else {
+            fs::File::open(&lock_path)
+        };
+        match lock_file {
+            Ok(lock_file) => {
+                if exclusive {
+                    lock_file.lock()?;
+                } else {
+                    lock_file.lock_shared()?;
+                }
+                Ok(Self { _file: Some(lock_file) })
+            }
+            Err(err) if exclusive && err.kind() != io::ErrorKind::NotFound => Err(err),
+            Err(_) => Ok(Self { _file: None }),
+        }
+    }
+}
+
+/// Run `f` holding a `FileLock` on `path`
+pub fn with_file_lock<T, E: From<io::Error>>(
+    path: &Path,
+    exclusive: bool,
+    f: impl FnOnce() -> Result<T, E>,
+) -> Result<T, E> {
+    let _lock = FileLock::acquire(path, exclusive)?;
+    f()
+}
+
+/// Parse a `retry_status_codes` setting: a list of HTTP status numbers
+pub fn parse_status_codes(value: &serde_json::Value) -> Result<Vec<StatusCode>, String> {
+    let items = value.as_array().ok_or("expected a list of status codes")?;
+    items.iter()
+        .map(|item| {
+            item.as_u64()
+                .and_then(|code| u16::try_from(code).ok())
+                .filter(|code| (100..=599).contains(code))
+                .and_then(|code| StatusCode::from_u16(code).ok())
+                .ok_or_else(|| format!("{} is not a valid HTTP status code", item))
+        })
+        .collect()
+}
+
+/// Check an API base URL and put it in the form requests are built from:
+/// an http(s) URL with a host and no trailing slash, since endpoints are
+/// appended as `/path`
+pub fn normalize_base_url(base_url: &str) -> Result<String> {
+    let trimmed = base_url.trim();
+    // `localhost:8002` would otherwise parse with "localhost" as the scheme
+    if !trimmed.contains("://") {
+        return Err(anyhow!(
+            "API URL '{}' has no scheme; use http://{} or https://{}",
+            base_url, trimmed, trimmed
+        ));
+    }
+    let url = reqwest::Url::parse(trimmed)
+        .map_err(|err| anyhow!("Invalid API URL '{}': {}", base_url, err))?;
+    if !matches!(url.scheme(), "http" | "https") {
+        return Err(anyhow!(
+            "API URL '{}' must use http or https, not {}",
+            base_url, url.scheme()
+        ));
+    }
+    if url.host_str().is_none_or(str::is_empty) {
+        return Err(anyhow!("API URL '{}' has no host", base_url));
+    }
+    if url.query().is_some() || url.fragment().is_some() {
+        return Err(anyhow!("API URL '{}' can't have a query or fragment", base_url));
+    }
+    if url.path().trim_end_matches('/').contains("//") {
+        return Err(anyhow!("API URL '{}' has an empty path segment", base_url));
+    }
+    Ok(url.as_str().trim_end_matches('/').to_string())
+}
 
 #[derive(Debug, Serialize, Deserialize, Default)]
 pub struct AppConfig {
     pub base_url: String,
     pub timeout_seconds: u64,
+    // Times an idempotent request is retried after a connection error or 5xx
+    #[serde(default = "default_max_retries")]
+    pub max_retries: u32,
+    // How long each kind of wait polls, in seconds, when --wait-timeout isn't
+    // given. Unset means the wait's own default.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bootstrap_timeout_seconds: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub service_timeout_seconds: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub backup_timeout_seconds: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rollback_timeout_seconds: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub push_timeout_seconds: Option<u64>,
     // Store arbitrary key-value pairs for different parts of the app
     #[serde(default)]
     pub settings: HashMap<String, serde_json::Value>,
 }
 
+impl AppConfig {
+    /// The configuration written when none exists yet
+    pub fn with_defaults() -> Self {
+        Self {
+            base_url: String::from("http://localhost:8002/api/v1"),
+            timeout_seconds: 30,
+            max_retries: DEFAULT_MAX_RETRIES,
+            bootstrap_timeout_seconds: None,
+            service_timeout_seconds: None,
+            backup_timeout_seconds: None,
+            rollback_timeout_seconds: None,
+            push_timeout_seconds: None,
+            settings: HashMap::new(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CachedResponse {
+    fetched_at: i64,
+    value: serde_json::Value,
+}
+
 pub struct ApiClient {
     pub client: Client,
     pub base_url: String,
     pub headers: HeaderMap,
     pub config_path: Option<PathBuf>,
     pub config: AppConfig,
+    // -v: request summaries, -vv: bodies too, -vvv: timing and retries
+    pub verbosity: u8,
+    // False with --no-config-write: config changes stay in memory for the run
+    pub config_writes: bool,
+    // Whether `config_path` existed and was read, rather than defaults used
+    pub config_loaded: bool,
+    metadata_cache: Mutex<HashMap<String, CachedResponse>>,
 }
 
 impl ApiClient {
     pub fn new() -> Self {
+        Self::load(true)
+    }
+    
+    /// Load the config if present, but never create directories or write files
+    pub fn without_config_writes() -> Self {
+        Self::load(false)
+    }
+    
+    fn load(config_writes: bool) -> Self {
         let mut headers = HeaderMap::new();
         headers.insert("Content-Type", HeaderValue::from_static("application/json"));
         
         let app_name = env!("CARGO_PKG_NAME");
         
         // Initialize with defaults
-        let mut config = AppConfig::default();
-        config.base_url = String::from("http://localhost:8002/api/v1");
-        config.timeout_seconds = 30;
+        let mut config = AppConfig::with_defaults();
+        let mut config_loaded = false;
         
         let config_path = dirs::config_dir().map(|config_dir| {
             let app_config_dir = config_dir.join(app_name);
             let config_file = app_config_dir.join("config.json");
             
             // Ensure the app config directory exists
-            Self::ensure_config_dir(&app_config_dir);
+            if config_writes {
+                Self::ensure_config_dir(&app_config_dir);
+            }
             
             // Load config if it exists, otherwise create default
             match Self::load_config(&config_file) {
                 Ok(loaded_config) => {
                     config = loaded_config;
-                    println!("Loaded configuration from {:?}", config_file);
+                    config_loaded = true;
                 },
+                Err(_) if !config_writes => {}
                 Err(_) => {
                     // Write default config
                     if let Err(err) = Self::write_config(&config_file, &config) {
                         eprintln!("Failed to write default config: {}", err);
                     } else {
-                        println!("Created default config at {:?}", config_file);
+                        eprintln!("Created default config at {:?}", config_file);
                     }
                 }
             }
@@ -65,14 +400,63 @@ impl ApiClient {
             .timeout(Duration::from_secs(config.timeout_seconds))
             .build()
             .expect("Failed to build HTTP client");
+        
+        // Identify CLI traffic to the server; the `user_agent` setting overrides it
+        let default_user_agent = format!(
+            "omni-cli/{} ({})",
+            env!("CARGO_PKG_VERSION"),
+            std::env::consts::OS
+        );
+        let user_agent = config.settings.get("user_agent")
+            .and_then(|value| value.as_str())
+            .and_then(|value| match HeaderValue::from_str(value) {
+                Ok(header) => Some(header),
+                Err(_) => {
+                    eprintln!("Ignoring invalid user_agent setting: {:?}", value);
+                    None
+                }
+            })
+            .unwrap_or_else(|| HeaderValue::from_str(&default_user_agent).expect("Invalid default user agent"));
+        headers.insert(USER_AGENT, user_agent);
+        
+        // Stay logged in across runs: `omni login` stores the token as `api_key`
+        if let Some(api_key) = config.settings.get("api_key").and_then(|value| value.as_str()) {
+            match bearer_header(api_key) {
+                Ok(header) => {
+                    headers.insert(AUTHORIZATION, header);
+                }
+                Err(_) => eprintln!("Ignoring invalid api_key setting"),
+            }
+        }
             
-        Self {
+        let base_url = normalize_base_url(&config.base_url).unwrap_or_else(|err| {
+            eprintln!("{}", style(format!("Warning: {}", err)).yellow());
+            config.base_url.clone()
+        });
+
+        let mut api_client = Self {
             client,
-            base_url: config.base_url.clone(),
+            base_url,
             headers,
             config_path,
             config,
+            verbosity: 0,
+            config_writes,
+            config_loaded,
+            metadata_cache: Mutex::new(HashMap::new()),
+        };
+        
+        // Pick up metadata cached by earlier invocations if persistence is on
+        if api_client.persist_metadata_cache() {
+            if let Some(cache) = api_client.metadata_cache_path()
+                .and_then(|path| fs::read_to_string(path).ok())
+                .and_then(|content| serde_json::from_str(&content).ok())
+            {
+                api_client.metadata_cache = Mutex::new(cache);
+            }
         }
+        
+        api_client
     }
     
     // Helper methods for configuration management
@@ -89,6 +473,11 @@ impl ApiClient {
             return Err(anyhow!("Config file doesn't exist"));
         }
         
+        with_file_lock(path, false, || Self::read_config(path))
+    }
+    
+    // Callers hold the config lock
+    fn read_config(path: &Path) -> Result<AppConfig> {
         let content = fs::read_to_string(path)?;
         let config = serde_json::from_str(&content)?;
         Ok(config)
@@ -96,6 +485,10 @@ impl ApiClient {
     
     // Renamed to avoid collision with instance method
     fn write_config(path: &Path, config: &AppConfig) -> io::Result<()> {
+        with_file_lock(path, true, || Self::write_config_unlocked(path, config))
+    }
+    
+    fn write_config_unlocked(path: &Path, config: &AppConfig) -> io::Result<()> {
         let json = serde_json::to_string_pretty(config)?;
         
         // Ensure parent directory exists
@@ -103,7 +496,33 @@ impl ApiClient {
             fs::create_dir_all(parent)?;
         }
         
-        fs::write(path, json)
+        write_file_atomic(path, json.as_bytes())
+    }
+    
+    /// Change the config and save it as one locked read-modify-write: the
+    /// file is re-read first, so changes other `omni` runs saved since this
+    /// one started aren't overwritten
+    pub fn update_config<T>(
+        &mut self,
+        update: impl FnOnce(&mut AppConfig) -> Result<T>,
+    ) -> Result<T> {
+        let path = match &self.config_path {
+            Some(path) if self.config_writes => path.clone(),
+            _ => {
+                let result = update(&mut self.config)?;
+                self.save_config()?;
+                return Ok(result);
+            }
+        };
+        with_file_lock(&path, true, || {
+            if let Ok(on_disk) = Self::read_config(&path) {
+                self.config = on_disk;
+            }
+            let result = update(&mut self.config)?;
+            Self::write_config_unlocked(&path, &self.config)
+                .map_err(|e| anyhow!("Failed to save config: {}", e))?;
+            Ok(result)
+        })
     }
     
     // Key-value storage methods
@@ -122,24 +541,30 @@ impl ApiClient {
     /// Set a setting value
     pub fn set_setting<T: Serialize>(&mut self, key: &str, value: T) -> Result<()> {
         let json_value = serde_json::to_value(value)?;
-        self.config.settings.insert(key.to_string(), json_value);
-        
-        // Save the updated config
-        self.save_config()
+        self.update_config(|config| {
+            config.settings.insert(key.to_string(), json_value);
+            Ok(())
+        })
     }
     
     /// Remove a setting
     pub fn remove_setting(&mut self, key: &str) -> bool {
-        let removed = self.config.settings.remove(key).is_some();
-        if removed {
-            // Only save if something was actually removed
-            let _ = self.save_config();
+        if !self.config.settings.contains_key(key) {
+            return false;
         }
-        removed
+        // Only save if something was actually removed
+        let removed = self.update_config(|config| Ok(config.settings.remove(key).is_some()));
+        removed.unwrap_or_else(|_| {
+            self.config.settings.remove(key);
+            true
+        })
     }
     
     /// Save the current configuration to disk
     pub fn save_config(&self) -> Result<()> {
+        if !self.config_writes {
+            return Ok(());
+        }
         if let Some(config_path) = &self.config_path {
             Self::write_config(config_path, &self.config)
                 .map_err(|e| anyhow!("Failed to save config: {}", e))?;
@@ -157,13 +582,102 @@ impl ApiClient {
             .collect()
     }
     
+    /// Remove all settings, or only those whose key starts with `prefix`
+    pub fn clear_settings(&mut self, prefix: Option<&str>) -> Result<usize> {
+        self.update_config(|config| {
+            let keys: Vec<String> = config.settings.keys()
+                .filter(|key| prefix.is_none_or(|prefix| key.starts_with(prefix)))
+                .cloned()
+                .collect();
+            for key in &keys {
+                config.settings.remove(key);
+            }
+            Ok(keys.len())
+        })
+    }
+    
+    // Metadata cache methods
+    
+    fn metadata_ttl(&self) -> i64 {
+        self.get_setting_or("metadata_cache.ttl_seconds", DEFAULT_METADATA_TTL_SECONDS)
+    }
+    
+    fn persist_metadata_cache(&self) -> bool {
+        self.config_writes && self.get_setting_or("metadata_cache.persist", false)
+    }
+    
+    fn metadata_cache_path(&self) -> Option<PathBuf> {
+        self.config_path.as_ref()
+            .and_then(|path| path.parent())
+            .map(|dir| dir.join("metadata-cache.json"))
+    }
+    
+    /// GET an idempotent metadata endpoint, reusing a recent response when available
+    pub async fn get_cached<U>(&self, endpoint: &str) -> Result<U> 
+    where 
+        U: DeserializeOwned,
+    {
+        let now = chrono::Utc::now().timestamp();
+        let cached = self.metadata_cache.lock().unwrap()
+            .get(endpoint)
+            .filter(|entry| now - entry.fetched_at < self.metadata_ttl())
+            .map(|entry| entry.value.clone());
+        
+        let value = match cached {
+            Some(value) => value,
+            None => {
+                let value = self.get::<serde_json::Value>(endpoint).await?;
+                let mut cache = self.metadata_cache.lock().unwrap();
+                cache.insert(endpoint.to_string(), CachedResponse {
+                    fetched_at: now,
+                    value: value.clone(),
+                });
+                
+                if self.persist_metadata_cache() {
+                    if let (Some(path), Ok(json)) = (self.metadata_cache_path(), serde_json::to_string(&*cache)) {
+                        // The cache is an optimization; failing to persist it is not an error
+                        let _ = fs::write(path, json);
+                    }
+                }
+                value
+            }
+        };
+        
+        Ok(serde_json::from_value(value)?)
+    }
+    
+    /// Drop all cached metadata so the next lookups hit the server
+    pub fn refresh_metadata(&self) {
+        self.metadata_cache.lock().unwrap().clear();
+        if let Some(path) = self.metadata_cache_path().filter(|_| self.config_writes) {
+            if path.exists() {
+                let _ = fs::remove_file(path);
+            }
+        }
+    }
+    
+    /// Statuses that make `request` retry an idempotent request. An invalid
+    /// `retry_status_codes` setting is reported and the defaults are used.
+    pub fn retry_status_codes(&self) -> Vec<StatusCode> {
+        if let Some(value) = self.config.settings.get("retry_status_codes") {
+            match parse_status_codes(value) {
+                Ok(codes) => return codes,
+                Err(problem) => eprintln!("Ignoring invalid retry_status_codes setting: {}", problem),
+            }
+        }
+        DEFAULT_RETRY_STATUS_CODES.iter()
+            .filter_map(|&code| StatusCode::from_u16(code).ok())
+            .collect()
+    }
+    
     // Builder methods
-    pub fn with_base_url(mut self, base_url: &str) -> Self {
-        self.base_url = base_url.to_string();
-        self.config.base_url = base_url.to_string();
+    pub fn with_base_url(mut self, base_url: &str) -> Result<Self> {
+        let base_url = normalize_base_url(base_url)?;
+        self.base_url = base_url.clone();
+        self.config.base_url = base_url;
         // Ignore errors during chain building
         let _ = self.save_config();
-        self
+        Ok(self)
     }
     
     pub fn with_timeout(mut self, seconds: u64) -> Self {
@@ -178,16 +692,35 @@ impl ApiClient {
     }
     
     pub fn with_api_key(mut self, api_key: &str) -> Self {
-        self.headers.insert(
-            "Authorization", 
-            HeaderValue::from_str(&format!("Bearer {}", api_key))
-                .expect("Invalid API key format")
-        );
-        // Store API key in settings
-        let _ = self.set_setting("api_key", api_key);
+        self.set_api_key(api_key).expect("Invalid API key format");
         self
     }
     
+    /// Authenticate requests with `api_key` and store it for later runs
+    pub fn set_api_key(&mut self, api_key: &str) -> Result<()> {
+        let header = bearer_header(api_key)?;
+        self.headers.insert(AUTHORIZATION, header);
+        self.set_setting("api_key", api_key)
+    }
+    
+    /// Forget the stored API key; false if there wasn't one
+    pub fn clear_api_key(&mut self) -> bool {
+        self.headers.remove(AUTHORIZATION);
+        self.remove_setting("api_key")
+    }
+    
+    pub fn with_verbosity(mut self, verbosity: u8) -> Self {
+        self.verbosity = verbosity;
+        self
+    }
+    
+    // Verbose output goes to stderr so it never mixes with command output
+    fn trace(&self, level: u8, message: String) {
+        if self.verbosity >= level {
+            eprintln!("{}", style(message).dim());
+        }
+    }
+    
     pub fn with_header(mut self, key: &str, value: &str) -> Self {
         self.headers.insert(
             HeaderName::from_bytes(key.as_bytes()).expect("Invalid header name"), 
@@ -196,31 +729,98 @@ impl ApiClient {
         self
     }
     
-    // HTTP Request methods (unchanged)
+    /// Attempts made for an idempotent request: the first plus `max_retries`
+    pub fn max_attempts(&self) -> u32 {
+        self.config.max_retries.saturating_add(1)
+    }
+    
+    // HTTP Request methods
     pub async fn request<T, U>(&self, method: Method, endpoint: &str, body: Option<&T>) -> Result<U> 
+    where 
+        T: Serialize + ?Sized,
+        U: DeserializeOwned,
+    {
+        // Only requests that are safe to repeat are retried
+        let idempotent = matches!(method, Method::GET | Method::HEAD | Method::PUT | Method::DELETE);
+        self.send_request(method, endpoint, body, idempotent).await
+    }
+    
+    async fn send_request<T, U>(
+        &self,
+        method: Method,
+        endpoint: &str,
+        body: Option<&T>,
+        idempotent: bool,
+    ) -> Result<U> 
     where 
         T: Serialize + ?Sized,
         U: DeserializeOwned,
     {
         let url = format!("{}{}", self.base_url, endpoint);
+        let max_attempts = if idempotent { self.max_attempts() } else { 1 };
+        let mut attempt = 1;
         
-        let mut request = self.client.request(method, &url);
-        request = request.headers(self.headers.clone());
-        
-        if let Some(data) = body {
-            request = request.json(data);
-        }
-        
-        let response = request.send().await?;
-        
-        match response.status() {
-            StatusCode::OK | StatusCode::CREATED | StatusCode::ACCEPTED => {
-                let data = response.json::<U>().await?;
-                Ok(data)
-            },
-            status => {
-                let error_text = response.text().await?;
-                Err(anyhow!("API error: {} - {}", status, error_text))
+        loop {
+            let mut request = self.client.request(method.clone(), &url);
+            request = request.headers(self.headers.clone());
+            
+            if let Some(data) = body {
+                request = request.json(data);
+                if self.verbosity >= 2 {
+                    let mut data = serde_json::to_value(data)?;
+                    redact_secrets(&mut data);
+                    self.trace(2, format!("  request body: {}", data));
+                }
+            }
+            
+            self.trace(1, format!("→ {} {}", method, url));
+            if self.verbosity >= 2 {
+                for (name, value) in &self.headers {
+                    self.trace(2, format!("  {}: {}", name, redacted_header(name, value)));
+                }
+            }
+            let started = Instant::now();
+            let response = match request.send().await {
+                Ok(response) => response,
+                Err(err) if (err.is_connect() || err.is_timeout()) && attempt < max_attempts => {
+                    let delay = retry_delay(attempt);
+                    self.trace(3, format!(
+                        "  retrying after {} in {} ms (attempt {}/{})",
+                        err, delay.as_millis(), attempt + 1, max_attempts
+                    ));
+                    tokio::time::sleep(delay).await;
+                    attempt += 1;
+                    continue;
+                }
+                Err(err) => return Err(err.into()),
+            };
+            let status = response.status();
+            self.trace(1, format!("← {} {}", status, url));
+            self.trace(3, format!("  took {} ms", started.elapsed().as_millis()));
+            
+            match status {
+                StatusCode::OK | StatusCode::CREATED | StatusCode::ACCEPTED => {
+                    let text = response.text().await?;
+                    self.trace(2, format!("  response body: {}", redacted_body(&text)));
+                    let data = serde_json::from_str::<U>(&text)?;
+                    return Ok(data);
+                },
+                status if attempt < max_attempts
+                    && self.retry_status_codes().contains(&status) =>
+                {
+                    let delay = retry_delay(attempt);
+                    self.trace(3, format!(
+                        "  retrying after {} in {} ms (attempt {}/{})",
+                        status, delay.as_millis(), attempt + 1, max_attempts
+                    ));
+                    tokio::time::sleep(delay).await;
+                    attempt += 1;
+                },
+                status => {
+                    let error_text = response.text().await?;
+                    self.trace(2, format!("  response body: {}", redacted_body(&error_text)));
+                    return Err(ApiError::new(status, error_text).into());
+                }
             }
         }
     }
@@ -241,6 +841,15 @@ impl ApiClient {
         self.request::<T, U>(Method::POST, endpoint, Some(body)).await
     }
     
+    /// POST that the caller knows is safe to repeat, so it's retried like GET
+    pub async fn post_idempotent<T, U>(&self, endpoint: &str, body: &T) -> Result<U> 
+    where 
+        T: Serialize + ?Sized,
+        U: DeserializeOwned,
+    {
+        self.send_request::<T, U>(Method::POST, endpoint, Some(body), true).await
+    }
+    
     pub async fn put<T, U>(&self, endpoint: &str, body: &T) -> Result<U> 
     where 
         T: Serialize + ?Sized,
@@ -263,4 +872,37 @@ impl ApiClient {
     {
         self.request::<T, U>(Method::PATCH, endpoint, Some(body)).await
     }
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalize_base_url_strips_trailing_slashes() {
+        assert_eq!(
+            normalize_base_url("http://localhost:8002/api/v1/").unwrap(),
+            "http://localhost:8002/api/v1"
+        );
+        assert_eq!(
+            normalize_base_url(" https://omni.example.com/// ").unwrap(),
+            "https://omni.example.com"
+        );
+    }
+
+    #[test]
+    fn normalize_base_url_requires_a_scheme() {
+        let err = normalize_base_url("localhost:8002/api/v1").unwrap_err();
+        assert!(err.to_string().contains("has no scheme"), "{}", err);
+        assert!(normalize_base_url("ftp://localhost:8002").is_err());
+    }
+
+    #[test]
+    fn normalize_base_url_keeps_path_segments() {
+        assert_eq!(
+            normalize_base_url("http://localhost:8002/omni/api/v1").unwrap(),
+            "http://localhost:8002/omni/api/v1"
+        );
+        assert!(normalize_base_url("http://localhost:8002/api//v1").is_err());
+        assert!(normalize_base_url("http://localhost:8002/api/v1?debug=1").is_err());
+    }
+}
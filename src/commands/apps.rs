@@ -0,0 +1,356 @@
+use crate::commands::init_env::{ApiResponse, ServiceAction, StatusData};
+use crate::commands::logs::{LogFormat, LogView};
+use crate::models::AppSummary;
+use crate::ui::PremiumUI;
+use anyhow::{anyhow, Context, Result};
+use console::style;
+use dialoguer::{Confirm, Select};
+use tabled::{Table, Tabled};
+
+// One row of the `apps restart` summary
+#[derive(Tabled)]
+struct RestartRow {
+    #[tabled(rename = "Host")]
+    host: String,
+    #[tabled(rename = "Service")]
+    service: String,
+    #[tabled(rename = "Result")]
+    result: String,
+}
+
+// How restarting one service went
+#[derive(Debug, Clone)]
+enum RestartOutcome {
+    // Not attempted, after an earlier rolling restart failed
+    Skipped,
+    Restarted,
+    Failed(String),
+}
+
+impl RestartOutcome {
+    fn from_result(result: Result<()>) -> Self {
+        match result {
+            Ok(()) => RestartOutcome::Restarted,
+            Err(err) => RestartOutcome::Failed(format!("{:#}", err)),
+        }
+    }
+
+    fn describe(&self) -> String {
+        match self {
+            RestartOutcome::Skipped => "Skipped".to_string(),
+            RestartOutcome::Restarted => "Restarted".to_string(),
+            RestartOutcome::Failed(error) => format!("Failed: {}", error),
+        }
+    }
+}
+
+// App names end up in hostnames, so they follow DNS label rules
+fn validate_app_name(name: &str) -> Result<(), String> {
+    if name.is_empty() || name.len() > 63 {
+        return Err("must be between 1 and 63 characters".to_string());
+    }
+    if !name
+        .chars()
+        .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+    {
+        return Err("may only contain lowercase letters, digits and '-'".to_string());
+    }
+    if !name.starts_with(|c: char| c.is_ascii_lowercase()) {
+        return Err("must start with a lowercase letter".to_string());
+    }
+    if name.ends_with('-') {
+        return Err("must not end with '-'".to_string());
+    }
+    Ok(())
+}
+
+impl PremiumUI {
+    // Names of all apps known to the platform
+    pub async fn list_app_names(&self) -> Result<Vec<String>> {
+        let response = self
+            .api_client
+            .get::<ApiResponse>("/apps")
+            .await
+            .context("Failed to list apps")?;
+        match self.parse_status_data::<Vec<AppSummary>>(response.data) {
+            StatusData::Available(apps) => Ok(apps.into_iter().map(|app| app.name).collect()),
+            StatusData::Missing => Ok(Vec::new()),
+            StatusData::Malformed => Err(anyhow!("Unexpected server response for app list")),
+        }
+    }
+
+    // The app named on the command line, or one picked from the deployed apps
+    pub async fn select_app(&self, app: Option<&str>, prompt: &str) -> Result<String> {
+        if let Some(app) = app {
+            return Ok(app.to_string());
+        }
+        let apps = self.list_app_names().await?;
+        if apps.is_empty() {
+            return Err(anyhow!("No apps have been deployed yet"));
+        }
+        let index = Select::with_theme(&self.theme)
+            .with_prompt(prompt)
+            .items(&apps)
+            .default(0)
+            .interact()?;
+        Ok(apps[index].clone())
+    }
+
+    pub async fn apps_rename(&self, old: &str, new: &str, yes: bool) -> Result<()> {
+        if old == new {
+            return Err(anyhow!("'{}' already has that name", old));
+        }
+        validate_app_name(new)
+            .map_err(|problem| anyhow!("Invalid app name '{}': {}", new, problem))?;
+
+        let names = self.list_app_names().await?;
+        if !names.iter().any(|name| name == old) {
+            return Err(anyhow!("App '{}' not found", old));
+        }
+        if names.iter().any(|name| name == new) {
+            return Err(anyhow!("An app named '{}' already exists", new));
+        }
+
+        println!(
+            "{}",
+            style(format!(
+                "⚠️  Endpoints and URLs derived from '{}' may change after the rename.",
+                old
+            ))
+            .yellow()
+        );
+        if !yes {
+            let confirmed = Confirm::with_theme(&self.theme)
+                .with_prompt(format!("Rename '{}' to '{}'?", old, new))
+                .default(false)
+                .interact()?;
+            if !confirmed {
+                println!("{}", style("Rename cancelled.").yellow());
+                return Ok(());
+            }
+        }
+
+        let mut spinner = self.create_spinner(&format!("Renaming {}...", old));
+        let result = self
+            .api_client
+            .patch::<_, ApiResponse>(
+                &format!("/apps/{}", old),
+                &serde_json::json!({ "name": new }),
+            )
+            .await;
+        spinner.stop();
+        result.with_context(|| format!("Failed to rename '{}'", old))?;
+
+        println!(
+            "{}",
+            style(format!("✅ Renamed '{}' to '{}'", old, new)).green()
+        );
+        Ok(())
+    }
+
+    // (host, service) pairs running `app`, or only its `component`, taken
+    // from the app's placements
+    async fn app_service_targets(
+        &self,
+        app: &str,
+        component: Option<&str>,
+    ) -> Result<Vec<(String, String)>> {
+        let state = self
+            .fetch_app_state(app)
+            .await
+            .ok_or_else(|| anyhow!("Couldn't fetch app {}; check the name with the server", app))?;
+
+        let components: Vec<_> = match component {
+            Some(wanted) => {
+                let matching: Vec<_> = state
+                    .components
+                    .iter()
+                    .filter(|c| c.name.eq_ignore_ascii_case(wanted))
+                    .collect();
+                if matching.is_empty() {
+                    let names: Vec<&str> =
+                        state.components.iter().map(|c| c.name.as_str()).collect();
+                    return Err(anyhow!(
+                        "{} has no component '{}'. Components: {}",
+                        app,
+                        wanted,
+                        names.join(", ")
+                    ));
+                }
+                matching
+            }
+            None => state.components.iter().collect(),
+        };
+
+        let mut targets: Vec<(String, String)> = components
+            .iter()
+            .flat_map(|c| c.placements.iter())
+            .map(|placement| (placement.host.clone(), placement.service.clone()))
+            .collect();
+        targets.sort();
+        targets.dedup();
+        Ok(targets)
+    }
+
+    // Tail every service that runs `app` (or one of its components), found
+    // from the app's placements rather than named by the user
+    pub async fn apps_logs(
+        &self,
+        app: &str,
+        component: Option<&str>,
+        tail: usize,
+        parallel: usize,
+    ) -> Result<()> {
+        let targets = self.app_service_targets(app, component).await?;
+        if targets.is_empty() {
+            println!(
+                "{}",
+                style(format!("No running services found for {}.", app)).yellow()
+            );
+            return Ok(());
+        }
+
+        let config = self.load_cloud_config()?;
+        let title = match component {
+            Some(component) => format!("📋 Logs of {}/{}", app, component),
+            None => format!("📋 Logs of {}", app),
+        };
+        let view = LogView {
+            tail: Some(tail),
+            since: None,
+            format: LogFormat::Text,
+            grep: None,
+            context: 0,
+        };
+        self.print_merged_logs(&config.cloud_name, targets, &title, parallel, &view)
+            .await
+    }
+
+    // Restart every service running `app` (or one of its components) and
+    // report how each went. With `rolling`, services restart one at a time,
+    // each waiting until it's running again; the first failure stops the
+    // rollout. Otherwise all restarts are sent before waiting on any.
+    pub async fn apps_restart(
+        &self,
+        app: &str,
+        component: Option<&str>,
+        rolling: bool,
+        yes: bool,
+    ) -> Result<()> {
+        let targets = self.app_service_targets(app, component).await?;
+        if targets.is_empty() {
+            println!(
+                "{}",
+                style(format!("No running services found for {}.", app)).yellow()
+            );
+            return Ok(());
+        }
+
+        println!(
+            "\n{}",
+            style(format!("🔄 Services running {}", app)).cyan().bold()
+        );
+        for (host, service) in &targets {
+            println!("  {} on {}", style(service).green(), host);
+        }
+        if !yes {
+            let confirmed = Confirm::with_theme(&self.theme)
+                .with_prompt(format!(
+                    "Restart {} service(s){}?",
+                    targets.len(),
+                    if rolling { " one at a time" } else { "" }
+                ))
+                .default(false)
+                .interact()?;
+            if !confirmed {
+                println!("{}", style("Restart cancelled.").yellow());
+                return Ok(());
+            }
+        }
+
+        let config = self.load_cloud_config()?;
+        let cloud_name = config.cloud_name.as_str();
+        let mut outcomes = vec![RestartOutcome::Skipped; targets.len()];
+
+        if rolling {
+            for (index, (host, service)) in targets.iter().enumerate() {
+                println!(
+                    "\n{}",
+                    style(format!("Restarting {} on {}", service, host)).cyan()
+                );
+                let result = match self
+                    .send_service_action(cloud_name, host, service, ServiceAction::Restart)
+                    .await
+                {
+                    Ok(_) => {
+                        self.wait_for_service_state(
+                            cloud_name,
+                            host,
+                            service,
+                            ServiceAction::Restart,
+                        )
+                        .await
+                    }
+                    Err(err) => Err(err),
+                };
+                outcomes[index] = RestartOutcome::from_result(result);
+                if matches!(outcomes[index], RestartOutcome::Failed(_)) {
+                    break;
+                }
+            }
+        } else {
+            let mut sent = Vec::new();
+            for (index, (host, service)) in targets.iter().enumerate() {
+                match self
+                    .send_service_action(cloud_name, host, service, ServiceAction::Restart)
+                    .await
+                {
+                    Ok(_) => sent.push(index),
+                    Err(err) => outcomes[index] = RestartOutcome::from_result(Err(err)),
+                }
+            }
+            for index in sent {
+                let (host, service) = &targets[index];
+                println!(
+                    "\n{}",
+                    style(format!("Waiting for {} on {}", service, host)).cyan()
+                );
+                outcomes[index] = RestartOutcome::from_result(
+                    self.wait_for_service_state(cloud_name, host, service, ServiceAction::Restart)
+                        .await,
+                );
+            }
+        }
+
+        let failed = outcomes
+            .iter()
+            .filter(|outcome| matches!(outcome, RestartOutcome::Failed(_)))
+            .count();
+        let rows: Vec<RestartRow> = targets
+            .iter()
+            .zip(outcomes)
+            .map(|((host, service), outcome)| RestartRow {
+                host: host.clone(),
+                service: service.clone(),
+                result: outcome.describe(),
+            })
+            .collect();
+
+        println!("\n{}", style("📋 Restart Summary").cyan().bold());
+        println!("{}", self.render_table(Table::new(rows)));
+
+        if failed > 0 {
+            return Err(anyhow!(
+                "{} of {} services of {} failed to restart",
+                failed,
+                targets.len(),
+                app
+            ));
+        }
+        println!(
+            "{}",
+            style(format!("✅ Restarted all services of {}", app)).green()
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,254 @@
+use crate::ui::PremiumUI;
+use anyhow::{Context, Result};
+use clap::parser::ValueSource;
+use clap::ArgMatches;
+use console::style;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::PathBuf;
+use tabled::{Table, Tabled};
+
+const AUDIT_FILE_NAME: &str = "audit.jsonl";
+
+// Commands that change remote or local state. These are audited, and
+// `omni watch` refuses to re-run them.
+const MUTATING_COMMANDS: &[&str] = &[
+    "init",
+    "login",
+    "logout",
+    "up",
+    "push",
+    "scale",
+    "rollback",
+    "apps rename",
+    "apps restart",
+    "host add",
+    "host remove",
+    "host edit",
+    "backup now",
+    "backup restore",
+    "prune releases",
+    "prune backups",
+    "service restart",
+    "service stop",
+    "service start",
+    "config edit",
+    "config reset",
+    "config edit-host",
+    "config push",
+    "config set",
+    "config unset",
+    "config clear-settings",
+    "config set-default-env",
+    "config set-secret",
+    "config unset-secret",
+    "registry login",
+    "registry logout",
+    "registry set-default",
+];
+
+// Argument names whose values must never be written to the audit log
+const SECRET_ARG_MARKERS: &[&str] = &["password", "secret", "token", "key", "credential"];
+
+// One line of the audit log
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AuditEntry {
+    pub timestamp: String,
+    pub command: String,
+    #[serde(default)]
+    pub cloud: Option<String>,
+    #[serde(default)]
+    pub target: Option<String>,
+    #[serde(default)]
+    pub args: BTreeMap<String, String>,
+    pub result: String,
+    #[serde(default)]
+    pub error: Option<String>,
+}
+
+// An audited invocation, captured before the command runs
+pub struct AuditedCommand {
+    command: String,
+    target: Option<String>,
+    args: BTreeMap<String, String>,
+}
+
+#[derive(Tabled)]
+struct AuditEntryDisplay {
+    #[tabled(rename = "Time")]
+    timestamp: String,
+    #[tabled(rename = "Command")]
+    command: String,
+    #[tabled(rename = "Cloud")]
+    cloud: String,
+    #[tabled(rename = "Target")]
+    target: String,
+    #[tabled(rename = "Result")]
+    result: String,
+}
+
+impl From<&AuditEntry> for AuditEntryDisplay {
+    fn from(entry: &AuditEntry) -> Self {
+        Self {
+            timestamp: entry.timestamp.clone(),
+            command: entry.command.clone(),
+            cloud: entry.cloud.clone().unwrap_or_else(|| "-".to_string()),
+            target: entry.target.clone().unwrap_or_else(|| "-".to_string()),
+            result: match &entry.error {
+                Some(error) => format!("{}: {}", entry.result, error),
+                None => entry.result.clone(),
+            },
+        }
+    }
+}
+
+pub fn is_secret_arg(name: &str) -> bool {
+    let name = name.to_lowercase();
+    SECRET_ARG_MARKERS
+        .iter()
+        .any(|marker| name.contains(marker))
+}
+
+// Follow nested subcommands down to the innermost one, returning its full
+// path (e.g. "service restart") and matches
+pub fn leaf_command(cli: &ArgMatches) -> (String, &ArgMatches) {
+    let mut path = Vec::new();
+    let mut matches = cli;
+    while let Some((name, sub_matches)) = matches.subcommand() {
+        path.push(name);
+        matches = sub_matches;
+    }
+    (path.join(" "), matches)
+}
+
+pub fn is_mutating_command(cli: &ArgMatches) -> bool {
+    let (command, _) = leaf_command(cli);
+    MUTATING_COMMANDS.contains(&command.as_str())
+}
+
+// Work out whether an invocation should be audited, capturing the full
+// subcommand path, its target and the explicitly given arguments
+pub fn audited_command(cli: &ArgMatches) -> Option<AuditedCommand> {
+    let (command, matches) = leaf_command(cli);
+    if !MUTATING_COMMANDS.contains(&command.as_str()) {
+        return None;
+    }
+
+    let mut args = BTreeMap::new();
+    for id in matches.ids() {
+        let id = id.as_str();
+        if matches.value_source(id) != Some(ValueSource::CommandLine) {
+            continue;
+        }
+        let Ok(Some(raw)) = matches.try_get_raw(id) else {
+            continue;
+        };
+        let value = if is_secret_arg(id) {
+            "[REDACTED]".to_string()
+        } else {
+            raw.map(|v| v.to_string_lossy())
+                .collect::<Vec<_>>()
+                .join(",")
+        };
+        args.insert(id.to_string(), value);
+    }
+
+    let target = match (args.get("host"), args.get("service")) {
+        (Some(host), Some(service)) => Some(format!("{}/{}", host, service)),
+        _ => ["id", "component", "version", "environment", "app", "path", "url"]
+            .iter()
+            .find_map(|key| args.get(*key).cloned()),
+    };
+
+    Some(AuditedCommand {
+        command,
+        target,
+        args,
+    })
+}
+
+impl PremiumUI {
+    // No audit log is kept with --no-config-write
+    fn audit_log_path(&self) -> Option<PathBuf> {
+        self.api_client
+            .config_path
+            .as_ref()
+            .filter(|_| self.api_client.config_writes)
+            .and_then(|path| path.parent())
+            .map(|dir| dir.join(AUDIT_FILE_NAME))
+    }
+
+    // Append the outcome of an audited command. Failing to write the log is
+    // reported but never fails the command itself.
+    pub fn record_audit<T>(&self, audited: AuditedCommand, result: &Result<T>) {
+        let Some(path) = self.audit_log_path() else {
+            return;
+        };
+
+        let entry = AuditEntry {
+            timestamp: chrono::Utc::now().to_rfc3339(),
+            command: audited.command,
+            cloud: self.load_cloud_config().ok().map(|c| c.cloud_name),
+            target: audited.target,
+            args: audited.args,
+            result: if result.is_ok() { "success" } else { "failed" }.to_string(),
+            error: result.as_ref().err().map(|err| err.to_string()),
+        };
+
+        let write = || -> Result<()> {
+            let mut file = OpenOptions::new().create(true).append(true).open(&path)?;
+            writeln!(file, "{}", serde_json::to_string(&entry)?)?;
+            Ok(())
+        };
+        if let Err(err) = write() {
+            eprintln!(
+                "{}",
+                style(format!(
+                    "Warning: failed to write audit log {}: {}",
+                    path.display(),
+                    err
+                ))
+                .yellow()
+            );
+        }
+    }
+
+    // Show the most recent audit log entries
+    pub async fn audit_log(&self, limit: usize) -> Result<()> {
+        let path = self
+            .audit_log_path()
+            .context("Could not determine the config directory")?;
+        if !path.exists() {
+            if self.output.is_structured() {
+                println!("{}", self.output.render::<[AuditEntry]>(&[])?.trim_end());
+            } else {
+                println!("{}", style("No audited operations recorded yet.").yellow());
+            }
+            return Ok(());
+        }
+
+        let contents = fs::read_to_string(&path).context("Failed to read audit log")?;
+        let entries: Vec<AuditEntry> = contents
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .filter_map(|line| serde_json::from_str(line).ok())
+            .collect();
+        let skip = entries.len().saturating_sub(limit);
+        if self.output.is_structured() {
+            println!("{}", self.output.render(&entries[skip..])?.trim_end());
+            return Ok(());
+        }
+
+        println!("\n{}", style("📜 Audit Log").cyan().bold());
+        println!("{}", style(path.display()).dim());
+        let display: Vec<AuditEntryDisplay> = entries[skip..]
+            .iter()
+            .map(AuditEntryDisplay::from)
+            .collect();
+        println!("{}", self.render_table(Table::new(display)));
+
+        Ok(())
+    }
+}
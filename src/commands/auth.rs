@@ -0,0 +1,107 @@
+use crate::api_client::ApiError;
+use crate::commands::context::response_field;
+use crate::ui::PremiumUI;
+use anyhow::{anyhow, Context, Result};
+use console::style;
+use dialoguer::{Input, Password};
+use reqwest::StatusCode;
+use serde_json::{json, Value};
+
+impl PremiumUI {
+    // `login`: store an API token, either given directly or obtained by
+    // logging in with a username and password
+    pub async fn login(&mut self, token: Option<&str>, username: Option<&str>) -> Result<()> {
+        let token = match token {
+            Some(token) => token.to_string(),
+            None => self.request_token(username).await?,
+        };
+
+        let previous = self.api_client.get_setting::<String>("api_key");
+        self.api_client.set_api_key(&token)?;
+
+        // Check the token before reporting success, and don't keep one the
+        // server rejects
+        match self.api_client.get::<Value>("/auth/whoami").await {
+            Ok(response) => {
+                let identity =
+                    response_field(&response, "username").unwrap_or_else(|| "authenticated".into());
+                println!(
+                    "{}",
+                    style(format!(
+                        "✅ Logged in to {} as {}",
+                        self.api_client.base_url, identity
+                    ))
+                    .green()
+                );
+            }
+            Err(err)
+                if matches!(
+                    ApiError::status_of(&err),
+                    Some(StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN)
+                ) =>
+            {
+                match previous {
+                    Some(previous) => self.api_client.set_api_key(&previous)?,
+                    None => {
+                        self.api_client.clear_api_key();
+                    }
+                }
+                return Err(anyhow!("The server rejected the token; nothing was saved"));
+            }
+            Err(err) => {
+                println!(
+                    "{}",
+                    style(format!(
+                        "✅ Token saved, but it couldn't be checked: {}",
+                        err
+                    ))
+                    .yellow()
+                );
+            }
+        }
+        Ok(())
+    }
+
+    // Exchange a username and password for a token via `/auth/login`
+    async fn request_token(&self, username: Option<&str>) -> Result<String> {
+        println!(
+            "\n{}",
+            style(format!("🔐 Log in to {}", self.api_client.base_url))
+                .cyan()
+                .bold()
+        );
+        let username = match username {
+            Some(username) => username.to_string(),
+            None => Input::<String>::with_theme(&self.theme)
+                .with_prompt("Username")
+                .interact_text()?,
+        };
+        let password = Password::with_theme(&self.theme)
+            .with_prompt("Password")
+            .interact()?;
+
+        let response = self
+            .api_client
+            .post::<_, Value>(
+                "/auth/login",
+                &json!({ "username": username, "password": password }),
+            )
+            .await
+            .context("Login failed")?;
+        response_field(&response, "token")
+            .ok_or_else(|| anyhow!("Login failed: the server returned no token"))
+    }
+
+    // `logout`: forget the stored API token
+    pub fn logout(&mut self) -> Result<()> {
+        if self.api_client.clear_api_key() {
+            println!(
+                "{}",
+                style(format!("✅ Logged out of {}", self.api_client.base_url)).green()
+            );
+        } else {
+            println!("{}", style("Not logged in.").yellow());
+        }
+        Ok(())
+    }
+}
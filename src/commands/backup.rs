@@ -0,0 +1,697 @@
+use crate::api_client::ApiError;
+use crate::commands::init_env::{ApiResponse, StatusData};
+use crate::commands::wait::WaitKind;
+use crate::ui::PremiumUI;
+use anyhow::{anyhow, bail, Context, Result};
+use console::style;
+use dialoguer::Confirm;
+use indicatif::{HumanBytes, ProgressBar};
+use reqwest::header::{ACCEPT_RANGES, CONTENT_RANGE, RANGE};
+use reqwest::StatusCode;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::fs::{self, File, OpenOptions};
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+use tabled::{Table, Tabled};
+use tokio::time::Duration;
+
+// Attempts made to finish a download before giving up
+const MAX_DOWNLOAD_ATTEMPTS: u32 = 5;
+// Server-side verification reads the whole archive, so allow it some time
+const VERIFY_TIMEOUT: Duration = Duration::from_secs(5 * 60);
+// Restores rewrite the platform's state and can run much longer
+const RESTORE_TIMEOUT: Duration = Duration::from_secs(30 * 60);
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BackupMetadata {
+    pub id: String,
+    #[serde(default)]
+    pub size_bytes: Option<u64>,
+    #[serde(default)]
+    pub sha256: Option<String>,
+    #[serde(default)]
+    pub created_at: Option<String>,
+    // When the retention policy will delete the backup
+    #[serde(default)]
+    pub expires_at: Option<String>,
+}
+
+#[derive(Tabled)]
+struct BackupDisplay {
+    #[tabled(rename = "ID")]
+    id: String,
+    #[tabled(rename = "Created")]
+    created_at: String,
+    #[tabled(rename = "Size")]
+    size: String,
+    #[tabled(rename = "Expires")]
+    expires_at: String,
+}
+
+impl From<&BackupMetadata> for BackupDisplay {
+    fn from(backup: &BackupMetadata) -> Self {
+        let or_dash = |value: &Option<String>| value.clone().unwrap_or_else(|| "-".into());
+        Self {
+            id: backup.id.clone(),
+            created_at: or_dash(&backup.created_at),
+            size: backup
+                .size_bytes
+                .map_or("-".into(), |bytes| HumanBytes(bytes).to_string()),
+            expires_at: or_dash(&backup.expires_at),
+        }
+    }
+}
+
+// Result of a server-side restorability check
+#[derive(Debug, Default, Deserialize)]
+pub struct BackupVerification {
+    #[serde(default)]
+    pub restorable: bool,
+    #[serde(default)]
+    pub corrupt_components: Vec<String>,
+    #[serde(default)]
+    pub missing_components: Vec<String>,
+    #[serde(default)]
+    pub issues: Vec<String>,
+}
+
+// Compute the hex SHA-256 digest of a file without loading it into memory
+pub fn file_sha256(path: &Path) -> Result<String> {
+    let mut file =
+        File::open(path).with_context(|| format!("Failed to open {}", path.display()))?;
+    let mut hasher = Sha256::new();
+    let mut buffer = [0u8; 64 * 1024];
+    loop {
+        let read = file.read(&mut buffer)?;
+        if read == 0 {
+            break;
+        }
+        hasher.update(&buffer[..read]);
+    }
+    Ok(format!("{:x}", hasher.finalize()))
+}
+
+// Check a local archive against the backup's recorded size and checksum
+pub fn verify_backup_file(path: &Path, metadata: &BackupMetadata) -> Result<()> {
+    let actual_size = fs::metadata(path)?.len();
+    if let Some(expected_size) = metadata.size_bytes {
+        if actual_size != expected_size {
+            bail!(
+                "size mismatch: expected {} bytes, found {}",
+                expected_size,
+                actual_size
+            );
+        }
+    }
+
+    if let Some(expected_sha) = &metadata.sha256 {
+        let actual_sha = file_sha256(path)?;
+        if !actual_sha.eq_ignore_ascii_case(expected_sha) {
+            bail!(
+                "checksum mismatch: expected {}, found {}",
+                expected_sha,
+                actual_sha
+            );
+        }
+    }
+
+    Ok(())
+}
+
+fn file_len(path: &Path) -> u64 {
+    fs::metadata(path).map(|m| m.len()).unwrap_or(0)
+}
+
+// Whether a failed download is worth resuming: the connection dropped or
+// timed out, or the server had a 5xx. Anything else would fail again.
+fn is_transient_download_error(err: &anyhow::Error) -> bool {
+    if let Some(status) = ApiError::status_of(err) {
+        return status.is_server_error();
+    }
+    err.downcast_ref::<reqwest::Error>()
+        .is_some_and(|err| err.is_connect() || err.is_timeout() || err.is_body())
+}
+
+impl PremiumUI {
+    // All backups of a cloud, in whatever order the server returns them
+    pub async fn list_backups(&self, cloud_name: &str) -> Result<Vec<BackupMetadata>> {
+        let response = self
+            .api_client
+            .get::<ApiResponse>(&format!("/platforms/{}/backups", cloud_name))
+            .await?;
+        match self.parse_status_data::<Vec<BackupMetadata>>(response.data) {
+            StatusData::Available(backups) => Ok(backups),
+            StatusData::Missing => Ok(Vec::new()),
+            StatusData::Malformed => Err(anyhow!("Unexpected server response for backup list")),
+        }
+    }
+
+    // `backup list`: the cloud's backups, newest first
+    pub async fn backup_list(&self) -> Result<()> {
+        let config = self.load_cloud_config()?;
+        let mut backups = self.list_backups(&config.cloud_name).await?;
+        backups.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+        if self.output.is_structured() {
+            println!("{}", self.output.render(&backups)?.trim_end());
+            return Ok(());
+        }
+        if backups.is_empty() {
+            println!(
+                "{}",
+                style(format!("No backups found for {}.", config.cloud_name)).yellow()
+            );
+            return Ok(());
+        }
+
+        println!(
+            "\n{}",
+            style(format!("💾 Backups of {}", config.cloud_name))
+                .cyan()
+                .bold()
+        );
+        println!(
+            "{}",
+            self.render_table(Table::new(backups.iter().map(BackupDisplay::from)))
+        );
+        Ok(())
+    }
+
+    // `backup restore`: replace the platform's state with a backup, after
+    // confirming, and follow the restore until it finishes
+    pub async fn restore_backup(&self, backup_id: &str) -> Result<()> {
+        let config = self.load_cloud_config()?;
+
+        let confirm = Confirm::with_theme(&self.theme)
+            .with_prompt(format!(
+                "⚠️  Restore {} from backup {}? This overwrites its current state.",
+                config.cloud_name, backup_id
+            ))
+            .default(false)
+            .interact()?;
+        if !confirm {
+            println!("{}", style("Restore cancelled").yellow());
+            return Ok(());
+        }
+
+        println!(
+            "\n{}",
+            style(format!("♻️  Restoring backup {}", backup_id))
+                .cyan()
+                .bold()
+        );
+
+        let response = self
+            .api_client
+            .post::<_, ApiResponse>(
+                &format!(
+                    "/platforms/{}/backups/{}/restore",
+                    config.cloud_name, backup_id
+                ),
+                &(),
+            )
+            .await
+            .with_context(|| format!("Failed to start restoring backup {}", backup_id))?;
+        println!("{}", style("Restore initiated ✓").green());
+        println!(
+            "{}",
+            style(format!("API response: {}", response.message)).green()
+        );
+
+        self.wait_for_restore_completion(&config.cloud_name, backup_id)
+            .await
+    }
+
+    // Poll the restore status endpoint, showing progress, until the server
+    // reports the restore finished or failed
+    async fn wait_for_restore_completion(&self, cloud_name: &str, backup_id: &str) -> Result<()> {
+        let mut progress_bar: Option<ProgressBar> = None;
+
+        let started = Instant::now();
+        let mut last_status: Option<serde_json::Value> = None;
+
+        self.info("Monitoring restore progress...");
+
+        let deadline = self.wait_deadline_or(WaitKind::Backup, RESTORE_TIMEOUT);
+        while Instant::now() < deadline {
+            match self
+                .api_client
+                .get::<ApiResponse>(&format!(
+                    "/platforms/{}/backups/{}/restore/status",
+                    cloud_name, backup_id
+                ))
+                .await
+            {
+                Ok(response) if response.status == "completed" => {
+                    if let Some(pb) = progress_bar.take() {
+                        pb.finish_and_clear();
+                    }
+                    println!(
+                        "{}",
+                        style("Restore completed successfully! ✓").green().bold()
+                    );
+                    return Ok(());
+                }
+                Ok(response) if response.status == "failed" => {
+                    if let Some(pb) = progress_bar.take() {
+                        pb.abandon_with_message("Server reported failure ✗");
+                    }
+                    bail!(
+                        "The server reported that the restore of backup {} failed: {}",
+                        backup_id,
+                        response.message
+                    );
+                }
+                Ok(response) => {
+                    if let Some(data) = response.data {
+                        self.update_transfer_progress(&mut progress_bar, &data, "Restoring");
+                        last_status = Some(data);
+                    }
+                }
+                Err(err) => {
+                    if self.debug {
+                        println!(
+                            "{}",
+                            style(format!("Error checking restore status: {}", err)).dim()
+                        );
+                    }
+                }
+            }
+
+            tokio::time::sleep(Duration::from_secs(1)).await;
+        }
+
+        if let Some(pb) = progress_bar.take() {
+            pb.abandon_with_message("Timed out locally");
+        }
+        Err(self.wait_timed_out(
+            &format!("the restore of backup {}", backup_id),
+            started.elapsed(),
+            last_status.as_ref(),
+        ))
+    }
+
+    // Fetch a backup's recorded metadata (size, checksum)
+    pub async fn fetch_backup_metadata(
+        &self,
+        cloud_name: &str,
+        backup_id: &str,
+    ) -> Result<BackupMetadata> {
+        let response = self
+            .api_client
+            .get::<ApiResponse>(&format!("/platforms/{}/backups/{}", cloud_name, backup_id))
+            .await?;
+        match self.parse_status_data::<BackupMetadata>(response.data) {
+            StatusData::Available(metadata) => Ok(metadata),
+            StatusData::Missing => Err(anyhow!("No metadata available for backup '{}'", backup_id)),
+            StatusData::Malformed => Err(anyhow!(
+                "Unexpected server response for backup '{}'",
+                backup_id
+            )),
+        }
+    }
+
+    // Ask the server to check that a backup is intact and restorable, and
+    // optionally check a downloaded copy of it against the recorded checksum
+    pub async fn verify_backup(&self, backup_id: &str, file: Option<&str>) -> Result<()> {
+        let config = self.load_cloud_config()?;
+
+        println!(
+            "\n{}",
+            style(format!("🔍 Verifying backup {}", backup_id))
+                .cyan()
+                .bold()
+        );
+
+        let mut local_ok = true;
+        if let Some(file) = file {
+            let path = self.resolve_path(file);
+            let metadata = self
+                .fetch_backup_metadata(&config.cloud_name, backup_id)
+                .await?;
+            if metadata.size_bytes.is_none() && metadata.sha256.is_none() {
+                println!(
+                    "{}",
+                    style("⚠️  Server has no recorded size or checksum; skipping local check")
+                        .yellow()
+                );
+            } else {
+                match verify_backup_file(&path, &metadata) {
+                    Ok(()) => println!(
+                        "{}",
+                        style(format!(
+                            "✓ Local archive {} matches the backup",
+                            path.display()
+                        ))
+                        .green()
+                    ),
+                    Err(err) => {
+                        local_ok = false;
+                        println!(
+                            "{}",
+                            style(format!("✗ Local archive {}: {}", path.display(), err)).red()
+                        );
+                    }
+                }
+            }
+        }
+
+        // Starting a verification twice is harmless, so it can be retried
+        let response = self
+            .api_client
+            .post_idempotent::<_, ApiResponse>(
+                &format!(
+                    "/platforms/{}/backups/{}/verify",
+                    config.cloud_name, backup_id
+                ),
+                &(),
+            )
+            .await?;
+        if self.debug {
+            println!(
+                "{}",
+                style(format!("API response: {}", response.message)).dim()
+            );
+        }
+
+        let report = self
+            .wait_for_backup_verification(&config.cloud_name, backup_id)
+            .await?;
+
+        let remote_ok = report.restorable
+            && report.corrupt_components.is_empty()
+            && report.missing_components.is_empty();
+
+        if remote_ok {
+            println!(
+                "{}",
+                style("✓ Server reports the backup is intact and restorable")
+                    .green()
+                    .bold()
+            );
+        } else {
+            println!(
+                "{}",
+                style("✗ Server reports the backup is not restorable")
+                    .red()
+                    .bold()
+            );
+            for component in &report.corrupt_components {
+                println!("  {} {}", style("corrupt:").red(), component);
+            }
+            for component in &report.missing_components {
+                println!("  {} {}", style("missing:").red(), component);
+            }
+        }
+        for issue in &report.issues {
+            println!("  {} {}", style("•").yellow(), issue);
+        }
+
+        if !remote_ok || !local_ok {
+            bail!("Backup {} failed verification", backup_id);
+        }
+        Ok(())
+    }
+
+    // Poll the verification status endpoint until the server reports a result
+    async fn wait_for_backup_verification(
+        &self,
+        cloud_name: &str,
+        backup_id: &str,
+    ) -> Result<BackupVerification> {
+        let mut spinner = self.create_spinner("Waiting for the server to verify the backup...");
+        let started = Instant::now();
+        let mut last_status: Option<serde_json::Value> = None;
+
+        let deadline = self.wait_deadline_or(WaitKind::Backup, VERIFY_TIMEOUT);
+        while Instant::now() < deadline {
+            match self
+                .api_client
+                .get::<ApiResponse>(&format!(
+                    "/platforms/{}/backups/{}/verify",
+                    cloud_name, backup_id
+                ))
+                .await
+            {
+                Ok(response) if response.status == "completed" || response.status == "failed" => {
+                    spinner.stop_with_message("Verification finished".to_string());
+                    return match self.parse_status_data::<BackupVerification>(response.data) {
+                        StatusData::Available(report) => Ok(report),
+                        StatusData::Missing if response.status == "failed" => {
+                            Ok(BackupVerification {
+                                issues: vec![response.message],
+                                ..Default::default()
+                            })
+                        }
+                        StatusData::Missing | StatusData::Malformed => Err(anyhow!(
+                            "Unexpected verification result from server: {}",
+                            response.message
+                        )),
+                    };
+                }
+                Ok(response) => {
+                    last_status = Some(serde_json::json!({
+                        "status": response.status,
+                        "message": response.message,
+                    }));
+                }
+                Err(err) => {
+                    if self.debug {
+                        println!(
+                            "{}",
+                            style(format!("Error checking verification status: {}", err)).dim()
+                        );
+                    }
+                }
+            }
+
+            tokio::time::sleep(Duration::from_secs(1)).await;
+        }
+
+        spinner.stop_with_message("Verification timed out locally".to_string());
+        Err(self.wait_timed_out(
+            &format!("verification of backup {}", backup_id),
+            started.elapsed(),
+            last_status.as_ref(),
+        ))
+    }
+
+    // Download a backup archive, resuming interrupted transfers and verifying
+    // the result against the server's recorded size and checksum
+    pub async fn download_backup(&self, backup_id: &str, output: Option<&str>) -> Result<()> {
+        let config = self.load_cloud_config()?;
+        let metadata = self
+            .fetch_backup_metadata(&config.cloud_name, backup_id)
+            .await?;
+
+        let output_path = self.resolve_path(
+            output
+                .map(String::from)
+                .unwrap_or_else(|| format!("backup-{}.tar.gz", backup_id)),
+        );
+        let part_path = PathBuf::from(format!("{}.part", output_path.display()));
+
+        println!(
+            "\n{}",
+            style(format!("💾 Downloading backup {}", metadata.id))
+                .cyan()
+                .bold()
+        );
+        if file_len(&part_path) > 0 {
+            self.info(format!(
+                "Resuming partial download at {} bytes",
+                file_len(&part_path)
+            ));
+        }
+
+        let resumed_from = self
+            .download_backup_file(&config.cloud_name, backup_id, &part_path, &metadata)
+            .await?;
+
+        if let Err(err) = verify_backup_file(&part_path, &metadata) {
+            // A resumed download is most likely corrupt after the point it
+            // resumed from, so only that portion is fetched again
+            println!(
+                "{}",
+                style(format!(
+                    "⚠️  Integrity check failed ({}). Re-downloading from byte {}...",
+                    err, resumed_from
+                ))
+                .yellow()
+            );
+            OpenOptions::new()
+                .write(true)
+                .open(&part_path)?
+                .set_len(resumed_from)?;
+            self.download_backup_file(&config.cloud_name, backup_id, &part_path, &metadata)
+                .await?;
+            verify_backup_file(&part_path, &metadata)
+                .context("Downloaded backup failed its integrity check")?;
+        }
+
+        fs::rename(&part_path, &output_path).context("Failed to move downloaded backup")?;
+
+        println!(
+            "{}",
+            style(format!(
+                "✓ Backup saved to {} (integrity verified)",
+                output_path.display()
+            ))
+            .green()
+            .bold()
+        );
+        Ok(())
+    }
+
+    // Download into `part_path`, resuming from its current length and retrying
+    // on interruption. Returns the offset this download resumed from.
+    async fn download_backup_file(
+        &self,
+        cloud_name: &str,
+        backup_id: &str,
+        part_path: &Path,
+        metadata: &BackupMetadata,
+    ) -> Result<u64> {
+        let url = format!(
+            "{}/platforms/{}/backups/{}/download",
+            self.api_client.base_url, cloud_name, backup_id
+        );
+        let resumed_from = file_len(part_path);
+        let mut progress_bar: Option<ProgressBar> = None;
+        let mut attempts = 0;
+
+        loop {
+            attempts += 1;
+            let offset = file_len(part_path);
+            if metadata.size_bytes.is_some_and(|total| offset >= total) {
+                break;
+            }
+
+            match self
+                .fetch_backup_range(
+                    &url,
+                    part_path,
+                    offset,
+                    metadata.size_bytes,
+                    &mut progress_bar,
+                )
+                .await
+            {
+                Ok(()) => break,
+                Err(err)
+                    if attempts < MAX_DOWNLOAD_ATTEMPTS && is_transient_download_error(&err) =>
+                {
+                    let message = format!(
+                        "Download interrupted ({}), resuming (attempt {}/{})...",
+                        err,
+                        attempts + 1,
+                        MAX_DOWNLOAD_ATTEMPTS
+                    );
+                    match &progress_bar {
+                        Some(pb) => pb.println(style(message).yellow().to_string()),
+                        None => println!("{}", style(message).yellow()),
+                    }
+                    tokio::time::sleep(Duration::from_secs(2u64.pow(attempts - 1))).await;
+                }
+                Err(err) => {
+                    if let Some(pb) = progress_bar.take() {
+                        pb.abandon_with_message("Download failed!");
+                    }
+                    return Err(err.context("Backup download failed"));
+                }
+            }
+        }
+
+        if let Some(pb) = progress_bar.take() {
+            pb.finish_with_message("Download complete ✓");
+        }
+        Ok(resumed_from)
+    }
+
+    // Request the archive from `offset` onwards and append it to `part_path`
+    async fn fetch_backup_range(
+        &self,
+        url: &str,
+        part_path: &Path,
+        offset: u64,
+        total_bytes: Option<u64>,
+        progress_bar: &mut Option<ProgressBar>,
+    ) -> Result<()> {
+        // The shared client's timeout covers the whole body, which a large
+        // backup can't download within; only bound the connect here
+        let client = reqwest::Client::builder()
+            .connect_timeout(Duration::from_secs(self.api_client.config.timeout_seconds))
+            .build()?;
+        let mut request = client.get(url).headers(self.api_client.headers.clone());
+        if offset > 0 {
+            request = request.header(RANGE, format!("bytes={}-", offset));
+        }
+
+        let mut response = request.send().await?;
+        let resuming = match response.status() {
+            StatusCode::PARTIAL_CONTENT => {
+                // Make sure the server resumed where we asked it to
+                let content_range = response
+                    .headers()
+                    .get(CONTENT_RANGE)
+                    .and_then(|v| v.to_str().ok())
+                    .unwrap_or_default()
+                    .to_string();
+                if !content_range.starts_with(&format!("bytes {}-", offset)) {
+                    bail!("server returned unexpected range '{}'", content_range);
+                }
+                true
+            }
+            StatusCode::OK => {
+                if offset > 0 {
+                    let supports_ranges = response
+                        .headers()
+                        .get(ACCEPT_RANGES)
+                        .and_then(|v| v.to_str().ok())
+                        .is_some_and(|v| v.contains("bytes"));
+                    let reason = if supports_ranges {
+                        "Server ignored the resume request"
+                    } else {
+                        "Server does not support resuming downloads"
+                    };
+                    println!(
+                        "{}",
+                        style(format!("{}; restarting from the beginning", reason)).dim()
+                    );
+                }
+                false
+            }
+            // Nothing left past `offset`; integrity verification decides if it's complete
+            StatusCode::RANGE_NOT_SATISFIABLE => return Ok(()),
+            status => {
+                let body = response.text().await.unwrap_or_default();
+                return Err(ApiError::new(status, body).into());
+            }
+        };
+
+        let start = if resuming { offset } else { 0 };
+        let mut file = if resuming {
+            OpenOptions::new().append(true).open(part_path)?
+        } else {
+            File::create(part_path)?
+        };
+
+        let total = total_bytes.or_else(|| response.content_length().map(|len| len + start));
+        let pb = progress_bar.get_or_insert_with(|| {
+            self.create_bytes_progress_bar(total.unwrap_or(0), "Downloading backup")
+        });
+        if let Some(total) = total {
+            pb.set_length(total);
+        }
+        pb.set_position(start);
+
+        while let Some(chunk) = response.chunk().await? {
+            file.write_all(&chunk)?;
+            pb.inc(chunk.len() as u64);
+        }
+        file.flush()?;
+
+        Ok(())
+    }
+}
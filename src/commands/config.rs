@@ -1,68 +1,521 @@
+use crate::api_client::{
+    normalize_base_url, parse_status_codes, with_file_lock, write_file_atomic, AppConfig,
+};
+use crate::commands::audit::is_secret_arg;
+use crate::commands::host::{check_bastion_kept, find_host};
+use crate::commands::init_env::{validate_ssh_host, ApiResponse, CloudConfig, SshHost, StatusData};
+use crate::commands::up::{find_environment, DEFAULT_ENVIRONMENT_SETTING};
+use crate::commands::wait::WaitKind;
 use crate::ui::PremiumUI;
-use anyhow::Result;
+use anyhow::{anyhow, Context, Result};
 use console::style;
 use dialoguer::Confirm;
-use std::{thread, time::Duration};
+use serde::Deserialize;
+use std::fs;
+use std::path::Path;
+
+// Editor for `config edit` when neither $VISUAL nor $EDITOR is set
+#[cfg(windows)]
+const DEFAULT_EDITOR: &str = "notepad";
+#[cfg(not(windows))]
+const DEFAULT_EDITOR: &str = "vi";
+
+// Changes the server would make for `config push`, from a dry run
+#[derive(Debug, Default, Deserialize)]
+struct ConfigPlan {
+    #[serde(default)]
+    hosts_to_add: Vec<String>,
+    #[serde(default)]
+    hosts_to_remove: Vec<String>,
+    #[serde(default)]
+    services_to_restart: Vec<String>,
+    #[serde(default)]
+    downtime_estimate: Option<String>,
+}
+
+impl ConfigPlan {
+    fn is_empty(&self) -> bool {
+        self.hosts_to_add.is_empty()
+            && self.hosts_to_remove.is_empty()
+            && self.services_to_restart.is_empty()
+    }
+}
+
+// Every host but the one at `index`
+fn other_hosts(hosts: &[SshHost], index: usize) -> Vec<SshHost> {
+    hosts
+        .iter()
+        .enumerate()
+        .filter(|(i, _)| *i != index)
+        .map(|(_, host)| host.clone())
+        .collect()
+}
 
 impl PremiumUI {
+    // Show the CLI configuration in effect, with secrets masked
     pub async fn config_view(&self) -> Result<()> {
-        let mut spinner = self.create_spinner("Loading configuration...");
-        thread::sleep(Duration::from_secs(1));
-        spinner.stop();
-
         println!("\n{}", style("📝 Application Configuration").cyan().bold());
+        match &self.api_client.config_path {
+            Some(path) if path.exists() => {
+                println!("Config file: {}", style(path.display()).green())
+            }
+            Some(path) => println!(
+                "Config file: {} {}",
+                style(path.display()).yellow(),
+                style("(not written yet, showing defaults)").dim()
+            ),
+            None => println!(
+                "Config file: {}",
+                style("none (no config directory on this system)").yellow()
+            ),
+        }
+        if self.api_client.base_url != self.api_client.config.base_url {
+            println!(
+                "API URL: {} {}",
+                style(&self.api_client.base_url).green(),
+                style("(overridden for this run)").dim()
+            );
+        }
+        println!(
+            "Cloud config: {}",
+            style(self.cloud_config_path().display()).green()
+        );
 
-        // Simulate YAML config
-        let config = r#"
-environment: production
-components:
-  frontend:
-    replicas: 3
-    resources:
-      cpu: 150m
-      memory: 256Mi
-  backend:
-    replicas: 2
-    resources:
-      cpu: 200m
-      memory: 512Mi
-  database:
-    replicas: 1
-    resources:
-      cpu: 500m
-      memory: 1Gi
-"#;
-        println!("{}", config);
+        let mut config = serde_json::to_value(&self.api_client.config)?;
+        if let Some(settings) = config
+            .get_mut("settings")
+            .and_then(|settings| settings.as_object_mut())
+        {
+            for (key, value) in settings.iter_mut() {
+                if is_secret_arg(key) {
+                    *value = serde_json::Value::from("[REDACTED]");
+                }
+            }
+        }
+        println!("\n{}", serde_json::to_string_pretty(&config)?);
+
+        println!("\n{}", style("🌐 Client Settings").cyan().bold());
+        let retry_status_codes: Vec<String> = self
+            .api_client
+            .retry_status_codes()
+            .iter()
+            .map(|status| status.as_u16().to_string())
+            .collect();
+        println!(
+            "Retry status codes: {}",
+            style(retry_status_codes.join(", ")).green()
+        );
         Ok(())
     }
 
+    // Open the config file in $VISUAL/$EDITOR. The edit happens on a copy that
+    // only replaces the real file once it parses, so a typo can't break the CLI.
     pub async fn config_edit(&self) -> Result<()> {
         println!("\n{}", style("✏️  Edit Configuration").cyan().bold());
+        if !self.api_client.config_writes {
+            return Err(anyhow!(
+                "Can't edit the configuration with --no-config-write"
+            ));
+        }
+        let path = self
+            .api_client
+            .config_path
+            .clone()
+            .context("No config directory available on this system")?;
+        let original = match fs::read_to_string(&path) {
+            Ok(contents) => contents,
+            Err(_) => serde_json::to_string_pretty(&self.api_client.config)?,
+        };
+
+        let draft = tempfile::Builder::new()
+            .prefix("omni-config-")
+            .suffix(".json")
+            .tempfile()
+            .context("Failed to create a temporary file to edit")?;
+        fs::write(draft.path(), &original)?;
+
+        let editor = std::env::var("VISUAL")
+            .or_else(|_| std::env::var("EDITOR"))
+            .unwrap_or_else(|_| DEFAULT_EDITOR.to_string());
+        let mut editor_parts = editor.split_whitespace();
+        let program = editor_parts
+            .next()
+            .ok_or_else(|| anyhow!("$EDITOR is empty"))?;
+        let editor_args: Vec<&str> = editor_parts.collect();
+
+        loop {
+            self.info(format!("Opening {} in {}...", path.display(), program));
+            let status = std::process::Command::new(program)
+                .args(&editor_args)
+                .arg(draft.path())
+                .status()
+                .with_context(|| format!("Failed to launch editor '{}'", program))?;
+            if !status.success() {
+                println!(
+                    "{}",
+                    style("Editor exited with an error; configuration left unchanged.").yellow()
+                );
+                return Ok(());
+            }
+
+            let edited = fs::read_to_string(draft.path())?;
+            if edited == original {
+                println!("{}", style("No changes made.").dim());
+                return Ok(());
+            }
+            let parsed = serde_json::from_str::<AppConfig>(&edited)
+                .map_err(anyhow::Error::from)
+                .and_then(|config| normalize_base_url(&config.base_url));
+            match parsed {
+                Ok(_) => {
+                    with_file_lock(&path, true, || write_file_atomic(&path, edited.as_bytes()))
+                        .context("Failed to save configuration")?;
+                    println!("{}", style("✓ Configuration updated").green());
+                    return Ok(());
+                }
+                Err(err) => {
+                    println!("{}", style(format!("Invalid configuration: {}", err)).red());
+                    let retry = Confirm::with_theme(&self.theme)
+                        .with_prompt("Re-open the editor to fix it?")
+                        .default(true)
+                        .interact()?;
+                    if !retry {
+                        println!(
+                            "{}",
+                            style("Edit discarded; configuration left unchanged.").yellow()
+                        );
+                        return Ok(());
+                    }
+                }
+            }
+        }
+    }
+
+    pub async fn config_reset(&mut self) -> Result<()> {
+        let confirm = Confirm::with_theme(&self.theme)
+            .with_prompt("⚠️  Are you sure you want to reset configuration to defaults?")
+            .default(false)
+            .interact()?;
+
+        if !confirm {
+            println!("{}", style("Reset cancelled.").yellow());
+            return Ok(());
+        }
+
+        // Settings go too, including a stored login
+        self.api_client.config = AppConfig::with_defaults();
+        self.api_client.save_config()?;
+        println!("{}", style("✓ Configuration reset to defaults!").green());
+        Ok(())
+    }
+
+    // Edit a single SSH host in place. Only that host's entry is rewritten, so
+    // the rest of cloud-config.json is left as it was, in the same format.
+    pub async fn config_edit_host(&self, name: &str) -> Result<()> {
+        let current = self.read_editable_config()?;
+        let index = find_host(&current, name, &self.cloud_config_path())?;
+        let hosts = &current.ssh_hosts;
+
+        println!(
+            "\n{}",
+            style(format!("✏️  Editing SSH host '{}'", name))
+                .cyan()
+                .bold()
+        );
+        let mut updated = self.prompt_ssh_host(Some(&hosts[index]))?;
+        let others = other_hosts(hosts, index);
+        validate_ssh_host(&updated, &others)?;
+        if !updated.is_bastion {
+            check_bastion_kept(&hosts[index], &others)?;
+        }
+        self.encrypt_host_passwords(std::slice::from_mut(&mut updated))?;
+
+        // Re-read under the lock and check again, in case the config changed
+        // while we were prompting
+        let mut editable = self.load_editable_config()?;
+        let index = find_host(&editable.config, name, &editable.path)?;
+        let hosts = &editable.config.ssh_hosts;
+        let others = other_hosts(hosts, index);
+        validate_ssh_host(&updated, &others)?;
+        if !updated.is_bastion {
+            check_bastion_kept(&hosts[index], &others)?;
+        }
+
+        // Merge into the existing entry so unknown keys on it survive
+        let entry = editable
+            .hosts_mut()?
+            .get_mut(index)
+            .and_then(|host| host.as_object_mut())
+            .context("Malformed ssh_hosts entry in configuration")?;
+        if let serde_json::Value::Object(fields) = serde_json::to_value(&updated)? {
+            entry.extend(fields);
+        }
+
+        editable.save()?;
         println!(
             "{}",
-            style("Opening configuration in your default editor...").dim()
+            style(format!("✅ SSH host '{}' updated", updated.name)).green()
         );
 
-        // Simulate editor opening
-        thread::sleep(Duration::from_secs(2));
-        println!("{}", style("Configuration updated successfully!").green());
         Ok(())
     }
 
-    pub async fn config_reset(&self) -> Result<()> {
+    // Remove accumulated settings, optionally only those under a prefix
+    pub async fn config_clear_settings(&mut self, prefix: Option<&str>) -> Result<()> {
+        let matching = match prefix {
+            Some(prefix) => self.api_client.get_settings_section(prefix).len(),
+            None => self.api_client.config.settings.len(),
+        };
+
+        if matching == 0 {
+            println!("{}", style("No matching settings to clear.").yellow());
+            return Ok(());
+        }
+
+        let prompt = match prefix {
+            Some(prefix) => format!(
+                "⚠️  Remove {} setting(s) starting with '{}'?",
+                matching, prefix
+            ),
+            None => format!("⚠️  Remove all {} setting(s)?", matching),
+        };
         let confirm = Confirm::with_theme(&self.theme)
-            .with_prompt("⚠️  Are you sure you want to reset configuration to defaults?")
+            .with_prompt(prompt)
             .default(false)
             .interact()?;
 
         if !confirm {
-            println!("{}", style("Reset cancelled.").yellow());
+            println!("{}", style("Clear cancelled.").yellow());
             return Ok(());
         }
 
-        let mut spinner = self.create_spinner("Resetting configuration...");
-        thread::sleep(Duration::from_secs(2));
-        spinner.stop_with_message("✓ Configuration reset to defaults!".to_string());
+        let removed = self.api_client.clear_settings(prefix)?;
+        println!(
+            "{}",
+            style(format!("✓ Removed {} setting(s)", removed)).green()
+        );
+
+        Ok(())
+    }
+
+    // `config set`: values that parse as JSON keep their type, so numbers
+    // and booleans round-trip; anything else is stored as a string
+    pub async fn config_set(&mut self, key: &str, value: &str) -> Result<()> {
+        let mut value = serde_json::from_str(value)
+            .unwrap_or_else(|_| serde_json::Value::String(value.to_string()));
+
+        if key == "base_url" {
+            let base_url: String =
+                serde_json::from_value(value.clone()).context("base_url must be a string")?;
+            value = serde_json::Value::from(normalize_base_url(&base_url)?);
+        }
+        if key == "retry_status_codes" {
+            parse_status_codes(&value)
+                .map_err(|problem| anyhow!("Invalid retry_status_codes: {}", problem))?;
+        }
+        self.api_client.update_config(|config| {
+            match key {
+                "base_url" => config.base_url = serde_json::from_value(value.clone())?,
+                "timeout_seconds" => {
+                    config.timeout_seconds = serde_json::from_value(value.clone())
+                        .context("timeout_seconds must be a whole number")?
+                }
+                "max_retries" => {
+                    config.max_retries = serde_json::from_value(value.clone())
+                        .context("max_retries must be a whole number")?
+                }
+                _ => match WaitKind::from_key(key) {
+                    Some(kind) => {
+                        let seconds = serde_json::from_value(value.clone()).with_context(|| {
+                            format!("{} must be a whole number of seconds", key)
+                        })?;
+                        *kind.configured_mut(config) = Some(seconds);
+                    }
+                    None => {
+                        config.settings.insert(key.to_string(), value.clone());
+                    }
+                },
+            }
+            Ok(())
+        })?;
+
+        // Secrets aren't echoed back, so they don't end up in scrollback or logs
+        if is_secret_arg(key) {
+            println!("{}", style(format!("✓ {} = [REDACTED]", key)).green());
+        } else {
+            println!("{}", style(format!("✓ {} = {}", key, value)).green());
+        }
+        Ok(())
+    }
+
+    // `config get`: print just the value so it can be captured in a shell
+    pub async fn config_get(&self, key: &str) -> Result<()> {
+        let config = &self.api_client.config;
+        let value = match key {
+            "base_url" => serde_json::Value::from(config.base_url.clone()),
+            "timeout_seconds" => serde_json::Value::from(config.timeout_seconds),
+            "max_retries" => serde_json::Value::from(config.max_retries),
+            _ => match WaitKind::from_key(key) {
+                Some(kind) => kind.configured(config).map(serde_json::Value::from),
+                None => self.api_client.get_setting::<serde_json::Value>(key),
+            }
+            .ok_or_else(|| anyhow!("No setting named '{}'", key))?,
+        };
+        match value {
+            serde_json::Value::String(value) => println!("{}", value),
+            other => println!("{}", other),
+        }
+        Ok(())
+    }
+
+    pub async fn config_unset(&mut self, key: &str) -> Result<()> {
+        if matches!(key, "base_url" | "timeout_seconds" | "max_retries") {
+            return Err(anyhow!(
+                "'{}' is required; change it with 'omni config set {} <value>'",
+                key,
+                key
+            ));
+        }
+        let removed = match WaitKind::from_key(key) {
+            Some(kind) => {
+                let was_set = kind.configured(&self.api_client.config).is_some();
+                if was_set {
+                    self.api_client.update_config(|config| {
+                        *kind.configured_mut(config) = None;
+                        Ok(())
+                    })?;
+                }
+                was_set
+            }
+            None => self.api_client.remove_setting(key),
+        };
+        if removed {
+            println!("{}", style(format!("✓ Removed {}", key)).green());
+        } else {
+            println!("{}", style(format!("No setting named '{}'", key)).yellow());
+        }
+        Ok(())
+    }
+
+    // Choose the environment `omni up` pre-selects. Protected environments
+    // are refused unless `allow_prod` is set, so a stray Enter can't ship to prod.
+    pub async fn config_set_default_env(&mut self, env: &str, allow_prod: bool) -> Result<()> {
+        let environments = self.deploy_environments().await;
+        let environment = find_environment(&environments, env)?;
+
+        if environment.protected && !allow_prod {
+            return Err(anyhow!(
+                "{} is a protected environment; pass --allow-prod-default to make it the default",
+                environment.name
+            ));
+        }
+
+        let name = environment.name.clone();
+        self.api_client
+            .set_setting(DEFAULT_ENVIRONMENT_SETTING, &name)?;
+        println!(
+            "{}",
+            style(format!("✅ Default deploy environment set to {}", name)).green()
+        );
+        Ok(())
+    }
+
+    // Apply the local cloud config to the live platform. The server's plan is
+    // always shown first; with `dry_run` nothing is applied.
+    pub async fn config_push(&self, dry_run: bool, yes: bool) -> Result<()> {
+        let mut config = self.load_cloud_config()?;
+        config.ssh_hosts = self.decrypt_host_passwords(&config.ssh_hosts)?;
+        let endpoint = format!("/platforms/{}/config", config.cloud_name);
+
+        let mut spinner = self.create_spinner("Computing plan...");
+        let response = self
+            .api_client
+            .put::<_, ApiResponse>(&format!("{}?dry_run=true", endpoint), &config)
+            .await;
+        spinner.stop();
+        let plan = match self.parse_status_data::<ConfigPlan>(
+            response.context("Failed to compute the config plan")?.data,
+        ) {
+            StatusData::Available(plan) => plan,
+            StatusData::Missing => ConfigPlan::default(),
+            StatusData::Malformed => {
+                return Err(anyhow!("Unexpected server response for the config plan"));
+            }
+        };
+
+        println!("\n{}", style("📝 Config Plan").cyan().bold());
+        if plan.is_empty() {
+            println!(
+                "{}",
+                style("No changes. The platform matches your config.").green()
+            );
+            return Ok(());
+        }
+        for host in &plan.hosts_to_add {
+            println!("  {} host {}", style("+").green().bold(), host);
+        }
+        for host in &plan.hosts_to_remove {
+            println!("  {} host {}", style("-").red().bold(), host);
+        }
+        for service in &plan.services_to_restart {
+            println!("  {} restart {}", style("~").yellow().bold(), service);
+        }
+        if let Some(downtime) = &plan.downtime_estimate {
+            println!("Estimated downtime: {}", style(downtime).yellow());
+        }
+
+        if dry_run {
+            println!(
+                "\n{}",
+                style("Dry run: nothing was applied. Run 'omni config push' to apply.").dim()
+            );
+            return Ok(());
+        }
+
+        if !yes {
+            let confirm = Confirm::with_theme(&self.theme)
+                .with_prompt("Apply this plan to the running platform?")
+                .default(false)
+                .interact()?;
+            if !confirm {
+                println!("{}", style("Push cancelled.").yellow());
+                return Ok(());
+            }
+        }
+
+        let mut spinner = self.create_spinner("Applying configuration...");
+        let response = self
+            .api_client
+            .put::<_, ApiResponse>(&endpoint, &config)
+            .await;
+        spinner.stop();
+        response.context("Failed to apply the config")?;
+
+        println!("{}", style("✅ Configuration applied").green());
+        Ok(())
+    }
+
+    // Print (or write) the JSON schema describing cloud-config.json
+    pub async fn config_schema(&self, out: Option<&str>) -> Result<()> {
+        let schema = schemars::schema_for!(CloudConfig);
+        let schema_json = serde_json::to_string_pretty(&schema)?;
+
+        match out {
+            Some(out_path) => {
+                if let Some(parent) = Path::new(out_path).parent() {
+                    if !parent.as_os_str().is_empty() {
+                        fs::create_dir_all(parent)
+                            .context("Failed to create schema output directory")?;
+                    }
+                }
+                fs::write(out_path, schema_json).context("Failed to write schema file")?;
+                println!(
+                    "{}",
+                    style(format!("✅ Schema written to {}", out_path)).green()
+                );
+            }
+            None => println!("{}", schema_json),
+        }
 
         Ok(())
     }
@@ -0,0 +1,80 @@
+use crate::ui::PremiumUI;
+use anyhow::Result;
+use console::style;
+use serde_json::Value;
+
+// Pull a string field out of a response, whether or not it is wrapped in
+// the usual `{ status, message, data }` envelope
+pub fn response_field(response: &Value, field: &str) -> Option<String> {
+    response
+        .get("data")
+        .and_then(|data| data.get(field))
+        .or_else(|| response.get(field))
+        .and_then(Value::as_str)
+        .map(String::from)
+}
+
+impl PremiumUI {
+    // "Where am I and who am I": the config in use, the API it talks to, the
+    // identity it authenticates as and both ends' versions
+    pub async fn context_info(&self) -> Result<()> {
+        let cloud_config_path = self.cloud_config_path();
+        let cloud = if cloud_config_path.exists() {
+            match self.load_cloud_config() {
+                Ok(config) => style(format!(
+                    "{} ({})",
+                    config.cloud_name,
+                    cloud_config_path.display()
+                ))
+                .green(),
+                Err(_) => style(format!("unreadable ({})", cloud_config_path.display())).red(),
+            }
+        } else {
+            style("not initialized (run 'omni init')".to_string()).yellow()
+        };
+
+        let cli_config = self
+            .api_client
+            .config_path
+            .as_ref()
+            .map(|path| path.display().to_string())
+            .unwrap_or_else(|| "-".to_string());
+
+        let identity = if self.api_client.get_setting::<String>("api_key").is_some() {
+            match self.api_client.get::<Value>("/auth/me").await {
+                Ok(response) => match response_field(&response, "username") {
+                    Some(username) => style(username).green(),
+                    None => style("authenticated".to_string()).green(),
+                },
+                Err(_) => style("token stored, but not accepted by the server".to_string()).red(),
+            }
+        } else {
+            style("not logged in".to_string()).yellow()
+        };
+
+        let server_version = match self.api_client.get::<Value>("/version").await {
+            Ok(response) => {
+                style(response_field(&response, "version").unwrap_or_else(|| "unknown".to_string()))
+                    .green()
+            }
+            Err(_) => style("unreachable".to_string()).red(),
+        };
+
+        println!("\n{}", style("🧭 Context").cyan().bold());
+        println!("Profile:        {}", style("default").green());
+        println!("Cloud:          {}", cloud);
+        println!("CLI config:     {}", style(cli_config).dim());
+        println!(
+            "API URL:        {}",
+            style(&self.api_client.base_url).green()
+        );
+        println!("Identity:       {}", identity);
+        println!(
+            "Client version: {}",
+            style(env!("CARGO_PKG_VERSION")).green()
+        );
+        println!("Server version: {}", server_version);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,179 @@
+use crate::ui::PremiumUI;
+use anyhow::{anyhow, Context, Result};
+use console::style;
+use serde::{Deserialize, Serialize};
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+// Per-project directory holding local deploy metadata; never uploaded
+pub const DEPLOY_METADATA_DIR: &str = ".omni";
+
+// One line of `.omni/deployments.jsonl`, written after each successful deploy
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DeploymentRecord {
+    pub timestamp: String,
+    pub app: String,
+    pub release: String,
+    pub environment: String,
+    // Git commit of the project at deploy time, if it is a git checkout
+    pub source_commit: Option<String>,
+    // Whether the checkout had uncommitted changes
+    pub source_dirty: Option<bool>,
+    pub tarball_sha256: String,
+    pub deployed_by: Option<String>,
+}
+
+fn git_output(project_path: &Path, args: &[&str]) -> Option<String> {
+    let output = Command::new("git")
+        .args(args)
+        .current_dir(project_path)
+        .output()
+        .ok()?;
+    output
+        .status
+        .success()
+        .then(|| String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+// HEAD commit and dirty flag of the project's git checkout, if any
+pub fn source_revision(project_path: &Path) -> (Option<String>, Option<bool>) {
+    match git_output(project_path, &["rev-parse", "HEAD"]) {
+        Some(commit) => {
+            let dirty = git_output(project_path, &["status", "--porcelain"])
+                .map(|status| !status.is_empty());
+            (Some(commit), dirty)
+        }
+        None => (None, None),
+    }
+}
+
+// Who ran the deploy: the git identity if set, otherwise the OS user
+pub fn local_identity(project_path: &Path) -> Option<String> {
+    git_output(project_path, &["config", "user.email"])
+        .filter(|email| !email.is_empty())
+        .or_else(|| std::env::var("USER").ok())
+        .or_else(|| std::env::var("USERNAME").ok())
+}
+
+// Contents of `.omni/deploy.lock`
+#[derive(Debug, Serialize, Deserialize)]
+struct LockOwner {
+    pid: u32,
+    started_at: String,
+}
+
+// Whether a process is still running, when that can be told
+fn process_alive(pid: u32) -> Option<bool> {
+    if cfg!(unix) {
+        Command::new("kill")
+            .args(["-0", &pid.to_string()])
+            .stderr(std::process::Stdio::null())
+            .status()
+            .ok()
+            .map(|status| status.success())
+    } else {
+        None
+    }
+}
+
+// Held for the duration of a deploy so two `omni up` runs against the same
+// project can't race. The lock file is removed when this is dropped.
+pub struct DeployLock {
+    path: PathBuf,
+}
+
+impl DeployLock {
+    // Take the project's deploy lock. A lock left by a process that is no
+    // longer running is taken over; `force` takes over any lock.
+    pub fn acquire(project_path: &Path, force: bool) -> Result<Self> {
+        let path = project_path.join(DEPLOY_METADATA_DIR).join("deploy.lock");
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)
+                .with_context(|| format!("Failed to create {}", dir.display()))?;
+        }
+
+        let owner = LockOwner {
+            pid: std::process::id(),
+            started_at: chrono::Utc::now().to_rfc3339(),
+        };
+        let contents = serde_json::to_string(&owner)?;
+
+        // Two attempts: the second follows removing a stale or forced lock
+        for _ in 0..2 {
+            match OpenOptions::new().write(true).create_new(true).open(&path) {
+                Ok(mut file) => {
+                    file.write_all(contents.as_bytes())?;
+                    return Ok(Self { path });
+                }
+                Err(err) if err.kind() == std::io::ErrorKind::AlreadyExists => {
+                    let holder = fs::read_to_string(&path)
+                        .ok()
+                        .and_then(|contents| serde_json::from_str::<LockOwner>(&contents).ok());
+                    let stale = holder
+                        .as_ref()
+                        .is_some_and(|holder| process_alive(holder.pid) == Some(false));
+                    if !force && !stale {
+                        let holder = holder.map_or("unknown pid".to_string(), |holder| {
+                            format!("pid {}, started {}", holder.pid, holder.started_at)
+                        });
+                        return Err(anyhow!(
+                            "A deploy is already in progress ({}). If it isn't, \
+                             rerun with --force-unlock.",
+                            holder
+                        ));
+                    }
+                    fs::remove_file(&path)
+                        .with_context(|| format!("Failed to remove {}", path.display()))?;
+                }
+                Err(err) => {
+                    return Err(err).with_context(|| format!("Failed to create {}", path.display()))
+                }
+            }
+        }
+        Err(anyhow!(
+            "Couldn't take the deploy lock at {}",
+            path.display()
+        ))
+    }
+}
+
+impl Drop for DeployLock {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.path);
+    }
+}
+
+pub fn deployments_log_path(project_path: &Path) -> PathBuf {
+    project_path
+        .join(DEPLOY_METADATA_DIR)
+        .join("deployments.jsonl")
+}
+
+impl PremiumUI {
+    // Append a deploy to the project's deployments log. Like the audit log,
+    // failing to write it is reported but never fails the deploy.
+    pub fn record_deployment(&self, project_path: &Path, record: &DeploymentRecord) {
+        let path = deployments_log_path(project_path);
+        let write = || -> Result<()> {
+            if let Some(dir) = path.parent() {
+                fs::create_dir_all(dir)?;
+            }
+            let mut file = OpenOptions::new().create(true).append(true).open(&path)?;
+            writeln!(file, "{}", serde_json::to_string(record)?)?;
+            Ok(())
+        };
+        if let Err(err) = write() {
+            eprintln!(
+                "{}",
+                style(format!(
+                    "Warning: failed to record deployment in {}: {}",
+                    path.display(),
+                    err
+                ))
+                .yellow()
+            );
+        }
+    }
+}
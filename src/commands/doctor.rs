@@ -0,0 +1,137 @@
+use crate::api_client::{normalize_base_url, AppConfig};
+use crate::commands::preflight::{CheckStatus, ReadinessCheck};
+use crate::ui::PremiumUI;
+use anyhow::{anyhow, Result};
+use console::style;
+use serde_json::Value;
+use std::fs;
+use std::net::ToSocketAddrs;
+
+impl PremiumUI {
+    // `doctor`: the usual reasons commands fail for a new user, checked in
+    // the order they'd get in each other's way
+    pub async fn doctor(&self) -> Result<()> {
+        println!("\n{}", style("🩺 Omni Doctor").cyan().bold());
+        let mut spinner = self.create_spinner("Running checks...");
+
+        let mut checks = Vec::new();
+        self.check_cli_config(&mut checks);
+        let api_ok = self.check_base_url(&mut checks) && self.check_health(&mut checks).await;
+        if api_ok {
+            self.check_authentication(&mut checks).await;
+        }
+        if let Some(config) = self.check_config_file(&self.cloud_config_path(), &mut checks) {
+            for host in &config.ssh_hosts {
+                let name = format!("DNS {}", host.name);
+                let check = match (host.hostname.as_str(), host.port).to_socket_addrs() {
+                    Ok(mut addresses) => match addresses.next() {
+                        Some(address) => ReadinessCheck::new(
+                            name,
+                            CheckStatus::Pass,
+                            format!("{} → {}", host.hostname, address.ip()),
+                        ),
+                        None => ReadinessCheck::new(
+                            name,
+                            CheckStatus::Fail,
+                            format!("{} has no addresses", host.hostname),
+                        ),
+                    },
+                    Err(err) => ReadinessCheck::new(
+                        name,
+                        CheckStatus::Fail,
+                        format!("{} doesn't resolve: {}", host.hostname, err),
+                    ),
+                };
+                checks.push(check);
+            }
+        }
+
+        spinner.stop();
+        self.print_readiness(&checks);
+
+        let failures = checks
+            .iter()
+            .filter(|check| check.status == CheckStatus::Fail)
+            .count();
+        if failures > 0 {
+            return Err(anyhow!("{} check(s) failed", failures));
+        }
+        println!("\n{}", style("No problems found.").green());
+        Ok(())
+    }
+
+    // The CLI's own settings file; missing is fine, the defaults apply. The
+    // file is read here rather than trusting what the client loaded, since a
+    // client that writes config replaces an unparseable file with defaults.
+    fn check_cli_config(&self, checks: &mut Vec<ReadinessCheck>) {
+        let name = "CLI config";
+        let check = match &self.api_client.config_path {
+            Some(path) if path.exists() => {
+                match fs::read_to_string(path)
+                    .map_err(anyhow::Error::from)
+                    .and_then(|contents| Ok(serde_json::from_str::<AppConfig>(&contents)?))
+                {
+                    Ok(_) => ReadinessCheck::new(name, CheckStatus::Pass, path.display().to_string()),
+                    Err(err) => ReadinessCheck::new(
+                        name,
+                        CheckStatus::Fail,
+                        format!(
+                            "{} can't be parsed ({}); fix it with 'omni config edit' or 'omni config reset'",
+                            path.display(),
+                            err
+                        ),
+                    ),
+                }
+            }
+            Some(path) => ReadinessCheck::new(
+                name,
+                CheckStatus::Warn,
+                format!("{} not written yet, using defaults", path.display()),
+            ),
+            None => ReadinessCheck::new(
+                name,
+                CheckStatus::Warn,
+                "no config directory on this system, using defaults",
+            ),
+        };
+        checks.push(check);
+    }
+
+    fn check_base_url(&self, checks: &mut Vec<ReadinessCheck>) -> bool {
+        match normalize_base_url(&self.api_client.base_url) {
+            Ok(base_url) => {
+                checks.push(ReadinessCheck::new("API URL", CheckStatus::Pass, base_url));
+                true
+            }
+            Err(err) => {
+                checks.push(ReadinessCheck::new(
+                    "API URL",
+                    CheckStatus::Fail,
+                    err.to_string(),
+                ));
+                false
+            }
+        }
+    }
+
+    async fn check_health(&self, checks: &mut Vec<ReadinessCheck>) -> bool {
+        match self.api_client.get::<Value>("/health").await {
+            Ok(_) => {
+                checks.push(ReadinessCheck::new(
+                    "API health",
+                    CheckStatus::Pass,
+                    format!("{}/health responded", self.api_client.base_url),
+                ));
+                true
+            }
+            Err(err) => {
+                checks.push(ReadinessCheck::new(
+                    "API health",
+                    CheckStatus::Fail,
+                    format!("{} unreachable: {}", self.api_client.base_url, err),
+                ));
+                false
+            }
+        }
+    }
+}
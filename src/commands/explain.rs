@@ -0,0 +1,239 @@
+use crate::commands::audit::leaf_command;
+use crate::ui::PremiumUI;
+use anyhow::{anyhow, Result};
+use clap::ArgMatches;
+use console::style;
+
+// Commands `--explain` can describe
+const EXPLAINED_COMMANDS: &[&str] = &[
+    "init",
+    "config push",
+    "scale",
+    "rollback",
+    "service restart",
+    "service stop",
+    "service start",
+    "backup restore",
+];
+
+// One step of an explained plan: an API call, or local work ("LOCAL")
+struct PlannedStep {
+    method: &'static str,
+    target: String,
+    effect: String,
+}
+
+fn step(method: &'static str, target: impl Into<String>, effect: impl Into<String>) -> PlannedStep {
+    PlannedStep {
+        method,
+        target: target.into(),
+        effect: effect.into(),
+    }
+}
+
+// An argument's value, or a placeholder saying it will be asked for
+fn arg_or_prompt(matches: &ArgMatches, id: &str) -> String {
+    match matches.try_get_one::<String>(id) {
+        Ok(Some(value)) => value.clone(),
+        _ => format!("<{}, prompted>", id),
+    }
+}
+
+fn plan(command: &str, matches: &ArgMatches, cloud: &str) -> Vec<PlannedStep> {
+    match command {
+        "init" => vec![
+            step(
+                "LOCAL",
+                "cloud-config",
+                "Read the cloud config, or run the setup wizard if there is none",
+            ),
+            step(
+                "GET",
+                "/regions",
+                "List regions to choose from in the wizard",
+            ),
+            step(
+                "POST",
+                "/platforms/init",
+                "Send the config; the server starts bootstrapping every SSH host",
+            ),
+            step(
+                "GET",
+                format!("/platforms/{}/status", cloud),
+                "Poll until every host is bootstrapped",
+            ),
+            step(
+                "POST",
+                format!("/platforms/{}/network/configure", cloud),
+                "Configure cluster networking between the hosts",
+            ),
+            step(
+                "POST",
+                format!("/platforms/{}/monitoring/setup", cloud),
+                "Deploy monitoring services, if enabled in the config",
+            ),
+            step(
+                "POST",
+                format!("/platforms/{}/backups/setup", cloud),
+                "Configure scheduled backups, if enabled in the config",
+            ),
+        ],
+        "config push" => {
+            let mut steps = vec![step(
+                "PUT",
+                format!("/platforms/{}/config?dry_run=true", cloud),
+                "Ask the server which hosts and settings would change, and show that plan",
+            )];
+            if !matches.get_flag("dry-run") {
+                steps.push(step(
+                    "PUT",
+                    format!("/platforms/{}/config", cloud),
+                    "After confirmation, apply the local config to the live platform",
+                ));
+            }
+            steps
+        }
+        "scale" => {
+            let app = arg_or_prompt(matches, "app");
+            let component = arg_or_prompt(matches, "component");
+            let replicas = match matches.try_get_one::<u32>("replicas") {
+                Ok(Some(replicas)) => replicas.to_string(),
+                _ => "<replicas, prompted>".to_string(),
+            };
+            vec![
+                step(
+                    "GET",
+                    format!("/apps/{}", app),
+                    "Read the app's components to check the one being scaled exists",
+                ),
+                step(
+                    "PUT",
+                    format!("/apps/{}/components/{}/scale", app, component),
+                    format!(
+                        "Set the component to {} replica(s); the platform starts or stops replicas to match",
+                        replicas
+                    ),
+                ),
+                step(
+                    "GET",
+                    format!("/apps/{}", app),
+                    "Show the component's updated status",
+                ),
+            ]
+        }
+        "rollback" => {
+            let app = arg_or_prompt(matches, "app");
+            let version = arg_or_prompt(matches, "version");
+            vec![
+                step(
+                    "GET",
+                    format!("/apps/{}/releases", app),
+                    "List the app's releases to find the target version",
+                ),
+                step(
+                    "GET",
+                    format!("/apps/{}", app),
+                    "Find the current release, which can't be the target",
+                ),
+                step(
+                    "POST",
+                    format!("/apps/{}/releases/{}/rollback", app, version),
+                    "After confirmation, redeploy that release in place of the current one",
+                ),
+                step(
+                    "GET",
+                    format!("/platforms/{}/status", cloud),
+                    "Poll until every host has rolled out the release",
+                ),
+            ]
+        }
+        "service restart" | "service stop" | "service start" => {
+            let action = command.trim_start_matches("service ");
+            let host = arg_or_prompt(matches, "host");
+            let service = arg_or_prompt(matches, "service");
+            vec![
+                step(
+                    "LOCAL",
+                    "cloud-config",
+                    format!("Check that host {} is in the cloud config", host),
+                ),
+                step(
+                    "GET",
+                    format!("/platforms/{}/hosts/{}/services", cloud, host),
+                    format!("Check that the host runs {}", service),
+                ),
+                step(
+                    "POST",
+                    format!(
+                        "/platforms/{}/hosts/{}/services/{}/{}",
+                        cloud, host, service, action
+                    ),
+                    format!("Ask the host to {} the service", action),
+                ),
+                step(
+                    "GET",
+                    format!("/platforms/{}/hosts/{}/services/{}", cloud, host, service),
+                    "Poll until the service reports its new state",
+                ),
+            ]
+        }
+        "backup restore" => {
+            let id = arg_or_prompt(matches, "id");
+            vec![
+                step(
+                    "POST",
+                    format!("/platforms/{}/backups/{}/restore", cloud, id),
+                    "After confirmation, overwrite the platform's current state with the backup",
+                ),
+                step(
+                    "GET",
+                    format!("/platforms/{}/backups/{}/restore/status", cloud, id),
+                    "Poll the restore's progress until it finishes",
+                ),
+            ]
+        }
+        _ => Vec::new(),
+    }
+}
+
+impl PremiumUI {
+    // `--explain`: describe the calls a command would make and their effect,
+    // without making any of them
+    pub fn explain_command(&self, cli: &ArgMatches) -> Result<()> {
+        let (command, matches) = leaf_command(cli);
+        if !EXPLAINED_COMMANDS.contains(&command.as_str()) {
+            return Err(anyhow!(
+                "--explain isn't available for '{}'. It covers: {}",
+                if command.is_empty() { "omni" } else { &command },
+                EXPLAINED_COMMANDS.join(", ")
+            ));
+        }
+
+        let cloud = self
+            .load_cloud_config()
+            .map(|config| config.cloud_name)
+            .unwrap_or_else(|_| "<cloud>".to_string());
+
+        println!(
+            "\n{}",
+            style(format!("📖 What 'omni {}' would do", command))
+                .cyan()
+                .bold()
+        );
+        println!(
+            "{}",
+            style(format!("API: {}", self.api_client.base_url)).dim()
+        );
+        for (index, step) in plan(&command, matches, &cloud).iter().enumerate() {
+            println!(
+                "{:>3}. {} {}",
+                index + 1,
+                style(format!("{:<6}", step.method)).yellow(),
+                step.target
+            );
+            println!("       {}", style(&step.effect).dim());
+        }
+        println!("\n{}", style("Nothing was executed (--explain).").green());
+        Ok(())
+    }
+}
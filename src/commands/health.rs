@@ -0,0 +1,226 @@
+use crate::commands::init_env::{ApiResponse, HostDeploymentStatus, StatusData};
+use crate::models::AppState;
+use crate::ui::PremiumUI;
+use anyhow::{anyhow, Result};
+use console::style;
+use serde::Serialize;
+
+// Issues printed in the text summary; `--json` includes all of them
+const MAX_LISTED_ISSUES: usize = 5;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+pub enum HealthLevel {
+    Healthy,
+    Degraded,
+    Down,
+}
+
+impl HealthLevel {
+    // Exit status for monitoring probes, following the Nagios convention
+    pub fn exit_code(self) -> i32 {
+        match self {
+            HealthLevel::Healthy => 0,
+            HealthLevel::Degraded => 1,
+            HealthLevel::Down => 2,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct HealthReport {
+    pub status: HealthLevel,
+    pub hosts_up: usize,
+    pub hosts_total: usize,
+    pub services_running: usize,
+    pub services_total: usize,
+    pub apps_ready: usize,
+    pub apps_total: usize,
+    pub issues: Vec<String>,
+}
+
+impl HealthReport {
+    fn new() -> Self {
+        Self {
+            status: HealthLevel::Healthy,
+            hosts_up: 0,
+            hosts_total: 0,
+            services_running: 0,
+            services_total: 0,
+            apps_ready: 0,
+            apps_total: 0,
+            issues: Vec::new(),
+        }
+    }
+
+    // Record a problem, raising the overall status to at least `level`
+    fn issue(&mut self, level: HealthLevel, issue: String) {
+        self.status = self.status.max(level);
+        self.issues.push(issue);
+    }
+}
+
+fn host_down(host: &HostDeploymentStatus) -> bool {
+    let status = host.status.to_lowercase();
+    host.error.is_some()
+        || ["fail", "error", "down", "offline", "unreachable"]
+            .iter()
+            .any(|marker| status.contains(marker))
+}
+
+impl PremiumUI {
+    // `health`: platform, service and app health rolled up into one status
+    pub async fn health(&self, json: bool) -> Result<HealthLevel> {
+        let mut spinner = self.create_spinner("Checking platform health...");
+        let mut report = HealthReport::new();
+        self.check_platform_health(&mut report).await;
+        self.check_apps_health(&mut report).await;
+        spinner.stop();
+
+        if json {
+            println!("{}", serde_json::to_string_pretty(&report)?);
+        } else {
+            self.print_health(&report);
+        }
+        Ok(report.status)
+    }
+
+    async fn check_platform_health(&self, report: &mut HealthReport) {
+        let hosts = match self.fetch_platform_status().await {
+            Ok(hosts) => hosts,
+            Err(err) => {
+                report.issue(
+                    HealthLevel::Down,
+                    format!("Platform status unavailable: {}", err),
+                );
+                return;
+            }
+        };
+
+        report.hosts_total = hosts.len();
+        for host in &hosts {
+            if host_down(host) {
+                let detail = host.error.as_deref().unwrap_or(&host.status);
+                report.issue(
+                    HealthLevel::Degraded,
+                    format!("Host {} is down: {}", host.host, detail),
+                );
+            } else {
+                report.hosts_up += 1;
+            }
+
+            for service in &host.services {
+                report.services_total += 1;
+                if service.status == "Running" {
+                    report.services_running += 1;
+                } else {
+                    report.issue(
+                        HealthLevel::Degraded,
+                        format!(
+                            "Service {} on {} is {}",
+                            service.name, host.host, service.status
+                        ),
+                    );
+                }
+            }
+        }
+
+        if report.hosts_total > 0 && report.hosts_up == 0 {
+            report.status = HealthLevel::Down;
+        }
+    }
+
+    async fn fetch_platform_status(&self) -> Result<Vec<HostDeploymentStatus>> {
+        let config = self.load_cloud_config()?;
+        let response = self
+            .api_client
+            .get::<ApiResponse>(&format!("/platforms/{}/status", config.cloud_name))
+            .await?;
+        match self.parse_status_data::<Vec<HostDeploymentStatus>>(response.data) {
+            StatusData::Available(hosts) => Ok(hosts),
+            StatusData::Missing => Ok(Vec::new()),
+            StatusData::Malformed => Err(anyhow!("unexpected server response")),
+        }
+    }
+
+    async fn check_apps_health(&self, report: &mut HealthReport) {
+        let apps = match self.list_app_names().await {
+            Ok(apps) => apps,
+            Err(err) => {
+                report.issue(HealthLevel::Degraded, format!("{:#}", err));
+                return;
+            }
+        };
+
+        let states =
+            futures::future::join_all(apps.iter().map(|app| self.fetch_app_state(app))).await;
+        report.apps_total = apps.len();
+        for (app, state) in apps.iter().zip(states) {
+            match state.as_ref().and_then(|state| app_problem(app, state)) {
+                None if state.is_some() => report.apps_ready += 1,
+                None => report.issue(
+                    HealthLevel::Degraded,
+                    format!("App {}: state unavailable", app),
+                ),
+                Some(problem) => report.issue(HealthLevel::Degraded, problem),
+            }
+        }
+    }
+
+    fn print_health(&self, report: &HealthReport) {
+        let status = format!("{:?}", report.status);
+        let status = match report.status {
+            HealthLevel::Healthy => style(status).green(),
+            HealthLevel::Degraded => style(status).yellow(),
+            HealthLevel::Down => style(status).red(),
+        };
+        println!("\n{} {}", style("🩺 Health:").cyan().bold(), status.bold());
+        println!("Hosts:    {}/{} up", report.hosts_up, report.hosts_total);
+        println!(
+            "Services: {}/{} running",
+            report.services_running, report.services_total
+        );
+        println!(
+            "Apps:     {}/{} ready",
+            report.apps_ready, report.apps_total
+        );
+
+        if report.issues.is_empty() {
+            return;
+        }
+        println!("\n{}", style("Top issues:").yellow().bold());
+        for issue in report.issues.iter().take(MAX_LISTED_ISSUES) {
+            println!("  {} {}", style("•").yellow(), issue);
+        }
+        if report.issues.len() > MAX_LISTED_ISSUES {
+            println!(
+                "  {}",
+                style(format!(
+                    "... and {} more (use --json for all)",
+                    report.issues.len() - MAX_LISTED_ISSUES
+                ))
+                .dim()
+            );
+        }
+    }
+}
+
+// Why an app isn't ready: failed components or missing replicas
+fn app_problem(app: &str, state: &AppState) -> Option<String> {
+    let failed: Vec<&str> = state
+        .components
+        .iter()
+        .filter(|c| c.is_failed())
+        .map(|c| c.name.as_str())
+        .collect();
+    if !failed.is_empty() {
+        return Some(format!(
+            "App {}: failed components {}",
+            app,
+            failed.join(", ")
+        ));
+    }
+
+    let replicas: u32 = state.components.iter().map(|c| c.replicas).sum();
+    let ready: u32 = state.components.iter().map(|c| c.ready_replicas).sum();
+    (ready < replicas).then(|| format!("App {}: {}/{} replicas ready", app, ready, replicas))
+}
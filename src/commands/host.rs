@@ -0,0 +1,176 @@
+use crate::api_client::{with_file_lock, FileLock};
+use crate::commands::init_env::{
+    validate_ssh_host, write_config_file_locked, CloudConfig, ConfigFileFormat, SshHost,
+};
+use crate::ui::PremiumUI;
+use anyhow::{anyhow, Context, Result};
+use console::style;
+use dialoguer::Confirm;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+// The cloud config opened for editing. Changes go through the raw document
+// so keys the CLI doesn't know, and ${...} references, survive the rewrite.
+// The config stays exclusively locked until this is dropped, so another
+// `omni` run can't write in between our read and our save. Prompt first
+// (against `read_editable_config`) and open this only to apply the answers,
+// so the lock is never held while waiting on the user.
+pub struct EditableConfig {
+    pub path: PathBuf,
+    format: ConfigFileFormat,
+    raw: serde_json::Value,
+    pub config: CloudConfig,
+    _lock: FileLock,
+}
+
+impl EditableConfig {
+    pub fn hosts_mut(&mut self) -> Result<&mut Vec<serde_json::Value>> {
+        self.raw
+            .get_mut("ssh_hosts")
+            .and_then(|hosts| hosts.as_array_mut())
+            .context("Malformed ssh_hosts list in configuration")
+    }
+
+    pub fn save(&self) -> Result<()> {
+        write_config_file_locked(&self.path, &self.format.serialize(&self.raw)?)
+    }
+}
+
+// Refuse to leave hosts behind without a bastion to reach them through
+// when `removed` was the only one
+pub fn check_bastion_kept(removed: &SshHost, remaining: &[SshHost]) -> Result<()> {
+    if removed.is_bastion && !remaining.is_empty() && !remaining.iter().any(|h| h.is_bastion) {
+        return Err(anyhow!(
+            "'{}' is the only bastion host and {} other host(s) are reached through it. \
+             Make another host a bastion first with 'omni host edit <name>'.",
+            removed.name,
+            remaining.len()
+        ));
+    }
+    Ok(())
+}
+
+// Parse the config as written, without resolving ${...} references
+fn parse_editable(
+    format: ConfigFileFormat,
+    contents: &str,
+) -> Result<(serde_json::Value, CloudConfig)> {
+    let raw: serde_json::Value = format
+        .parse(contents)
+        .map_err(|err| anyhow!("Failed to parse configuration: {}", err))?;
+    let config: CloudConfig =
+        serde_json::from_value(raw.clone()).context("Failed to parse configuration")?;
+    Ok((raw, config))
+}
+
+// Position of the host called `name`
+pub fn find_host(config: &CloudConfig, name: &str, path: &Path) -> Result<usize> {
+    config
+        .ssh_hosts
+        .iter()
+        .position(|host| host.name == name)
+        .ok_or_else(|| anyhow!("No SSH host named '{}' in {}", name, path.display()))
+}
+
+impl PremiumUI {
+    fn editable_config_path(&self) -> Result<PathBuf> {
+        let path = self.cloud_config_path();
+        if !path.exists() {
+            return Err(anyhow!(
+                "No cloud configuration found at {}. Run 'omni init' first.",
+                path.display()
+            ));
+        }
+        Ok(path)
+    }
+
+    // Snapshot of the config to prompt against. Only briefly share-locked,
+    // so it may be stale by the time the answers are applied.
+    pub fn read_editable_config(&self) -> Result<CloudConfig> {
+        let path = self.editable_config_path()?;
+        let format = ConfigFileFormat::from_path(&path);
+        let contents = with_file_lock(&path, false, || fs::read_to_string(&path))
+            .context("Failed to read configuration file")?;
+        parse_editable(format, &contents).map(|(_, config)| config)
+    }
+
+    // Lock the config and read it afresh, ready to apply and save a change
+    pub fn load_editable_config(&self) -> Result<EditableConfig> {
+        let path = self.editable_config_path()?;
+        let format = ConfigFileFormat::from_path(&path);
+        let lock = FileLock::acquire(&path, true).context("Failed to lock configuration file")?;
+        let contents = fs::read_to_string(&path).context("Failed to read configuration file")?;
+        let (raw, config) = parse_editable(format, &contents)?;
+        Ok(EditableConfig {
+            path,
+            format,
+            raw,
+            config,
+            _lock: lock,
+        })
+    }
+
+    // Add an SSH host to the existing config, prompting for its details
+    pub async fn host_add(&self) -> Result<()> {
+        let current = self.read_editable_config()?;
+
+        println!("\n{}", style("➕ Adding SSH host").cyan().bold());
+        let mut host = self.prompt_ssh_host(None)?;
+        validate_ssh_host(&host, &current.ssh_hosts)?;
+        self.encrypt_host_passwords(std::slice::from_mut(&mut host))?;
+
+        // Check again against the config as it is now
+        let mut editable = self.load_editable_config()?;
+        validate_ssh_host(&host, &editable.config.ssh_hosts)?;
+        editable.hosts_mut()?.push(serde_json::to_value(&host)?);
+        editable.save()?;
+        println!(
+            "{}",
+            style(format!(
+                "✅ SSH host '{}' added. Run 'omni config push' to apply it to the platform.",
+                host.name
+            ))
+            .green()
+        );
+        Ok(())
+    }
+
+    pub async fn host_remove(&self, name: &str, yes: bool) -> Result<()> {
+        let current = self.read_editable_config()?;
+        let index = find_host(&current, name, &self.cloud_config_path())?;
+        let mut remaining = current.ssh_hosts.clone();
+        let removed = remaining.remove(index);
+        check_bastion_kept(&removed, &remaining)?;
+
+        if !yes {
+            let confirmed = Confirm::with_theme(&self.theme)
+                .with_prompt(format!(
+                    "Remove SSH host '{}' ({})?",
+                    removed.name, removed.hostname
+                ))
+                .default(false)
+                .interact()?;
+            if !confirmed {
+                println!("{}", style("Removal cancelled.").yellow());
+                return Ok(());
+            }
+        }
+
+        let mut editable = self.load_editable_config()?;
+        let index = find_host(&editable.config, name, &editable.path)?;
+        let mut remaining = editable.config.ssh_hosts.clone();
+        let removed = remaining.remove(index);
+        check_bastion_kept(&removed, &remaining)?;
+        editable.hosts_mut()?.remove(index);
+        editable.save()?;
+        println!(
+            "{}",
+            style(format!(
+                "✅ SSH host '{}' removed. Run 'omni config push' to apply it to the platform.",
+                name
+            ))
+            .green()
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,210 @@
+use crate::commands::init_env::{
+    loose_key_permissions, resolve_identity_file, validate_ssh_host, SshHost,
+};
+use crate::ui::PremiumUI;
+use anyhow::{anyhow, Context, Result};
+use console::style;
+use serde::Deserialize;
+use std::fs;
+use std::path::Path;
+
+// Columns of a host import CSV. The header must name exactly these, in any
+// order.
+const CSV_COLUMNS: [&str; 6] = [
+    "name",
+    "hostname",
+    "username",
+    "port",
+    "identity_file",
+    "is_bastion",
+];
+
+// What `omni init --from` read from its file: the hosts, and any of the
+// wizard's other answers a JSON config object supplied
+#[derive(Debug, Default, Deserialize)]
+pub struct ImportedConfig {
+    pub company_name: Option<String>,
+    pub admin_name: Option<String>,
+    pub cloud_name: Option<String>,
+    pub region: Option<String>,
+    pub enable_monitoring: Option<bool>,
+    pub enable_backups: Option<bool>,
+    pub backup_retention_days: Option<u32>,
+    pub ssh_hosts: Vec<SshHost>,
+}
+
+// Split a CSV line into its fields. Fields may be double-quoted, with `""`
+// standing for a quote inside them.
+fn split_csv_line(line: &str) -> Result<Vec<String>, String> {
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut chars = line.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            '"' if quoted => quoted = false,
+            '"' if field.trim().is_empty() => {
+                field.clear();
+                quoted = true;
+            }
+            ',' if !quoted => fields.push(std::mem::take(&mut field).trim().to_string()),
+            _ => field.push(c),
+        }
+    }
+    if quoted {
+        return Err("unterminated quoted field".to_string());
+    }
+    fields.push(field.trim().to_string());
+    Ok(fields)
+}
+
+fn parse_csv_bool(value: &str) -> Result<bool, String> {
+    match value.to_ascii_lowercase().as_str() {
+        "true" | "yes" | "1" => Ok(true),
+        "false" | "no" | "0" | "" => Ok(false),
+        _ => Err(format!("is_bastion '{}' must be true or false", value)),
+    }
+}
+
+// Hosts from a CSV file, each with the line it came from
+fn parse_hosts_csv(contents: &str) -> Result<Vec<(String, SshHost)>, String> {
+    let mut lines = contents
+        .lines()
+        .enumerate()
+        .map(|(index, line)| (index + 1, line.trim()))
+        .filter(|(_, line)| !line.is_empty() && !line.starts_with('#'));
+
+    let (header_line, header) = lines.next().ok_or("the file has no header row")?;
+    let header: Vec<String> = split_csv_line(header)
+        .map_err(|problem| format!("line {}: {}", header_line, problem))?
+        .into_iter()
+        .map(|column| column.to_ascii_lowercase())
+        .collect();
+    let column = |name: &str| header.iter().position(|column| column == name);
+    if header.len() != CSV_COLUMNS.len() || CSV_COLUMNS.iter().any(|name| column(name).is_none()) {
+        return Err(format!(
+            "line {}: expected the columns {} (found {})",
+            header_line,
+            CSV_COLUMNS.join(", "),
+            header.join(", ")
+        ));
+    }
+    let [name, hostname, username, port, identity_file, is_bastion] =
+        CSV_COLUMNS.map(|name| column(name).unwrap_or_default());
+
+    let mut hosts = Vec::new();
+    for (line_number, line) in lines {
+        let location = format!("line {}", line_number);
+        let fields =
+            split_csv_line(line).map_err(|problem| format!("{}: {}", location, problem))?;
+        if fields.len() != header.len() {
+            return Err(format!(
+                "{}: expected {} values, found {}",
+                location,
+                header.len(),
+                fields.len()
+            ));
+        }
+        let host = SshHost {
+            name: fields[name].clone(),
+            hostname: fields[hostname].clone(),
+            username: match fields[username].as_str() {
+                "" => "root".to_string(),
+                username => username.to_string(),
+            },
+            password: None,
+            port: match fields[port].as_str() {
+                "" => 22,
+                port => port.parse().map_err(|_| {
+                    format!(
+                        "{}: port '{}' must be a number between 1 and 65535",
+                        location, port
+                    )
+                })?,
+            },
+            identity_file: Some(fields[identity_file].clone()).filter(|path| !path.is_empty()),
+            is_bastion: parse_csv_bool(&fields[is_bastion])
+                .map_err(|problem| format!("{}: {}", location, problem))?,
+        };
+        hosts.push((location, host));
+    }
+    Ok(hosts)
+}
+
+impl PremiumUI {
+    // `init --from`: read SSH hosts from a CSV file, or from a JSON array of
+    // hosts or config object, and check them as the wizard would
+    pub fn import_hosts(&self, path: &Path) -> Result<ImportedConfig> {
+        let contents = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        let is_json = match path.extension().and_then(|ext| ext.to_str()) {
+            Some("json") => true,
+            Some("csv") => false,
+            _ => contents.trim_start().starts_with(['[', '{']),
+        };
+        let invalid =
+            |problem: String| anyhow!("Invalid host file {}: {}", path.display(), problem);
+
+        let (mut imported, hosts) = if is_json {
+            let mut imported = if contents.trim_start().starts_with('[') {
+                ImportedConfig {
+                    ssh_hosts: serde_json::from_str(&contents)
+                        .map_err(|err| invalid(err.to_string()))?,
+                    ..Default::default()
+                }
+            } else {
+                serde_json::from_str::<ImportedConfig>(&contents)
+                    .map_err(|err| invalid(err.to_string()))?
+            };
+            let hosts = std::mem::take(&mut imported.ssh_hosts)
+                .into_iter()
+                .enumerate()
+                .map(|(index, host)| (format!("host {}", index + 1), host))
+                .collect();
+            (imported, hosts)
+        } else {
+            let hosts = parse_hosts_csv(&contents).map_err(invalid)?;
+            (ImportedConfig::default(), hosts)
+        };
+
+        for (location, mut host) in hosts {
+            validate_ssh_host(&host, &imported.ssh_hosts)
+                .map_err(|err| invalid(format!("{}: {}", location, err)))?;
+            if let Some(identity_file) = &host.identity_file {
+                let resolved = resolve_identity_file(identity_file)
+                    .map_err(|problem| invalid(format!("{}: {}", location, problem)))?;
+                if let Some(mode) = loose_key_permissions(&resolved) {
+                    println!(
+                        "{}",
+                        style(format!(
+                            "⚠️  {} has mode {:o}; ssh expects 600 or stricter for private keys.",
+                            resolved.display(),
+                            mode
+                        ))
+                        .yellow()
+                    );
+                }
+                host.identity_file = Some(resolved.display().to_string());
+            }
+            imported.ssh_hosts.push(host);
+        }
+
+        if imported.ssh_hosts.is_empty() {
+            return Err(invalid("it doesn't list any hosts".to_string()));
+        }
+        println!(
+            "{}",
+            style(format!(
+                "✅ Imported {} SSH host(s) from {}",
+                imported.ssh_hosts.len(),
+                path.display()
+            ))
+            .green()
+        );
+        Ok(imported)
+    }
+}
@@ -1,65 +1,416 @@
 use anyhow::{Context, Result};
+use clap::ArgMatches;
 use console::style;
-use dialoguer::{Confirm, Input, MultiSelect, Select};
+use dialoguer::{Confirm, Input, MultiSelect, Password, Select};
 use indicatif::{ProgressBar, ProgressStyle};
 use libomni::types::db::v1 as types;
-use serde::{Deserialize, Serialize};
+use reqwest::StatusCode;
+use schemars::JsonSchema;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::fmt::Write as _;
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
+use std::time::Instant;
 use tabled::{Table, Tabled};
 use tokio::time::Duration;
 
-use crate::ui::PremiumUI;
+use crate::api_client::{with_file_lock, write_file_atomic, ApiError};
+use crate::commands::host_import::ImportedConfig;
+use crate::commands::logs::{print_service_lines, LogFormat, LogView};
+use crate::commands::secrets::{lookup_secret, SECRET_REF_PREFIX};
+use crate::commands::wait::WaitKind;
+use crate::selector::{matches_all, Selector};
+use crate::ui::{expand_home, OutputMode, PremiumUI};
 
 #[derive(Debug, Deserialize)]
-struct ApiResponse {
-    status: String,
-    message: String,
+pub struct ApiResponse {
+    pub status: String,
+    pub message: String,
     #[serde(skip_serializing_if = "Option::is_none")]
-    data: Option<serde_json::Value>,
+    pub data: Option<serde_json::Value>,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, JsonSchema)]
 pub struct SshHost {
-    name: String,
-    hostname: String,
-    username: String,
-    password: Option<String>,
-    port: u16,
-    identity_file: Option<String>,
-    is_bastion: bool,
+    pub name: String,
+    pub hostname: String,
+    pub username: String,
+    pub password: Option<String>,
+    pub port: u16,
+    pub identity_file: Option<String>,
+    pub is_bastion: bool,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct CloudConfig {
-    company_name: String,
-    admin_name: String,
-    cloud_name: String,
-    region: String,
-    ssh_hosts: Vec<SshHost>,
-    enable_monitoring: bool,
-    enable_backups: bool,
-    backup_retention_days: u32,
+    pub company_name: String,
+    pub admin_name: String,
+    pub cloud_name: String,
+    pub region: String,
+    pub ssh_hosts: Vec<SshHost>,
+    pub enable_monitoring: bool,
+    pub enable_backups: bool,
+    pub backup_retention_days: u32,
+}
+
+// Body of `POST /platforms/init`: the cloud config plus bootstrap options
+#[derive(Debug, Serialize)]
+struct PlatformInitRequest<'a> {
+    #[serde(flatten)]
+    config: &'a CloudConfig,
+    // Leave concurrency to the server when unset
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_parallel_hosts: Option<u32>,
+}
+
+// Platform view printed by `hosts`/`status` with --output json|yaml
+#[derive(Debug, Serialize)]
+pub struct PlatformStatusReport {
+    pub company_name: String,
+    pub cloud_name: String,
+    pub region: String,
+    pub hosts: Vec<HostSummary>,
+    pub status: Vec<HostDeploymentStatus>,
+}
+
+// Configured host without its credentials
+#[derive(Debug, Serialize)]
+pub struct HostSummary {
+    pub name: String,
+    pub hostname: String,
+    pub username: String,
+    pub port: u16,
+    pub identity_file: Option<String>,
+    pub is_bastion: bool,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+impl From<&SshHost> for HostSummary {
+    fn from(host: &SshHost) -> Self {
+        Self {
+            name: host.name.clone(),
+            hostname: host.hostname.clone(),
+            username: host.username.clone(),
+            port: host.port,
+            identity_file: host.identity_file.clone(),
+            is_bastion: host.is_bastion,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct HostDeploymentStatus {
-    host: String,
-    status: String,
-    services: Vec<ServiceStatus>,
-    current_step: String,
-    progress: u8,
-    error: Option<String>,
-    completed: bool,
+    pub host: String,
+    pub status: String,
+    pub services: Vec<ServiceStatus>,
+    pub current_step: String,
+    pub progress: u8,
+    pub error: Option<String>,
+    pub completed: bool,
+    // Extended fields, shown with --format wide
+    #[serde(default)]
+    pub region: Option<String>,
+    #[serde(default)]
+    pub last_seen: Option<String>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ServiceStatus {
-    name: String,
-    status: String,
-    uptime: Option<String>,
-    cpu: Option<String>,
-    memory: Option<String>,
+    pub name: String,
+    pub status: String,
+    pub uptime: Option<String>,
+    pub cpu: Option<String>,
+    pub memory: Option<String>,
+    // Extended fields, shown with --format wide
+    #[serde(default)]
+    pub restart_count: Option<u32>,
+    #[serde(default)]
+    pub ports: Vec<serde_json::Value>,
+    #[serde(default)]
+    pub image_tag: Option<String>,
+}
+
+// On-disk format of a cloud config file, picked by its extension
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfigFileFormat {
+    Json,
+    Yaml,
+}
+
+impl ConfigFileFormat {
+    pub fn from_path(path: &Path) -> Self {
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some("yaml") | Some("yml") => ConfigFileFormat::Yaml,
+            _ => ConfigFileFormat::Json,
+        }
+    }
+
+    pub fn parse<T: DeserializeOwned>(self, contents: &str) -> std::result::Result<T, String> {
+        match self {
+            ConfigFileFormat::Json => {
+                serde_json::from_str(contents).map_err(|err| err.to_string())
+            }
+            ConfigFileFormat::Yaml => {
+                serde_yaml::from_str(contents).map_err(|err| err.to_string())
+            }
+        }
+    }
+
+    pub fn serialize<T: Serialize>(self, value: &T) -> Result<String> {
+        Ok(match self {
+            ConfigFileFormat::Json => serde_json::to_string_pretty(value)?,
+            ConfigFileFormat::Yaml => serde_yaml::to_string(value)?,
+        })
+    }
+}
+
+// Parse cloud config contents, describing what's wrong with an empty or
+// corrupt file instead of surfacing a raw serde error
+pub fn parse_cloud_config(
+    contents: &str,
+    format: ConfigFileFormat,
+) -> std::result::Result<CloudConfig, String> {
+    if contents.trim().is_empty() {
+        return Err("the file is empty".to_string());
+    }
+    format
+        .parse(contents)
+        .map_err(|err| format!("it could not be parsed ({})", err))
+}
+
+// Replace `${VAR}` references in `text` with the variable's value, and
+// `${secret:NAME}` ones with the stored secret, recording unresolvable
+// references in `unset`
+fn interpolate_env(text: &str, unset: &mut Vec<String>) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find("${") {
+        let Some(len) = rest[start + 2..].find('}') else {
+            break;
+        };
+        let name = &rest[start + 2..start + 2 + len];
+        result.push_str(&rest[..start]);
+        let value = match name.strip_prefix(SECRET_REF_PREFIX) {
+            Some(secret) => lookup_secret(secret),
+            None => std::env::var(name).ok(),
+        };
+        match value {
+            Some(value) => result.push_str(&value),
+            None => {
+                if !unset.iter().any(|known| known == name) {
+                    unset.push(name.to_string());
+                }
+                result.push_str(&rest[start..start + 3 + len]);
+            }
+        }
+        rest = &rest[start + 3 + len..];
+    }
+    result.push_str(rest);
+    result
+}
+
+fn interpolate_env_value(value: &mut serde_json::Value, unset: &mut Vec<String>) {
+    match value {
+        serde_json::Value::String(text) if text.contains("${") => {
+            *text = interpolate_env(text, unset);
+        }
+        serde_json::Value::Array(items) => {
+            items.iter_mut().for_each(|item| interpolate_env_value(item, unset))
+        }
+        serde_json::Value::Object(fields) => fields
+            .values_mut()
+            .for_each(|field| interpolate_env_value(field, unset)),
+        _ => {}
+    }
+}
+
+// Resolve `${VAR}` references in a loaded config's string fields from the
+// environment, so secrets needn't be stored in the file. Only configs about to
+// be used are resolved; anything written back keeps the references.
+pub fn resolve_env_references(config: CloudConfig) -> Result<CloudConfig> {
+    let mut value = serde_json::to_value(&config)?;
+    let mut unset = Vec::new();
+    interpolate_env_value(&mut value, &mut unset);
+    if !unset.is_empty() {
+        return Err(anyhow::anyhow!(
+            "The configuration references unset environment variable(s) or secret(s): {}",
+            unset.join(", ")
+        ));
+    }
+    Ok(serde_json::from_value(value)?)
+}
+
+// Turn an identity file answer into the absolute path stored in the config:
+// `~` and `${VAR}` are expanded, and the file must exist and be readable
+pub fn resolve_identity_file(input: &str) -> Result<PathBuf, String> {
+    let mut unset = Vec::new();
+    let expanded = interpolate_env(input.trim(), &mut unset);
+    if !unset.is_empty() {
+        return Err(format!("{} is not set", unset.join(", ")));
+    }
+    let path = expand_home(Path::new(&expanded)).map_err(|err| err.to_string())?;
+    let path = std::path::absolute(&path).map_err(|err| err.to_string())?;
+    if !path.is_file() {
+        return Err(format!("{} doesn't exist or isn't a file", path.display()));
+    }
+    fs::File::open(&path).map_err(|err| format!("{} can't be read: {}", path.display(), err))?;
+    Ok(path)
+}
+
+// Mode bits of a private key readable or writable by anyone but its owner,
+// which ssh refuses to use
+pub fn loose_key_permissions(path: &Path) -> Option<u32> {
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let mode = fs::metadata(path).ok()?.permissions().mode() & 0o777;
+        if mode & 0o077 != 0 {
+            return Some(mode);
+        }
+    }
+    #[cfg(not(unix))]
+    let _ = path;
+    None
+}
+
+pub fn config_backup_path(path: &Path) -> PathBuf {
+    PathBuf::from(format!("{}.bak", path.display()))
+}
+
+// Write a cloud config file atomically, creating parent directories as
+// needed. A valid previous version is kept as `<path>.bak`. The file holds SSH
+// credentials, so it's only readable by its owner.
+pub fn write_config_file(path: &Path, contents: &str) -> Result<()> {
+    let config_dir = match path.parent() {
+        Some(dir) if !dir.as_os_str().is_empty() => dir,
+        _ => Path::new("."),
+    };
+    if !config_dir.exists() {
+        fs::create_dir_all(config_dir).context("Failed to create config directory")?;
+    }
+
+    // Locked so a concurrent write can't land between the backup and ours
+    with_file_lock(path, true, || write_config_file_locked(path, contents))
+}
+
+// `write_config_file` for callers already holding the config's exclusive lock
+pub fn write_config_file_locked(path: &Path, contents: &str) -> Result<()> {
+    let format = ConfigFileFormat::from_path(path);
+    let previous_is_valid = fs::read_to_string(path)
+        .is_ok_and(|previous| parse_cloud_config(&previous, format).is_ok());
+    if previous_is_valid {
+        fs::copy(path, config_backup_path(path))
+            .context("Failed to back up configuration file")?;
+    }
+
+    // Tighten permissions left over from before configs were written privately
+    #[cfg(unix)]
+    if let Ok(metadata) = fs::metadata(path) {
+        use std::os::unix::fs::PermissionsExt;
+        let mut permissions = metadata.permissions();
+        permissions.set_mode(0o600);
+        fs::set_permissions(path, permissions)
+            .context("Failed to restrict configuration file permissions")?;
+    }
+
+    write_file_atomic(path, contents.as_bytes())
+        .context("Failed to write configuration file")
+}
+
+// Service rows are matched with their host name alongside the service's own
+// fields, so `--selector host=web-1` works too
+fn service_matches(host: &str, service: &ServiceStatus, selectors: &[Selector]) -> bool {
+    let Ok(mut row) = serde_json::to_value(service) else {
+        return false;
+    };
+    row["host"] = serde_json::Value::String(host.to_string());
+    matches_all(&row, selectors)
+}
+
+// Check a host entry before it's written to the config, rejecting duplicate
+// names among `others` (the remaining configured hosts)
+pub fn validate_ssh_host(host: &SshHost, others: &[SshHost]) -> Result<()> {
+    if host.name.trim().is_empty() {
+        anyhow::bail!("Host name cannot be empty");
+    }
+    if host.hostname.trim().is_empty() {
+        anyhow::bail!("Hostname cannot be empty");
+    }
+    if host.username.trim().is_empty() {
+        anyhow::bail!("SSH username cannot be empty");
+    }
+    if host.port == 0 {
+        anyhow::bail!("SSH port must be between 1 and 65535");
+    }
+    if others.iter().any(|other| other.name == host.name) {
+        anyhow::bail!("A host named '{}' already exists", host.name);
+    }
+    Ok(())
+}
+
+// Lifecycle operations of `omni service`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ServiceAction {
+    Restart,
+    Stop,
+    Start,
+}
+
+impl ServiceAction {
+    // Endpoint path segment, also used in messages
+    fn verb(self) -> &'static str {
+        match self {
+            ServiceAction::Restart => "restart",
+            ServiceAction::Stop => "stop",
+            ServiceAction::Start => "start",
+        }
+    }
+
+    fn noun(self) -> &'static str {
+        match self {
+            ServiceAction::Restart => "Restart",
+            ServiceAction::Stop => "Stop",
+            ServiceAction::Start => "Start",
+        }
+    }
+
+    fn progressive(self) -> &'static str {
+        match self {
+            ServiceAction::Restart => "Restarting",
+            ServiceAction::Stop => "Stopping",
+            ServiceAction::Start => "Starting",
+        }
+    }
+
+    fn past(self) -> &'static str {
+        match self {
+            ServiceAction::Restart => "restarted",
+            ServiceAction::Stop => "stopped",
+            ServiceAction::Start => "started",
+        }
+    }
+
+    fn icon(self) -> &'static str {
+        match self {
+            ServiceAction::Restart => "🔄",
+            ServiceAction::Stop => "⏹️ ",
+            ServiceAction::Start => "▶️ ",
+        }
+    }
+
+    // Service status the server reports once the action has taken effect
+    fn target_status(self) -> &'static str {
+        match self {
+            ServiceAction::Restart | ServiceAction::Start => "Running",
+            ServiceAction::Stop => "Stopped",
+        }
+    }
+}
+
+// Outcome of reading the `data` field of a status response
+pub enum StatusData<T> {
+    Available(T),
+    Missing,
+    Malformed,
 }
 
 #[derive(Tabled)]
@@ -96,6 +447,44 @@ struct ServiceStatusDisplay {
     memory: String,
 }
 
+#[derive(Tabled)]
+struct ServiceStatusWideDisplay {
+    #[tabled(rename = "Host")]
+    host: String,
+    #[tabled(rename = "Service")]
+    service: String,
+    #[tabled(rename = "Status")]
+    status: String,
+    #[tabled(rename = "Uptime")]
+    uptime: String,
+    #[tabled(rename = "CPU")]
+    cpu: String,
+    #[tabled(rename = "Memory")]
+    memory: String,
+    #[tabled(rename = "Restarts")]
+    restarts: String,
+    #[tabled(rename = "Ports")]
+    ports: String,
+    #[tabled(rename = "Image Tag")]
+    image_tag: String,
+}
+
+#[derive(Tabled)]
+struct HostWideDisplay {
+    #[tabled(rename = "Name")]
+    name: String,
+    #[tabled(rename = "Hostname")]
+    hostname: String,
+    #[tabled(rename = "Status")]
+    status: String,
+    #[tabled(rename = "Region")]
+    region: String,
+    #[tabled(rename = "Bastion")]
+    is_bastion: String,
+    #[tabled(rename = "Last Seen")]
+    last_seen: String,
+}
+
 impl From<&SshHost> for SshHostDisplay {
     fn from(host: &SshHost) -> Self {
         SshHostDisplay {
@@ -114,210 +503,454 @@ impl From<&SshHost> for SshHostDisplay {
 }
 
 impl PremiumUI {
-    pub async fn init_environment(&self) -> Result<()> {
-        let config_dir = "config";
-        let config_path = format!("{}/cloud-config.json", config_dir);
-        let config = if Path::new(&config_path).exists() {
-            println!(
-                "\n{}",
-                style("📋 Using existing configuration").cyan().bold()
-            );
-            let config_json =
-                fs::read_to_string(&config_path).context("Failed to read configuration file")?;
-            let config: CloudConfig =
-                serde_json::from_str(&config_json).context("Failed to parse configuration")?;
+    // Parse the `data` field of a status response, keeping a missing field
+    // distinct from one the CLI doesn't understand
+    pub fn parse_status_data<T: DeserializeOwned>(
+        &self,
+        data: Option<serde_json::Value>,
+    ) -> StatusData<T> {
+        match data {
+            None => StatusData::Missing,
+            Some(value) => match serde_json::from_value::<T>(value) {
+                Ok(parsed) => StatusData::Available(parsed),
+                Err(err) => {
+                    if self.debug {
+                        eprintln!(
+                            "{}",
+                            style(format!("[debug] Failed to parse status data: {}", err)).dim()
+                        );
+                    }
+                    StatusData::Malformed
+                }
+            },
+        }
+    }
 
-            // Display summary of loaded configuration
-            println!("Company: {}", style(&config.company_name).green());
-            println!("Cloud Name: {}", style(&config.cloud_name).green());
-            println!("SSH Hosts: {}", style(config.ssh_hosts.len()).green());
+    // Location of the cloud configuration: --config if given, otherwise
+    // config/cloud-config.json, resolved against --cwd. A YAML config is
+    // picked up when there's no JSON one.
+    pub fn cloud_config_path(&self) -> PathBuf {
+        if let Some(path) = &self.config_path {
+            return self.resolve_path(path);
+        }
+        let json_path = self.resolve_path("config/cloud-config.json");
+        if json_path.exists() {
+            return json_path;
+        }
+        ["config/cloud-config.yaml", "config/cloud-config.yml"]
+            .iter()
+            .map(|path| self.resolve_path(path))
+            .find(|path| path.exists())
+            .unwrap_or(json_path)
+    }
 
-            config
-        } else {
-            println!(
-                "\n{}",
-                style("🚀 Cloud Environment Configuration").cyan().bold()
-            );
-            println!(
-                "{}",
-                style("This wizard will help you configure your self-hosted cloud environment.")
-                    .dim()
-            );
+    // Load the cloud configuration written by `omni init`
+    pub fn load_cloud_config(&self) -> Result<CloudConfig> {
+        let config_path = self.cloud_config_path();
+        let config_json = with_file_lock(&config_path, false, || fs::read_to_string(&config_path))
+            .context("Failed to read configuration file")?;
+        let format = ConfigFileFormat::from_path(&config_path);
+        let config = parse_cloud_config(&config_json, format).map_err(|problem| {
+            anyhow::anyhow!(
+                "Configuration file {} is unusable: {}. Run 'omni init' to repair it.",
+                config_path.display(),
+                problem
+            )
+        })?;
+        resolve_env_references(config)
+    }
 
-            // Basic cloud platform configuration
-            let company_name: String = Input::with_theme(&self.theme)
-                .with_prompt("Company name")
-                .interact_text()?;
+    // Offer to recover from an empty or corrupt config during init. Returns
+    // the config to continue with, or `None` to run the wizard from scratch.
+    fn recover_cloud_config(&self, config_path: &Path) -> Result<Option<CloudConfig>> {
+        let config_json = with_file_lock(config_path, false, || fs::read_to_string(config_path))
+            .context("Failed to read configuration file")?;
+        let format = ConfigFileFormat::from_path(config_path);
+        let problem = match parse_cloud_config(&config_json, format) {
+            Ok(config) => return Ok(Some(config)),
+            Err(problem) => problem,
+        };
 
-            let admin_name: String = Input::with_theme(&self.theme)
-                .with_prompt("Your name (admin)")
-                .interact_text()?;
+        println!(
+            "\n{}",
+            style(format!(
+                "⚠️  The configuration at {} can't be used: {}.",
+                config_path.display(),
+                problem
+            ))
+            .yellow()
+            .bold()
+        );
 
-            let cloud_name: String = Input::with_theme(&self.theme)
-                .with_prompt("Cloud platform name")
-                .default(format!(
-                    "{}-cloud",
-                    company_name.to_lowercase().replace(" ", "-")
-                ))
-                .interact_text()?;
+        let backup_path = config_backup_path(config_path);
+        let backup = fs::read_to_string(&backup_path)
+            .ok()
+            .and_then(|contents| parse_cloud_config(&contents, format).ok());
 
-            // Fetch regions from API
-            println!("{}", style("Fetching available regions...").dim());
-            let regions_response = match self.api_client.get::<Vec<types::region::Region>>("/regions").await {
-                Ok(response) => {
-                    response
-                },
-                Err(err) => {
-                    println!("{}", style("Failed to fetch regions from API").red());
-                    println!("{}", style(format!("Error: {:?}", err)).red());
-                    return Err(anyhow::anyhow!("Failed to fetch regions from API: {}", err));
-                }
-            };
+        let mut options = Vec::new();
+        if backup.is_some() {
+            options.push(format!("Restore from backup ({})", backup_path.display()));
+        }
+        options.push("Re-run the setup wizard".to_string());
+        options.push("Cancel".to_string());
+
+        let selection = Select::with_theme(&self.theme)
+            .with_prompt("How would you like to continue?")
+            .items(&options)
+            .default(0)
+            .interact()?;
+
+        match (backup, options[selection].as_str()) {
+            (Some(config), choice) if choice.starts_with("Restore") => {
+                fs::copy(&backup_path, config_path)
+                    .context("Failed to restore configuration from backup")?;
+                println!("{}", style("✅ Configuration restored from backup").green());
+                Ok(Some(config))
+            }
+            (_, "Re-run the setup wizard") => Ok(None),
+            _ => Err(anyhow::anyhow!(
+                "Initialization cancelled. Fix or remove {} and try again.",
+                config_path.display()
+            )),
+        }
+    }
+
+    // Prompt for an SSH host's details, pre-filling answers from `current`
+    pub fn prompt_ssh_host(&self, current: Option<&SshHost>) -> Result<SshHost> {
+        let mut name_input =
+            Input::<String>::with_theme(&self.theme).with_prompt("Host name (identifier)");
+        if let Some(current) = current {
+            name_input = name_input.default(current.name.clone());
+        }
+        let name = name_input.interact_text()?;
+
+        let mut hostname_input =
+            Input::<String>::with_theme(&self.theme).with_prompt("Hostname or IP address");
+        if let Some(current) = current {
+            hostname_input = hostname_input.default(current.hostname.clone());
+        }
+        let hostname = hostname_input.interact_text()?;
 
-            if regions_response.is_empty() {
-                println!("{}", style("No regions found. Using default region.").yellow());
+        let username: String = Input::with_theme(&self.theme)
+            .with_prompt("SSH username")
+            .default(current.map_or_else(|| "root".into(), |h| h.username.clone()))
+            .interact_text()?;
+
+        let port: u16 = Input::with_theme(&self.theme)
+            .with_prompt("SSH port")
+            .default(current.map_or(22, |h| h.port))
+            .interact_text()?;
+
+        let use_identity_file = Confirm::with_theme(&self.theme)
+            .with_prompt("Use identity file for authentication? (If no you will be prompted for the password)")
+            .default(current.is_none_or(|h| h.identity_file.is_some()))
+            .interact()?;
+
+        let mut identity_file: Option<String> = None;
+        let mut password: Option<String> = None;
+        if use_identity_file {
+            let default = current
+                .and_then(|h| h.identity_file.clone())
+                .unwrap_or_else(|| "~/.ssh/id_rsa".into());
+            identity_file = Some(self.prompt_identity_file(default)?);
+        } else {
+            // The stored password is encrypted, so it's kept when left empty
+            // rather than shown as the default
+            let current_password = current.and_then(|h| h.password.clone());
+            let input_password = Password::with_theme(&self.theme)
+                .with_prompt(match current_password {
+                    Some(_) => "SSH password (leave empty to keep the current one)",
+                    None => "SSH password",
+                })
+                .allow_empty_password(current_password.is_some())
+                .interact()?;
+            password = if input_password.is_empty() {
+                current_password
             } else {
+                Some(input_password)
+            };
+        };
+
+        let is_bastion = Confirm::with_theme(&self.theme)
+            .with_prompt("Is this a bastion/jump host?")
+            .default(current.is_some_and(|h| h.is_bastion))
+            .interact()?;
+
+        Ok(SshHost {
+            name,
+            hostname,
+            username,
+            password,
+            port,
+            identity_file,
+            is_bastion,
+        })
+    }
+
+    // Ask for an identity file until one resolves to a readable file, warning
+    // about keys that ssh would reject for their permissions
+    fn prompt_identity_file(&self, default: String) -> Result<String> {
+        loop {
+            let input: String = Input::with_theme(&self.theme)
+                .with_prompt("Path to identity file")
+                .default(default.clone())
+                .validate_with(|input: &String| resolve_identity_file(input).map(|_| ()))
+                .interact_text()?;
+            let path = resolve_identity_file(&input).map_err(|problem| anyhow::anyhow!(problem))?;
+
+            if let Some(mode) = loose_key_permissions(&path) {
                 println!(
                     "{}",
-                    style(format!("Found {} regions", regions_response.len())).green()
+                    style(format!(
+                        "⚠️  {} has mode {:o}; ssh expects 600 or stricter for private keys.",
+                        path.display(),
+                        mode
+                    ))
+                    .yellow()
                 );
+                let use_anyway = Confirm::with_theme(&self.theme)
+                    .with_prompt("Use it anyway?")
+                    .default(false)
+                    .interact()?;
+                if !use_anyway {
+                    continue;
+                }
             }
+            return Ok(path.display().to_string());
+        }
+    }
 
-            // Create list of region names from API response
-            let mut regions: Vec<String> = regions_response
-                .iter()
-            //    .filter(|r| r.status == "active")
-                .map(|r| r.name.clone())
-                .collect();
-            regions.push("custom".to_string());
-            let region_selection = Select::with_theme(&self.theme)
-                .with_prompt("Select primary region")
-                .items(&regions)
-                .default(0)
-                .interact()?;
-
-            let region = if regions[region_selection] == "custom" {
-                Input::with_theme(&self.theme)
-                    .with_prompt("Enter custom region")
-                    .interact_text()?
-            } else {
-                regions[region_selection].to_string()
-            };
+    // Ask which region the cloud runs in, from the regions the API knows of
+    // or a custom one
+    async fn prompt_region(&self) -> Result<String> {
+        self.info("Fetching available regions...");
+        let regions_response = match self.api_client.get_cached::<Vec<types::region::Region>>("/regions").await {
+            Ok(response) => {
+                response
+            },
+            Err(err) => {
+                println!("{}", style("Failed to fetch regions from API").red());
+                println!("{}", style(format!("Error: {:#}", err)).red());
+                return Err(anyhow::anyhow!("Failed to fetch regions from API: {}", err));
+            }
+        };
 
-            // SSH hosts configuration
-            let mut ssh_hosts = Vec::new();
-            println!("\n{}", style("📡 SSH Host Configuration").cyan().bold());
+        if regions_response.is_empty() {
+            println!("{}", style("No regions found. Using default region.").yellow());
+        } else {
             println!(
                 "{}",
-                style("Configure SSH hosts for your cloud environment").dim()
+                style(format!("Found {} regions", regions_response.len())).green()
             );
+        }
 
-            loop {
-                // Display current hosts if any exist
-                if !ssh_hosts.is_empty() {
-                    println!("\n{}", style("Current SSH Hosts:").cyan());
+        // Create list of region names from API response
+        let mut regions: Vec<String> = regions_response
+            .iter()
+        //    .filter(|r| r.status == "active")
+            .map(|r| r.name.clone())
+            .collect();
+        regions.push("custom".to_string());
+        let region_selection = Select::with_theme(&self.theme)
+            .with_prompt("Select primary region")
+            .items(&regions)
+            .default(0)
+            .interact()?;
 
-                    let display_hosts: Vec<SshHostDisplay> =
-                        ssh_hosts.iter().map(SshHostDisplay::from).collect();
+        Ok(if regions[region_selection] == "custom" {
+            Input::with_theme(&self.theme)
+                .with_prompt("Enter custom region")
+                .interact_text()?
+        } else {
+            regions[region_selection].to_string()
+        })
+    }
 
-                    let table = Table::new(display_hosts).to_string();
-                    println!("{}", table);
-                }
+    // The wizard's host loop: add SSH hosts one at a time until the user is
+    // done
+    fn prompt_ssh_hosts(&self, ssh_hosts: &mut Vec<SshHost>) -> Result<()> {
+        println!("\n{}", style("📡 SSH Host Configuration").cyan().bold());
+        self.info("Configure SSH hosts for your cloud environment");
 
-                // Ask if user wants to add a host
-                let add_host = Confirm::with_theme(&self.theme)
-                    .with_prompt("Would you like to add an SSH host?")
-                    .default(true)
-                    .interact()?;
+        loop {
+            // Display current hosts if any exist
+            if !ssh_hosts.is_empty() {
+                println!("\n{}", style("Current SSH Hosts:").cyan());
 
-                if !add_host {
-                    break;
-                }
+                let display_hosts: Vec<SshHostDisplay> =
+                    ssh_hosts.iter().map(SshHostDisplay::from).collect();
+
+                let table = self.render_table(Table::new(display_hosts));
+                println!("{}", table);
+            }
 
-                // Host details
-                let host_name: String = Input::with_theme(&self.theme)
-                    .with_prompt("Host name (identifier)")
-                    .interact_text()?;
+            // Ask if user wants to add a host
+            let add_host = Confirm::with_theme(&self.theme)
+                .with_prompt("Would you like to add an SSH host?")
+                .default(true)
+                .interact()?;
 
-                let hostname: String = Input::with_theme(&self.theme)
-                    .with_prompt("Hostname or IP address")
-                    .interact_text()?;
+            if !add_host {
+                break;
+            }
 
-                let username: String = Input::with_theme(&self.theme)
-                    .with_prompt("SSH username")
-                    .default("root".into())
-                    .interact_text()?;
+            // Host details
+            let host = self.prompt_ssh_host(None)?;
+            if let Err(err) = validate_ssh_host(&host, ssh_hosts) {
+                println!("{}", style(format!("❌ {}", err)).red());
+                continue;
+            }
 
-                let port: u16 = Input::with_theme(&self.theme)
-                    .with_prompt("SSH port")
-                    .default(22)
-                    .interact_text()?;
+            // Add the host to our list
+            ssh_hosts.push(host);
 
-                let use_identity_file = Confirm::with_theme(&self.theme)
-                    .with_prompt("Use identity file for authentication? (If no you will be prompted for the password)")
-                    .default(true)
-                    .interact()?;
+            println!("{}", style("✅ SSH host added successfully").green());
+        }
+        Ok(())
+    }
 
-                let mut identity_file: Option<String> = None;
-                let mut password: Option<String> = None;
-                if use_identity_file {
-                    identity_file = Some(
-                        Input::with_theme(&self.theme)
-                            .with_prompt("Path to identity file")
-                            .default("~/.ssh/id_rsa".into())
-                            .interact_text()?,
-                    );
-                } else {
-                    let input_password = Input::with_theme(&self.theme)
-                        .with_prompt("SSH password")
-                        .default("".into())
-                        .interact_text()?;
-                    password = Some(input_password);
-                };
+    pub async fn init_environment(&self, matches: &ArgMatches) -> Result<()> {
+        let config_path = match (
+            matches.get_one::<String>("output-config"),
+            matches.get_one::<String>("config-format").map(String::as_str),
+        ) {
+            (Some(path), _) => self.resolve_path(path),
+            (None, _) if self.config_path.is_some() => self.cloud_config_path(),
+            (None, Some("yaml")) => self.resolve_path("config/cloud-config.yaml"),
+            (None, Some(_)) => self.resolve_path("config/cloud-config.json"),
+            (None, None) => self.cloud_config_path(),
+        };
+        if matches.get_flag("validate-only") {
+            return self.init_validate(&config_path).await;
+        }
+        let max_parallel_hosts = matches.get_one::<u32>("max-parallel-hosts").copied();
+        let force = matches.get_flag("force");
+        let import_path = matches.get_one::<String>("from").map(|path| self.resolve_path(path));
+        if import_path.is_some() && config_path.exists() && !force {
+            return Err(anyhow::anyhow!(
+                "{} already exists. Add --force to replace it with the imported hosts.",
+                config_path.display()
+            ));
+        }
+        let existing = if config_path.exists() && !force {
+            self.recover_cloud_config(&config_path)?
+                .map(resolve_env_references)
+                .transpose()?
+        } else {
+            None
+        };
+        if force && config_path.exists() {
+            println!(
+                "{}",
+                style(format!(
+                    "--force: ignoring {}. It's kept as {} once the new configuration is saved.",
+                    config_path.display(),
+                    config_backup_path(&config_path).display()
+                ))
+                .yellow()
+            );
+        }
+        let using_existing = existing.is_some();
+        let config = if let Some(config) = existing {
+            println!(
+                "\n{}",
+                style("📋 Using existing configuration").cyan().bold()
+            );
 
-                let is_bastion = Confirm::with_theme(&self.theme)
-                    .with_prompt("Is this a bastion/jump host?")
-                    .default(false)
-                    .interact()?;
+            // Display summary of loaded configuration
+            println!("Company: {}", style(&config.company_name).green());
+            println!("Cloud Name: {}", style(&config.cloud_name).green());
+            println!("SSH Hosts: {}", style(config.ssh_hosts.len()).green());
+            self.info("Run 'omni init --force' to start over with a new configuration.");
 
-                // Add the host to our list
-                ssh_hosts.push(SshHost {
-                    name: host_name,
-                    hostname,
-                    username,
-                    password,
-                    port,
-                    identity_file,
-                    is_bastion,
-                });
-
-                println!("{}", style("✅ SSH host added successfully").green());
-            }
+            config
+        } else {
+            println!(
+                "\n{}",
+                style("🚀 Cloud Environment Configuration").cyan().bold()
+            );
+            self.info("This wizard will help you configure your self-hosted cloud environment.");
 
-            // Additional configuration options
-            println!("\n{}", style("⚙️ Additional Configuration").cyan().bold());
+            // Hosts (and possibly other answers) from `--from`; whatever the
+            // file doesn't supply is asked for below
+            let mut imported = match &import_path {
+                Some(path) => self.import_hosts(path)?,
+                None => ImportedConfig::default(),
+            };
 
-            let options = vec!["Enable system monitoring", "Enable automated backups"];
-            let defaults = vec![true, true];
+            // Basic cloud platform configuration
+            let company_name: String = match imported.company_name.take() {
+                Some(company_name) => company_name,
+                None => Input::with_theme(&self.theme)
+                    .with_prompt("Company name")
+                    .interact_text()?,
+            };
 
-            let selections = MultiSelect::with_theme(&self.theme)
-                .with_prompt("Select additional services to enable")
-                .items(&options)
-                .defaults(&defaults)
-                .interact()?;
+            let admin_name: String = match imported.admin_name.take() {
+                Some(admin_name) => admin_name,
+                None => Input::with_theme(&self.theme)
+                    .with_prompt("Your name (admin)")
+                    .interact_text()?,
+            };
 
-            let enable_monitoring = selections.contains(&0);
-            let enable_backups = selections.contains(&1);
+            let cloud_name: String = match imported.cloud_name.take() {
+                Some(cloud_name) => cloud_name,
+                None => Input::with_theme(&self.theme)
+                    .with_prompt("Cloud platform name")
+                    .default(format!(
+                        "{}-cloud",
+                        company_name.to_lowercase().replace(" ", "-")
+                    ))
+                    .interact_text()?,
+            };
+
+            let region = match imported.region.take() {
+                Some(region) => region,
+                None => self.prompt_region().await?,
+            };
 
-            let backup_retention_days = if enable_backups {
-                Input::with_theme(&self.theme)
+            // SSH hosts configuration
+            let mut ssh_hosts = std::mem::take(&mut imported.ssh_hosts);
+            if import_path.is_none() {
+                self.prompt_ssh_hosts(&mut ssh_hosts)?;
+            }
+
+            // Additional configuration options
+            let (enable_monitoring, enable_backups) =
+                match (imported.enable_monitoring, imported.enable_backups) {
+                    (Some(enable_monitoring), Some(enable_backups)) => {
+                        (enable_monitoring, enable_backups)
+                    }
+                    (enable_monitoring, enable_backups) => {
+                        println!("\n{}", style("⚙️ Additional Configuration").cyan().bold());
+
+                        let options = vec!["Enable system monitoring", "Enable automated backups"];
+                        let defaults = vec![
+                            enable_monitoring.unwrap_or(true),
+                            enable_backups.unwrap_or(true),
+                        ];
+
+                        let selections = MultiSelect::with_theme(&self.theme)
+                            .with_prompt("Select additional services to enable")
+                            .items(&options)
+                            .defaults(&defaults)
+                            .interact()?;
+                        (selections.contains(&0), selections.contains(&1))
+                    }
+                };
+
+            let backup_retention_days = match imported.backup_retention_days {
+                Some(days) => days,
+                None if enable_backups => Input::with_theme(&self.theme)
                     .with_prompt("Backup retention period (days)")
                     .default(30)
-                    .interact_text()?
-            } else {
-                7 // Default value if backups are not enabled
+                    .interact_text()?,
+                None => 7, // Default value if backups are not enabled
             };
 
+            // SSH passwords are only stored encrypted
+            self.encrypt_host_passwords(&mut ssh_hosts)?;
+
             // Create configuration object
             let config = CloudConfig {
                 company_name,
@@ -333,16 +966,16 @@ impl PremiumUI {
             // Save configuration
             println!("\n{}", style("💾 Saving Configuration").cyan().bold());
 
-            if !Path::new(config_dir).exists() {
-                fs::create_dir(config_dir).context("Failed to create config directory")?;
-            }
-
-            let config_json = serde_json::to_string_pretty(&config)?;
-            fs::write(&config_path, config_json).context("Failed to write configuration file")?;
+            let config_contents = ConfigFileFormat::from_path(&config_path).serialize(&config)?;
+            write_config_file(&config_path, &config_contents)?;
 
             println!(
                 "{}",
-                style(format!("✅ Configuration saved to {}", config_path)).green()
+                style(format!(
+                    "✅ Configuration saved to {}",
+                    config_path.display()
+                ))
+                .green()
             );
 
             // Summary
@@ -384,14 +1017,10 @@ impl PremiumUI {
             "\n{}",
             style("⚡ Bootstrapping OmniOrchestrator").cyan().bold()
         );
-        println!(
-            "{}",
-            style(format!(
-                "Setting up OmniOrchestrator for {} cloud environment",
-                config.cloud_name
-            ))
-            .dim()
-        );
+        self.info(format!(
+            "Setting up OmniOrchestrator for {} cloud environment",
+            config.cloud_name
+        ));
 
         // Check if there are SSH hosts configured
         if config.ssh_hosts.is_empty() {
@@ -402,19 +1031,35 @@ impl PremiumUI {
             return Ok(());
         }
 
-        // Confirm before proceeding
-        let confirm = Confirm::with_theme(&self.theme)
-            .with_prompt("Ready to bootstrap OmniOrchestrator on all configured hosts?")
-            .default(true)
-            .interact()?;
+        // Confirm before proceeding. An existing config has usually been
+        // bootstrapped already, so re-running it is offered rather than assumed.
+        let confirm = if using_existing {
+            Select::with_theme(&self.theme)
+                .with_prompt("This configuration was loaded from disk. What would you like to do?")
+                .items(&["Re-run bootstrap on all configured hosts", "Exit"])
+                .default(0)
+                .interact()?
+                == 0
+        } else {
+            Confirm::with_theme(&self.theme)
+                .with_prompt("Ready to bootstrap OmniOrchestrator on all configured hosts?")
+                .default(true)
+                .interact()?
+        };
 
         if !confirm {
             println!("{}", style("Bootstrapping cancelled.").yellow());
             return Ok(());
         }
 
+        if !self.preflight_ssh_hosts(&config.ssh_hosts).await? {
+            println!("{}", style("Bootstrapping cancelled.").yellow());
+            return Ok(());
+        }
+
         // Bootstrap the orchestrator using server-driven approach
-        self.bootstrap_orchestrator(&config).await?;
+        self.bootstrap_orchestrator(&config, max_parallel_hosts)
+            .await?;
 
         println!(
             "\n{}",
@@ -422,19 +1067,17 @@ impl PremiumUI {
                 .green()
                 .bold()
         );
-        println!(
-            "{}",
-            style("Your OmniOrchestrator cloud environment is ready.").dim()
-        );
-        println!(
-            "{}",
-            style("You can now deploy applications with 'omni deploy'.").dim()
-        );
+        self.info("Your OmniOrchestrator cloud environment is ready.");
+        self.info("You can now deploy applications with 'omni deploy'.");
 
         Ok(())
     }
 
-    async fn bootstrap_orchestrator(&self, config: &CloudConfig) -> Result<()> {
+    async fn bootstrap_orchestrator(
+        &self,
+        config: &CloudConfig,
+        max_parallel_hosts: Option<u32>,
+    ) -> Result<()> {
         println!(
             "\n{}",
             style(format!(
@@ -453,7 +1096,7 @@ impl PremiumUI {
             admin_name: config.admin_name.clone(),
             cloud_name: config.cloud_name.clone(),
             region: config.region.clone(),
-            ssh_hosts: config.ssh_hosts.clone(),
+            ssh_hosts: self.decrypt_host_passwords(&config.ssh_hosts)?,
             enable_monitoring: config.enable_monitoring,
             enable_backups: config.enable_backups,
             backup_retention_days: config.backup_retention_days,
@@ -461,13 +1104,19 @@ impl PremiumUI {
 
         match self
             .api_client
-            .post::<_, ApiResponse>("/platforms/init", &api_config)
+            .post::<_, ApiResponse>(
+                "/platforms/init",
+                &PlatformInitRequest {
+                    config: &api_config,
+                    max_parallel_hosts,
+                },
+            )
             .await
         {
             Err(err) => {
                 println!("{}", style("API initialization failed").red().bold());
-                println!("{}", style(format!("Error: {:?}", err)).red());
-                return Err(anyhow::anyhow!("Failed to initialize platform: {:?}", err));
+                println!("{}", style(format!("Error: {:#}", err)).red());
+                return Err(anyhow::anyhow!("Failed to initialize platform: {:#}", err));
             }
             Ok(response) => {
                 println!("{}", style("Configuration sent successfully ✓").green());
@@ -488,7 +1137,18 @@ impl PremiumUI {
         );
 
         let mut prev_lines = 0;
+        let deadline = self.wait_deadline(WaitKind::Bootstrap);
+        let started = Instant::now();
+        let mut last_status: Option<Vec<HostDeploymentStatus>> = None;
         while !all_complete {
+            if deadline.is_some_and(|deadline| Instant::now() >= deadline) {
+                return Err(self.wait_timed_out(
+                    "the platform bootstrap",
+                    started.elapsed(),
+                    last_status.as_ref(),
+                ));
+            }
+
             match self
                 .api_client
                 .get::<ApiResponse>(&format!("/platforms/{}/status", cloud_name))
@@ -499,7 +1159,9 @@ impl PremiumUI {
                         "{}",
                         style("Failed to get deployment status: ").red().bold()
                     );
-                    println!("{}", style(format!("{:?}", err)).red());
+                    println!("{}", style(format!("{:#}", err)).red());
+                    // Keep the error on screen rather than clearing over it
+                    prev_lines = 0;
                     // Wait before retrying
                     tokio::time::sleep(Duration::from_secs(2)).await;
                 }
@@ -508,19 +1170,54 @@ impl PremiumUI {
                         all_complete = true;
                         continue;
                     }
+                    if response.status == "failed" {
+                        return Err(anyhow::anyhow!(
+                            "The server reported that the platform bootstrap failed: {}",
+                            response.message
+                        ));
+                    }
 
-                    // Extract host statuses from response data
-                    if let Some(data) = response.data {
-                        if let Ok(host_statuses) =
-                            serde_json::from_value::<Vec<HostDeploymentStatus>>(data)
-                        {
-                            // Clear previous status lines
-                            if prev_lines > 0 {
-                                print!("\x1B[{}A\x1B[J", prev_lines);
-                            }
+                    // Clear previous status lines
+                    if prev_lines > 0 {
+                        print!("\x1B[{}A\x1B[J", prev_lines);
+                    }
 
+                    // Extract host statuses from response data
+                    match self.parse_status_data::<Vec<HostDeploymentStatus>>(response.data) {
+                        StatusData::Missing => {
+                            self.info("Status unavailable, waiting for update...");
+                            // Nothing is printed with --quiet, so nothing to clear
+                            prev_lines = if self.quiet { 0 } else { 1 };
+                        }
+                        StatusData::Malformed => {
+                            println!(
+                                "{}",
+                                style("Unexpected server response while reading deployment status")
+                                    .yellow()
+                            );
+                            prev_lines = 1;
+                        }
+                        StatusData::Available(host_statuses) => {
                             // Display current status for each host
-                            println!("{}", style("Current deployment status:").cyan());
+                            match max_parallel_hosts {
+                                Some(limit) => {
+                                    let active = host_statuses
+                                        .iter()
+                                        .filter(|h| h.status == "in_progress")
+                                        .count();
+                                    println!(
+                                        "{}",
+                                        style(format!(
+                                            "Current deployment status ({}/{} hosts in progress):",
+                                            active, limit
+                                        ))
+                                        .cyan()
+                                    );
+                                }
+                                None => {
+                                    println!("{}", style("Current deployment status:").cyan())
+                                }
+                            }
                             for host in &host_statuses {
                                 let status_color = match host.status.as_str() {
                                     "completed" => {
@@ -571,6 +1268,7 @@ impl PremiumUI {
 
                             // Track how many lines we printed for clearing next time
                             prev_lines = host_statuses.len() + 2;
+                            last_status = Some(host_statuses);
                         }
                     }
 
@@ -590,8 +1288,8 @@ impl PremiumUI {
         {
             Err(err) => {
                 println!("{}", style("Network configuration failed ✗").red().bold());
-                println!("{}", style(format!("Error: {:?}", err)).red());
-                return Err(anyhow::anyhow!("Failed to configure network: {:?}", err));
+                println!("{}", style(format!("Error: {:#}", err)).red());
+                return Err(anyhow::anyhow!("Failed to configure network: {:#}", err));
             }
             Ok(response) => {
                 println!("{}", style("Network configuration initiated ✓").green());
@@ -617,8 +1315,8 @@ impl PremiumUI {
             {
                 Err(err) => {
                     println!("{}", style("Monitoring setup failed ✗").red().bold());
-                    println!("{}", style(format!("Error: {:?}", err)).red());
-                    return Err(anyhow::anyhow!("Failed to setup monitoring: {:?}", err));
+                    println!("{}", style(format!("Error: {:#}", err)).red());
+                    return Err(anyhow::anyhow!("Failed to setup monitoring: {:#}", err));
                 }
                 Ok(response) => {
                     println!("{}", style("Monitoring setup initiated ✓").green());
@@ -645,8 +1343,8 @@ impl PremiumUI {
             {
                 Err(err) => {
                     println!("{}", style("Backup setup failed ✗").red().bold());
-                    println!("{}", style(format!("Error: {:?}", err)).red());
-                    return Err(anyhow::anyhow!("Failed to setup backups: {:?}", err));
+                    println!("{}", style(format!("Error: {:#}", err)).red());
+                    return Err(anyhow::anyhow!("Failed to setup backups: {:#}", err));
                 }
                 Ok(response) => {
                     println!("{}", style("Backup setup initiated ✓").green());
@@ -678,16 +1376,14 @@ impl PremiumUI {
         process_type: &str,
     ) -> Result<()> {
         let mut complete = false;
-        let mut attempts = 0;
-        const MAX_ATTEMPTS: usize = 120; // 2 minutes with 1-second intervals
+        const TIMEOUT: Duration = Duration::from_secs(120);
+        let deadline = self.wait_deadline_or(WaitKind::Bootstrap, TIMEOUT);
+        let started = Instant::now();
+        let mut last_status: Option<Vec<HostDeploymentStatus>> = None;
 
-        println!(
-            "{}",
-            style(format!("Waiting for {} setup to complete...", process_type)).dim()
-        );
+        self.info(format!("Waiting for {} setup to complete...", process_type));
 
-        while !complete && attempts < MAX_ATTEMPTS {
-            attempts += 1;
+        while !complete && Instant::now() < deadline {
 
             match self
                 .api_client
@@ -704,12 +1400,30 @@ impl PremiumUI {
                         );
                         break;
                     }
+                    if response.status == "failed" {
+                        return Err(anyhow::anyhow!(
+                            "The server reported that {} setup failed: {}",
+                            process_type,
+                            response.message
+                        ));
+                    }
 
                     // Extract host statuses to check specific process status
-                    if let Some(data) = response.data {
-                        if let Ok(host_statuses) =
-                            serde_json::from_value::<Vec<HostDeploymentStatus>>(data)
-                        {
+                    match self.parse_status_data::<Vec<HostDeploymentStatus>>(response.data) {
+                        StatusData::Missing => {
+                            self.info(format!("{} setup: status unavailable", process_type));
+                        }
+                        StatusData::Malformed => {
+                            println!(
+                                "{}",
+                                style(format!(
+                                    "{} setup: unexpected server response, retrying",
+                                    process_type
+                                ))
+                                .yellow()
+                            );
+                        }
+                        StatusData::Available(host_statuses) => {
                             // Different processes have different indicators of completion
                             match process_type {
                                 "network" => {
@@ -730,11 +1444,7 @@ impl PremiumUI {
 
                                     // Show some progress info
                                     if let Some(host) = host_statuses.first() {
-                                        println!(
-                                            "{}",
-                                            style(format!("Network setup: {}", host.current_step))
-                                                .dim()
-                                        );
+                                        self.info(format!("Network setup: {}", host.current_step));
                                     }
                                 }
                                 "monitoring" => {
@@ -759,14 +1469,10 @@ impl PremiumUI {
                                         .iter()
                                         .find(|h| h.current_step.contains("monitoring"))
                                     {
-                                        println!(
-                                            "{}",
-                                            style(format!(
-                                                "Monitoring setup: {}",
-                                                host.current_step
-                                            ))
-                                            .dim()
-                                        );
+                                        self.info(format!(
+                                            "Monitoring setup: {}",
+                                            host.current_step
+                                        ));
                                     }
                                 }
                                 "backups" => {
@@ -798,11 +1504,7 @@ impl PremiumUI {
                                         .iter()
                                         .find(|h| h.current_step.contains("backup"))
                                     {
-                                        println!(
-                                            "{}",
-                                            style(format!("Backup setup: {}", host.current_step))
-                                                .dim()
-                                        );
+                                        self.info(format!("Backup setup: {}", host.current_step));
                                     }
                                 }
                                 _ => {
@@ -818,13 +1520,14 @@ impl PremiumUI {
                                     }
                                 }
                             }
+                            last_status = Some(host_statuses);
                         }
                     }
                 }
                 Err(err) => {
                     println!(
                         "{}",
-                        style(format!("Error polling status: {:?}", err)).yellow()
+                        style(format!("Error polling status: {:#}", err)).yellow()
                     );
                 }
             }
@@ -833,17 +1536,59 @@ impl PremiumUI {
         }
 
         if !complete {
-            println!("{}", style(format!("Timed out waiting for {} to complete. The process may still be running on the server.", process_type)).yellow());
+            return Err(self.wait_timed_out(
+                &format!("{} setup", process_type),
+                started.elapsed(),
+                last_status.as_ref(),
+            ));
         }
 
         Ok(())
     } // End of function
 
+    // Config summary plus live host status, for --output json|yaml
+    pub async fn platform_status_report(&self) -> Result<PlatformStatusReport> {
+        if !self.cloud_config_path().exists() {
+            anyhow::bail!("No cloud configuration found. Run 'omni init' first.");
+        }
+        let config = self.load_cloud_config()?;
+
+        let response = self
+            .api_client
+            .get::<ApiResponse>(&format!("/platforms/{}/status", config.cloud_name))
+            .await
+            .context("Failed to get status from API")?;
+        let status = match self.parse_status_data::<Vec<HostDeploymentStatus>>(response.data) {
+            StatusData::Available(host_statuses) => host_statuses,
+            StatusData::Missing => Vec::new(),
+            StatusData::Malformed => anyhow::bail!(
+                "Unexpected server response: host status data could not be read."
+            ),
+        };
+
+        Ok(PlatformStatusReport {
+            company_name: config.company_name.clone(),
+            cloud_name: config.cloud_name.clone(),
+            region: config.region.clone(),
+            hosts: config.ssh_hosts.iter().map(HostSummary::from).collect(),
+            status,
+        })
+    }
+
+    pub async fn print_platform_status(&self) -> Result<()> {
+        let mut report = self.platform_status_report().await?;
+        report.status = self.select_host_statuses(report.status);
+        println!("{}", self.output.render(&report)?.trim_end());
+        Ok(())
+    }
+
     // List SSH hosts
     pub async fn list_ssh_hosts(&self) -> Result<()> {
-        let config_path = "config/cloud-config.json";
+        if self.output.is_structured() {
+            return self.print_platform_status().await;
+        }
 
-        if !Path::new(config_path).exists() {
+        if !self.cloud_config_path().exists() {
             println!(
                 "{}",
                 style("No cloud configuration found. Run 'omni init' first.").yellow()
@@ -851,10 +1596,7 @@ impl PremiumUI {
             return Ok(());
         }
 
-        let config_json =
-            fs::read_to_string(config_path).context("Failed to read configuration file")?;
-        let config: CloudConfig =
-            serde_json::from_str(&config_json).context("Failed to parse configuration")?;
+        let config = self.load_cloud_config()?;
 
         if config.ssh_hosts.is_empty() {
             println!(
@@ -879,26 +1621,33 @@ impl PremiumUI {
         {
             Err(err) => {
                 println!("{}", style("Failed to get status from API.").red());
-                println!("{}", style(format!("Error: {:?}", err)).dim());
-                return Err(anyhow::anyhow!("Failed to get status from API: {:?}", err));
+                println!("{}", style(format!("Error: {:#}", err)).dim());
+                return Err(anyhow::anyhow!("Failed to get status from API: {:#}", err));
             }
             Ok(response) => {
-                if let Some(data) = response.data {
-                    if let Ok(host_statuses) =
-                        serde_json::from_value::<Vec<HostDeploymentStatus>>(data)
-                    {
+                match self.parse_status_data::<Vec<HostDeploymentStatus>>(response.data) {
+                    StatusData::Available(host_statuses) => {
                         // Display services for each host
                         self.display_service_status(&host_statuses, &config);
-                    } else {
+                    }
+                    StatusData::Missing => {
+                        println!(
+                            "{}",
+                            style("Status unavailable: the server returned no host data.").yellow()
+                        );
+                    }
+                    StatusData::Malformed => {
                         println!(
                             "{}",
-                            style("Failed to parse host status data from API.").red()
+                            style(
+                                "Unexpected server response: host status data could not be read."
+                            )
+                            .red()
                         );
-                        return Err(anyhow::anyhow!("Failed to parse host status data"));
+                        if !self.debug {
+                            println!("{}", style("Re-run with --debug for details.").dim());
+                        }
                     }
-                } else {
-                    println!("{}", style("No status data available from API.").yellow());
-                    return Err(anyhow::anyhow!("No status data available from API"));
                 }
             }
         }
@@ -920,36 +1669,140 @@ impl PremiumUI {
         Ok(())
     }
 
+    // Apply --selector to host statuses the way the tables do: a host is kept
+    // if it matches itself or still has services that match
+    fn select_host_statuses(
+        &self,
+        host_statuses: Vec<HostDeploymentStatus>,
+    ) -> Vec<HostDeploymentStatus> {
+        if self.selectors.is_empty() {
+            return host_statuses;
+        }
+        host_statuses
+            .into_iter()
+            .filter_map(|mut host_status| {
+                let host_matches = serde_json::to_value(&host_status)
+                    .is_ok_and(|row| matches_all(&row, &self.selectors));
+                host_status
+                    .services
+                    .retain(|service| service_matches(&host_status.host, service, &self.selectors));
+                (host_matches || !host_status.services.is_empty()).then_some(host_status)
+            })
+            .collect()
+    }
+
     // Display services status from API data
-    fn display_service_status(
+    pub fn display_service_status(
         &self,
-        host_statuses: &Vec<HostDeploymentStatus>,
+        host_statuses: &[HostDeploymentStatus],
         config: &CloudConfig,
     ) {
-        let mut services_display = Vec::new();
+        print!("{}", self.render_service_status(host_statuses, config));
+    }
 
-        for host_status in host_statuses {
-            for service in &host_status.services {
-                services_display.push(ServiceStatusDisplay {
-                    host: host_status.host.clone(),
-                    service: service.name.clone(),
-                    status: service.status.clone(),
-                    uptime: service.uptime.clone().unwrap_or_else(|| "-".to_string()),
-                    cpu: service.cpu.clone().unwrap_or_else(|| "-".to_string()),
-                    memory: service.memory.clone().unwrap_or_else(|| "-".to_string()),
-                });
+    // The host and service tables plus system information, as printed by
+    // `display_service_status`
+    pub fn render_service_status(
+        &self,
+        host_statuses: &[HostDeploymentStatus],
+        config: &CloudConfig,
+    ) -> String {
+        let mut out = String::new();
+        let or_dash = |value: &Option<String>| value.clone().unwrap_or_else(|| "-".to_string());
+
+        if self.output == OutputMode::Wide {
+            let hosts_display: Vec<HostWideDisplay> = host_statuses
+                .iter()
+                .filter(|host_status| {
+                    serde_json::to_value(host_status)
+                        .is_ok_and(|row| matches_all(&row, &self.selectors))
+                })
+                .map(|host_status| {
+                    let host = config.ssh_hosts.iter().find(|h| h.name == host_status.host);
+                    HostWideDisplay {
+                        name: host_status.host.clone(),
+                        hostname: host.map_or_else(|| "-".to_string(), |h| h.hostname.clone()),
+                        status: host_status.status.clone(),
+                        region: host_status
+                            .region
+                            .clone()
+                            .unwrap_or_else(|| config.region.clone()),
+                        is_bastion: if host.is_some_and(|h| h.is_bastion) {
+                            "Yes"
+                        } else {
+                            "No"
+                        }
+                        .to_string(),
+                        last_seen: or_dash(&host_status.last_seen),
+                    }
+                })
+                .collect();
+            if !hosts_display.is_empty() {
+                let _ = writeln!(out, "{}", self.render_table(Table::new(hosts_display)));
             }
         }
 
-        if services_display.is_empty() {
-            println!("{}", style("No services found.").yellow());
+        let services = host_statuses
+            .iter()
+            .flat_map(|host_status| host_status.services.iter().map(move |s| (host_status, s)))
+            .filter(|(host_status, service)| {
+                service_matches(&host_status.host, service, &self.selectors)
+            });
+        let table = if self.output == OutputMode::Wide {
+            let services_display: Vec<ServiceStatusWideDisplay> = services
+                .map(|(host_status, service)| ServiceStatusWideDisplay {
+                    host: host_status.host.clone(),
+                    service: service.name.clone(),
+                    status: service.status.clone(),
+                    uptime: or_dash(&service.uptime),
+                    cpu: or_dash(&service.cpu),
+                    memory: or_dash(&service.memory),
+                    restarts: service
+                        .restart_count
+                        .map_or_else(|| "-".to_string(), |count| count.to_string()),
+                    ports: if service.ports.is_empty() {
+                        "-".to_string()
+                    } else {
+                        service
+                            .ports
+                            .iter()
+                            .map(|port| match port.as_str() {
+                                Some(port) => port.to_string(),
+                                None => port.to_string(),
+                            })
+                            .collect::<Vec<_>>()
+                            .join(",")
+                    },
+                    image_tag: or_dash(&service.image_tag),
+                })
+                .collect();
+            (!services_display.is_empty()).then(|| Table::new(services_display))
         } else {
-            let table = Table::new(services_display).to_string();
-            println!("{}", table);
+            let services_display: Vec<ServiceStatusDisplay> = services
+                .map(|(host_status, service)| ServiceStatusDisplay {
+                    host: host_status.host.clone(),
+                    service: service.name.clone(),
+                    status: service.status.clone(),
+                    uptime: or_dash(&service.uptime),
+                    cpu: or_dash(&service.cpu),
+                    memory: or_dash(&service.memory),
+                })
+                .collect();
+            (!services_display.is_empty()).then(|| Table::new(services_display))
+        };
+
+        match table {
+            Some(table) => {
+                let _ = writeln!(out, "{}", self.render_table(table));
+            }
+            None => {
+                let _ = writeln!(out, "{}", style("No services found.").yellow());
+            }
         }
 
-        println!("\n{}", style("🔄 System Information").cyan().bold());
-        println!(
+        let _ = writeln!(out, "\n{}", style("🔄 System Information").cyan().bold());
+        let _ = writeln!(
+            out,
             "Monitoring: {}",
             if config.enable_monitoring {
                 style("Enabled").green()
@@ -957,7 +1810,8 @@ impl PremiumUI {
                 style("Disabled").yellow()
             }
         );
-        println!(
+        let _ = writeln!(
+            out,
             "Backups: {}",
             if config.enable_backups {
                 style("Enabled").green()
@@ -966,7 +1820,8 @@ impl PremiumUI {
             }
         );
         if config.enable_backups {
-            println!(
+            let _ = writeln!(
+                out,
                 "  Retention: {} days",
                 style(config.backup_retention_days).green()
             );
@@ -985,60 +1840,128 @@ impl PremiumUI {
                         .find(|s| s.name == "backup-manager")
                     {
                         // In a real implementation, we would extract these dates from service metadata
-                        println!("  Last Backup: {}", style("From server data").green());
-                        println!("  Next Backup: {}", style("From server data").green());
+                        let _ = writeln!(
+                            out,
+                            "  Last Backup: {}",
+                            style("From server data").green()
+                        );
+                        let _ = writeln!(
+                            out,
+                            "  Next Backup: {}",
+                            style("From server data").green()
+                        );
                         break;
                     }
                 }
             }
         }
+
+        out
     }
 
     // Restart a service via API
     pub async fn restart_service(&self, host_name: &str, service_name: &str) -> Result<()> {
-        let config_path = "config/cloud-config.json";
-        let config_json =
-            fs::read_to_string(config_path).context("Failed to read configuration file")?;
-        let config: CloudConfig =
-            serde_json::from_str(&config_json).context("Failed to parse configuration")?;
+        self.run_service_action(host_name, service_name, ServiceAction::Restart)
+            .await
+    }
+
+    pub async fn stop_service(&self, host_name: &str, service_name: &str) -> Result<()> {
+        self.run_service_action(host_name, service_name, ServiceAction::Stop)
+            .await
+    }
+
+    pub async fn start_service(&self, host_name: &str, service_name: &str) -> Result<()> {
+        self.run_service_action(host_name, service_name, ServiceAction::Start)
+            .await
+    }
+
+    // Send `action` for a service and wait for it to reach the resulting
+    // state. Unknown hosts, and services the host doesn't report, are
+    // rejected before anything is sent.
+    async fn run_service_action(
+        &self,
+        host_name: &str,
+        service_name: &str,
+        action: ServiceAction,
+    ) -> Result<()> {
+        let config = self.load_cloud_config()?;
+
+        if !config.ssh_hosts.iter().any(|host| host.name == host_name) {
+            let names: Vec<&str> = config.ssh_hosts.iter().map(|h| h.name.as_str()).collect();
+            return Err(anyhow::anyhow!(
+                "No host named '{}' in the cloud config. Hosts: {}",
+                host_name,
+                names.join(", ")
+            ));
+        }
+        if let Ok(response) = self
+            .api_client
+            .get::<ApiResponse>(&format!(
+                "/platforms/{}/hosts/{}/services",
+                config.cloud_name, host_name
+            ))
+            .await
+        {
+            if let StatusData::Available(services) =
+                self.parse_status_data::<Vec<ServiceStatus>>(response.data)
+            {
+                if !services.iter().any(|s| s.name == service_name) {
+                    let names: Vec<&str> = services.iter().map(|s| s.name.as_str()).collect();
+                    return Err(anyhow::anyhow!(
+                        "Host {} has no service named '{}'. Services: {}",
+                        host_name,
+                        service_name,
+                        names.join(", ")
+                    ));
+                }
+            }
+        }
 
         println!(
             "\n{}",
             style(format!(
-                "🔄 Restarting service {} on host {}",
-                service_name, host_name
+                "{} {} service {} on host {}",
+                action.icon(),
+                action.progressive(),
+                service_name,
+                host_name
             ))
             .cyan()
             .bold()
         );
 
         match self
-            .api_client
-            .post::<_, ApiResponse>(
-                &format!(
-                    "/platforms/{}/hosts/{}/services/{}/restart",
-                    config.cloud_name, host_name, service_name
-                ),
-                &(),
-            )
+            .send_service_action(&config.cloud_name, host_name, service_name, action)
             .await
         {
             Err(err) => {
-                println!("{}", style("Failed to restart service: ").red().bold());
-                println!("{}", style(format!("{:?}", err)).red());
-                return Err(anyhow::anyhow!("Failed to restart service: {:?}", err));
+                println!(
+                    "{}",
+                    style(format!("Failed to {} service: ", action.verb()))
+                        .red()
+                        .bold()
+                );
+                println!("{}", style(format!("{:#}", err)).red());
+                return Err(anyhow::anyhow!(
+                    "Failed to {} service: {:#}",
+                    action.verb(),
+                    err
+                ));
             }
             Ok(response) => {
-                println!("{}", style("Restart request sent successfully ✓").green());
+                println!(
+                    "{}",
+                    style(format!("{} request sent successfully ✓", action.noun())).green()
+                );
                 println!(
                     "{}",
                     style(format!("API response: {}", response.message)).green()
                 );
 
-                // Wait for service to restart by polling the host services endpoint
-                println!("{}", style("Waiting for service to restart...").dim());
+                // Wait for the new state by polling the host services endpoint
+                self.info(format!("Waiting for service to {}...", action.verb()));
 
-                self.wait_for_service_restart(&config.cloud_name, host_name, service_name)
+                self.wait_for_service_state(&config.cloud_name, host_name, service_name, action)
                     .await?;
             }
         }
@@ -1046,72 +1969,183 @@ impl PremiumUI {
         Ok(())
     }
 
-    // Helper to wait for a service to restart
-    async fn wait_for_service_restart(
+    // Ask the server to restart, stop or start a service, without waiting
+    pub async fn send_service_action(
         &self,
         cloud_name: &str,
         host_name: &str,
         service_name: &str,
-    ) -> Result<()> {
-        let mut service_restarted = false;
-        let mut attempts = 0;
-        const MAX_ATTEMPTS: usize = 30;
-
-        while !service_restarted && attempts < MAX_ATTEMPTS {
-            attempts += 1;
+        action: ServiceAction,
+    ) -> Result<ApiResponse> {
+        self.api_client
+            .post::<_, ApiResponse>(
+                &format!(
+                    "/platforms/{}/hosts/{}/services/{}/{}",
+                    cloud_name,
+                    host_name,
+                    service_name,
+                    action.verb()
+                ),
+                &(),
+            )
+            .await
+    }
 
+    // Current status of one service, `None` if the host doesn't have it. Uses
+    // the single-service endpoint while the server provides it, and the
+    // host's full service list once it turns out not to.
+    async fn fetch_service_status(
+        &self,
+        cloud_name: &str,
+        host_name: &str,
+        service_name: &str,
+        single_endpoint: &mut bool,
+    ) -> Result<StatusData<Option<ServiceStatus>>> {
+        if *single_endpoint {
             match self
                 .api_client
                 .get::<ApiResponse>(&format!(
-                    "/platforms/{}/hosts/{}/services",
-                    cloud_name, host_name
+                    "/platforms/{}/hosts/{}/services/{}",
+                    cloud_name, host_name, service_name
                 ))
                 .await
             {
                 Ok(response) => {
-                    if let Some(data) = response.data {
-                        if let Ok(services) = serde_json::from_value::<Vec<ServiceStatus>>(data) {
-                            if let Some(service) = services.iter().find(|s| s.name == service_name)
-                            {
-                                // Check service status
-                                match service.status.as_str() {
-                                    "Running" => {
-                                        service_restarted = true;
-                                        println!(
-                                            "{}",
-                                            style("Service restarted successfully! ✓")
-                                                .green()
-                                                .bold()
-                                        );
-                                        break;
-                                    }
-                                    "Restarting" => {
-                                        println!(
-                                            "{}",
-                                            style("Service is currently restarting...").yellow()
-                                        );
-                                    }
-                                    status => {
-                                        println!(
-                                            "{}",
-                                            style(format!("Service status: {}", status)).yellow()
-                                        );
-                                    }
+                    return Ok(match self.parse_status_data::<ServiceStatus>(response.data) {
+                        StatusData::Available(service) => StatusData::Available(Some(service)),
+                        StatusData::Missing => StatusData::Missing,
+                        StatusData::Malformed => StatusData::Malformed,
+                    });
+                }
+                Err(err)
+                    if matches!(
+                        ApiError::status_of(&err),
+                        Some(
+                            StatusCode::NOT_FOUND
+                                | StatusCode::METHOD_NOT_ALLOWED
+                                | StatusCode::NOT_IMPLEMENTED
+                        )
+                    ) =>
+                {
+                    if self.debug {
+                        println!(
+                            "{}",
+                            style("Single-service status unavailable, polling the service list")
+                                .dim()
+                        );
+                    }
+                    *single_endpoint = false;
+                }
+                Err(err) => return Err(err),
+            }
+        }
+
+        let response = self
+            .api_client
+            .get::<ApiResponse>(&format!(
+                "/platforms/{}/hosts/{}/services",
+                cloud_name, host_name
+            ))
+            .await?;
+        Ok(
+            match self.parse_status_data::<Vec<ServiceStatus>>(response.data) {
+                StatusData::Available(services) => StatusData::Available(
+                    services.into_iter().find(|s| s.name == service_name),
+                ),
+                StatusData::Missing => StatusData::Missing,
+                StatusData::Malformed => StatusData::Malformed,
+            },
+        )
+    }
+
+    pub async fn wait_for_service_restart(
+        &self,
+        cloud_name: &str,
+        host_name: &str,
+        service_name: &str,
+    ) -> Result<()> {
+        self.wait_for_service_state(cloud_name, host_name, service_name, ServiceAction::Restart)
+            .await
+    }
+
+    // Poll the host's services until `service_name` reports the status
+    // `action` leads to, giving up after 30 seconds
+    pub async fn wait_for_service_state(
+        &self,
+        cloud_name: &str,
+        host_name: &str,
+        service_name: &str,
+        action: ServiceAction,
+    ) -> Result<()> {
+        let mut reached = false;
+        let mut single_endpoint = true;
+        const TIMEOUT: Duration = Duration::from_secs(30);
+        let deadline = self.wait_deadline_or(WaitKind::Service, TIMEOUT);
+        let started = Instant::now();
+        let mut last_status: Option<ServiceStatus> = None;
+
+        while !reached && Instant::now() < deadline {
+
+            match self
+                .fetch_service_status(cloud_name, host_name, service_name, &mut single_endpoint)
+                .await
+            {
+                Ok(status) => match status {
+                    StatusData::Missing => {
+                        self.info("Service status unavailable, waiting...");
+                    }
+                    StatusData::Malformed => {
+                        println!(
+                            "{}",
+                            style("Unexpected server response while checking service status")
+                                .yellow()
+                        );
+                    }
+                    StatusData::Available(service) => {
+                        if let Some(service) = service {
+                            // Check service status
+                            match service.status.as_str() {
+                                status if status == action.target_status() => {
+                                    reached = true;
+                                    println!(
+                                        "{}",
+                                        style(format!("Service {} successfully! ✓", action.past()))
+                                            .green()
+                                            .bold()
+                                    );
+                                    break;
                                 }
-                            } else {
-                                println!(
-                                    "{}",
-                                    style(format!("Service '{}' not found on host", service_name))
+                                "Restarting" | "Stopping" | "Starting" => {
+                                    println!(
+                                        "{}",
+                                        style(format!(
+                                            "Service is currently {}...",
+                                            service.status.to_lowercase()
+                                        ))
                                         .yellow()
-                                );
+                                    );
+                                }
+                                status => {
+                                    println!(
+                                        "{}",
+                                        style(format!("Service status: {}", status)).yellow()
+                                    );
+                                }
                             }
+                            last_status = Some(service);
+                        } else {
+                            println!(
+                                "{}",
+                                style(format!("Service '{}' not found on host", service_name))
+                                    .yellow()
+                            );
                         }
                     }
-                }
+                },
                 Err(err) => {
                     println!(
                         "{}",
-                        style(format!("Error checking service status: {:?}", err)).yellow()
+                        style(format!("Error checking service status: {:#}", err)).yellow()
                     );
                 }
             }
@@ -1119,92 +2153,74 @@ impl PremiumUI {
             tokio::time::sleep(Duration::from_secs(1)).await;
         }
 
-        if !service_restarted {
-            println!("{}", style("Timed out waiting for service to restart. The service may still be restarting.").yellow());
+        if !reached {
+            return Err(self.wait_timed_out(
+                &format!("{} to {}", service_name, action.verb()),
+                started.elapsed(),
+                last_status.as_ref(),
+            ));
         }
 
         Ok(())
     }
 
     // View logs for a specific service
-    pub async fn view_service_logs(&self, host_name: &str, service_name: &str) -> Result<()> {
-        let config_path = "config/cloud-config.json";
-        let config_json =
-            fs::read_to_string(config_path).context("Failed to read configuration file")?;
-        let config: CloudConfig =
-            serde_json::from_str(&config_json).context("Failed to parse configuration")?;
+    pub async fn view_service_logs(
+        &self,
+        host_name: &str,
+        service_name: &str,
+        view: &LogView<'_>,
+    ) -> Result<()> {
+        let config = self.load_cloud_config()?;
+        // Structured formats keep stdout to one record per line
+        let text = view.format == LogFormat::Text;
 
-        println!(
-            "\n{}",
-            style(format!(
-                "📜 Logs for service {} on host {}",
-                service_name, host_name
-            ))
-            .cyan()
-            .bold()
-        );
+        if text {
+            println!(
+                "\n{}",
+                style(format!(
+                    "📜 Logs for service {} on host {}",
+                    service_name, host_name
+                ))
+                .cyan()
+                .bold()
+            );
+        }
 
-        match self
-            .api_client
-            .get::<ApiResponse>(&format!(
-                "/platforms/{}/hosts/{}/services/{}/logs",
-                config.cloud_name, host_name, service_name
-            ))
+        let logs = self
+            .fetch_service_logs(&config.cloud_name, host_name, service_name, view.tail, view.since)
             .await
-        {
-            Err(err) => {
-                println!("{}", style("Failed to retrieve logs: ").red().bold());
-                println!("{}", style(format!("{:?}", err)).red());
-                return Err(anyhow::anyhow!("Failed to retrieve logs: {:?}", err));
-            }
-            Ok(response) => {
-                if let Some(data) = response.data {
-                    if let Ok(logs) = serde_json::from_value::<Vec<String>>(data) {
-                        if logs.is_empty() {
-                            println!("{}", style("No logs available for this service.").yellow());
-                        } else {
-                            println!("\n{}", style("Service Logs:").yellow().bold());
-                            for log_line in logs {
-                                let formatted_line = if log_line.contains("[INFO]") {
-                                    style(log_line).dim()
-                                } else if log_line.contains("[WARN]") {
-                                    style(log_line).yellow()
-                                } else if log_line.contains("[ERROR]") {
-                                    style(log_line).red()
-                                } else {
-                                    style(log_line)
-                                };
+            .context("Failed to retrieve logs")?;
 
-                                println!("{}", formatted_line);
-                            }
-                        }
-                    } else {
-                        println!("{}", style("Failed to parse log data from API.").red());
-                        return Err(anyhow::anyhow!("Failed to parse log data"));
-                    }
-                } else {
-                    println!("{}", style("No log data available from API.").yellow());
-                    return Err(anyhow::anyhow!("No log data available"));
-                }
+        if logs.is_empty() {
+            if text {
+                println!("{}", style("No logs available for this service.").yellow());
             }
+            return Ok(());
         }
+        if text {
+            println!("\n{}", style("Service Logs:").yellow().bold());
+        }
+        print_service_lines(host_name, service_name, logs, view);
 
-        println!("\n{}", style("💡 Tip").cyan().bold());
-        println!(
-            "Use {} to follow logs in real-time",
-            style("omni logs <host> <service> --follow").yellow()
-        );
+        if text {
+            println!("\n{}", style("💡 Tip").cyan().bold());
+            println!(
+                "Use {} to follow logs in real-time",
+                style(format!(
+                    "omni logs --host {} --service {} --follow",
+                    host_name, service_name
+                ))
+                .yellow()
+            );
+        }
 
         Ok(())
     }
 
     // Trigger an immediate backup
     pub async fn trigger_backup(&self) -> Result<()> {
-        let config_path = "config/cloud-config.json";
-        let config_json =
-            fs::read_to_string(config_path).context("Failed to read configuration file")?;
-        let config: CloudConfig =
-            serde_json::from_str(&config_json).context("Failed to parse configuration")?;
+        let config = self.load_cloud_config()?;
 
         if !config.enable_backups {
             println!(
@@ -1229,8 +2245,8 @@ impl PremiumUI {
         {
             Err(err) => {
                 println!("{}", style("Failed to trigger backup: ").red().bold());
-                println!("{}", style(format!("{:?}", err)).red());
-                return Err(anyhow::anyhow!("Failed to trigger backup: {:?}", err));
+                println!("{}", style(format!("{:#}", err)).red());
+                return Err(anyhow::anyhow!("Failed to trigger backup: {:#}", err));
             }
             Ok(response) => {
                 println!("{}", style("Backup process initiated ✓").green());
@@ -1248,15 +2264,54 @@ impl PremiumUI {
     }
 
     // Helper to wait for backup completion
+    // Render transfer progress (backups, restores) from a status payload,
+    // preferring byte counts with rate/ETA and falling back to the percentage
+    pub fn update_transfer_progress(
+        &self,
+        bar: &mut Option<ProgressBar>,
+        info: &serde_json::Value,
+        label: &str,
+    ) {
+        let bytes = info.get("bytes_done").and_then(|v| v.as_u64()).zip(
+            info.get("bytes_total")
+                .and_then(|v| v.as_u64())
+                .filter(|total| *total > 0),
+        );
+
+        match bytes {
+            Some((bytes_done, bytes_total)) => {
+                let pb =
+                    bar.get_or_insert_with(|| self.create_bytes_progress_bar(bytes_total, label));
+                pb.set_length(bytes_total);
+                pb.set_position(bytes_done);
+            }
+            None => {
+                if let Some(progress) = info.get("progress").and_then(|v| v.as_u64()) {
+                    let pb = bar.get_or_insert_with(|| self.create_progress_bar(100, label));
+                    pb.set_position(progress.min(100));
+                }
+            }
+        }
+
+        if let (Some(pb), Some(current_step)) = (
+            bar.as_ref(),
+            info.get("current_step").and_then(|v| v.as_str()),
+        ) {
+            pb.set_message(current_step.to_string());
+        }
+    }
+
     async fn wait_for_backup_completion(&self, cloud_name: &str) -> Result<()> {
         let mut backup_completed = false;
-        let mut attempts = 0;
-        const MAX_ATTEMPTS: usize = 60; // 1 minute timeout
+        let mut progress_bar: Option<ProgressBar> = None;
+        const TIMEOUT: Duration = Duration::from_secs(60);
+        let deadline = self.wait_deadline_or(WaitKind::Backup, TIMEOUT);
+        let started = Instant::now();
+        let mut last_status: Option<serde_json::Value> = None;
 
-        println!("{}", style("Monitoring backup progress...").dim());
+        self.info("Monitoring backup progress...");
 
-        while !backup_completed && attempts < MAX_ATTEMPTS {
-            attempts += 1;
+        while !backup_completed && Instant::now() < deadline {
 
             match self
                 .api_client
@@ -1266,6 +2321,9 @@ impl PremiumUI {
                 Ok(response) => {
                     if response.status == "completed" {
                         backup_completed = true;
+                        if let Some(pb) = progress_bar.take() {
+                            pb.finish_and_clear();
+                        }
                         println!(
                             "{}",
                             style("Backup completed successfully! ✓").green().bold()
@@ -1291,24 +2349,20 @@ impl PremiumUI {
                         }
 
                         break;
+                    } else if response.status == "failed" {
+                        if let Some(pb) = progress_bar.take() {
+                            pb.abandon_with_message("Server reported failure ✗");
+                        }
+                        return Err(anyhow::anyhow!(
+                            "The server reported that the backup failed: {}",
+                            response.message
+                        ));
                     } else {
                         // Extract and display backup progress information
                         if let Some(data) = response.data {
-                            if let Ok(backup_info) =
-                                serde_json::from_value::<serde_json::Value>(data)
-                            {
-                                if let Some(progress) =
-                                    backup_info.get("progress").and_then(|v| v.as_u64())
-                                {
-                                    println!("Backup progress: {}%", style(progress).cyan());
-                                }
-                                if let Some(current_step) =
-                                    backup_info.get("current_step").and_then(|v| v.as_str())
-                                {
-                                    println!("Current step: {}", style(current_step).dim());
-                                }
-                            }
-                        } else {
+                            self.update_transfer_progress(&mut progress_bar, &data, "Backing up");
+                            last_status = Some(data);
+                        } else if progress_bar.is_none() {
                             println!("Waiting for backup progress update...");
                         }
                     }
@@ -1316,7 +2370,7 @@ impl PremiumUI {
                 Err(err) => {
                     println!(
                         "{}",
-                        style(format!("Error checking backup status: {:?}", err)).yellow()
+                        style(format!("Error checking backup status: {:#}", err)).yellow()
                     );
                 }
             }
@@ -1325,9 +2379,241 @@ impl PremiumUI {
         }
 
         if !backup_completed {
-            println!("{}", style("Timed out waiting for backup to complete. The backup may still be in progress.").yellow());
+            if let Some(pb) = progress_bar.take() {
+                pb.abandon_with_message("Timed out locally");
+            }
+            return Err(self.wait_timed_out(
+                "the backup",
+                started.elapsed(),
+                last_status.as_ref(),
+            ));
         }
 
         Ok(())
     }
 }
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::api_client::ApiClient;
+
+    pub(crate) fn test_ui() -> PremiumUI {
+        PremiumUI::new(ApiClient::without_config_writes())
+    }
+
+    // Serve `routes` (path, status, body) over HTTP on a local port, one
+    // request per connection, and return the base URL
+    pub(crate) async fn serve(routes: Vec<(&'static str, u16, &'static str)>) -> String {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        tokio::spawn(async move {
+            while let Ok((mut stream, _)) = listener.accept().await {
+                let mut request = Vec::new();
+                let mut buf = [0; 1024];
+                while !request.windows(4).any(|w| w == b"\r\n\r\n") {
+                    match stream.read(&mut buf).await {
+                        Ok(0) | Err(_) => break,
+                        Ok(n) => request.extend_from_slice(&buf[..n]),
+                    }
+                }
+                // Read the whole body before replying, so an upload isn't
+                // cut off with a reset instead of getting the response
+                let header_end = request
+                    .windows(4)
+                    .position(|w| w == b"\r\n\r\n")
+                    .map_or(request.len(), |end| end + 4);
+                let head = String::from_utf8_lossy(&request[..header_end]).to_lowercase();
+                let body_len = head
+                    .lines()
+                    .find_map(|line| line.strip_prefix("content-length:"))
+                    .and_then(|len| len.trim().parse::<usize>().ok())
+                    .unwrap_or(0);
+                while request.len() < header_end + body_len {
+                    match stream.read(&mut buf).await {
+                        Ok(0) | Err(_) => break,
+                        Ok(n) => request.extend_from_slice(&buf[..n]),
+                    }
+                }
+                let request = String::from_utf8_lossy(&request);
+                let path = request.split_whitespace().nth(1).unwrap_or_default();
+                let (status, body) = routes
+                    .iter()
+                    .find(|(route, _, _)| *route == path)
+                    .map_or((404, "{}"), |(_, status, body)| (*status, *body));
+                let response = format!(
+                    "HTTP/1.1 {} X\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                );
+                let _ = stream.write_all(response.as_bytes()).await;
+            }
+        });
+        base_url
+    }
+
+    fn sample_config(cloud_name: String, hosts: usize) -> CloudConfig {
+        CloudConfig {
+            company_name: "Acme".to_string(),
+            admin_name: "ops".to_string(),
+            cloud_name,
+            region: "us-east".to_string(),
+            ssh_hosts: (0..hosts)
+                .map(|i| SshHost {
+                    name: format!("host-{}", i),
+                    hostname: format!("10.0.0.{}", i),
+                    username: "root".to_string(),
+                    password: None,
+                    port: 22,
+                    identity_file: None,
+                    is_bastion: i == 0,
+                })
+                .collect(),
+            enable_monitoring: true,
+            enable_backups: false,
+            backup_retention_days: 7,
+        }
+    }
+
+    #[test]
+    fn concurrent_config_writes_leave_a_parseable_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("cloud-config.json");
+
+        let writers: Vec<_> = (0..8)
+            .map(|writer| {
+                let path = path.clone();
+                std::thread::spawn(move || {
+                    for round in 0..20 {
+                        // Sizes differ so a torn write would leave trailing garbage
+                        let config =
+                            sample_config(format!("cloud-{}-{}", writer, round), writer + 1);
+                        let contents = serde_json::to_string_pretty(&config).unwrap();
+                        write_config_file(&path, &contents).unwrap();
+                    }
+                })
+            })
+            .collect();
+        for writer in writers {
+            writer.join().unwrap();
+        }
+
+        let contents = fs::read_to_string(&path).unwrap();
+        let config = parse_cloud_config(&contents, ConfigFileFormat::Json).unwrap();
+        assert!(config.cloud_name.ends_with("-19"), "{}", config.cloud_name);
+        assert!(parse_cloud_config(
+            &fs::read_to_string(config_backup_path(&path)).unwrap(),
+            ConfigFileFormat::Json
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn status_data_without_a_data_field_is_missing() {
+        let response: ApiResponse =
+            serde_json::from_str(r#"{"status": "ok", "message": "no hosts yet"}"#).unwrap();
+        assert!(matches!(
+            test_ui().parse_status_data::<Vec<HostDeploymentStatus>>(response.data),
+            StatusData::Missing
+        ));
+    }
+
+    #[test]
+    fn status_data_of_the_wrong_shape_is_malformed() {
+        let response: ApiResponse = serde_json::from_str(
+            r#"{"status": "ok", "message": "", "data": {"hosts": "not a list"}}"#,
+        )
+        .unwrap();
+        assert!(matches!(
+            test_ui().parse_status_data::<Vec<HostDeploymentStatus>>(response.data),
+            StatusData::Malformed
+        ));
+    }
+
+    #[test]
+    fn status_data_that_parses_is_available() {
+        let data = serde_json::json!([{"name": "api", "status": "Running"}]);
+        match test_ui().parse_status_data::<Vec<ServiceStatus>>(Some(data)) {
+            StatusData::Available(services) => assert_eq!(services[0].name, "api"),
+            _ => panic!("expected parsed service status"),
+        }
+    }
+
+    #[tokio::test]
+    async fn service_status_uses_the_single_service_endpoint() {
+        let mut ui = test_ui();
+        ui.api_client.base_url = serve(vec![(
+            "/platforms/prod/hosts/web-1/services/api",
+            200,
+            r#"{"status": "ok", "message": "", "data": {"name": "api", "status": "Running"}}"#,
+        )])
+        .await;
+
+        let mut single_endpoint = true;
+        let status = ui
+            .fetch_service_status("prod", "web-1", "api", &mut single_endpoint)
+            .await
+            .unwrap();
+        assert!(single_endpoint);
+        match status {
+            StatusData::Available(Some(service)) => assert_eq!(service.status, "Running"),
+            _ => panic!("expected the service's status"),
+        }
+    }
+
+    #[tokio::test]
+    async fn service_status_falls_back_to_the_service_list() {
+        let mut ui = test_ui();
+        ui.api_client.base_url = serve(vec![(
+            "/platforms/prod/hosts/web-1/services",
+            200,
+            r#"{"status": "ok", "message": "", "data": [
+                {"name": "db", "status": "Running"},
+                {"name": "api", "status": "Restarting"}
+            ]}"#,
+        )])
+        .await;
+
+        let mut single_endpoint = true;
+        let status = ui
+            .fetch_service_status("prod", "web-1", "api", &mut single_endpoint)
+            .await
+            .unwrap();
+        // The 404 from the single-service endpoint switches later polls to the list
+        assert!(!single_endpoint);
+        match status {
+            StatusData::Available(Some(service)) => assert_eq!(service.status, "Restarting"),
+            _ => panic!("expected the service's status from the list"),
+        }
+    }
+
+    #[test]
+    fn selectors_filter_structured_host_statuses() {
+        let ui = test_ui().with_selectors(vec![Selector::parse("status=Stopped").unwrap()]);
+        let host_statuses: Vec<HostDeploymentStatus> = serde_json::from_value(serde_json::json!([
+            {
+                "host": "web-1", "status": "Running", "current_step": "", "progress": 100,
+                "error": null, "completed": true,
+                "services": [
+                    {"name": "api", "status": "Running"},
+                    {"name": "worker", "status": "Stopped"}
+                ]
+            },
+            {
+                "host": "web-2", "status": "Running", "current_step": "", "progress": 100,
+                "error": null, "completed": true,
+                "services": [{"name": "api", "status": "Running"}]
+            }
+        ]))
+        .unwrap();
+
+        let selected = ui.select_host_statuses(host_statuses);
+        assert_eq!(selected.len(), 1);
+        assert_eq!(selected[0].host, "web-1");
+        let services: Vec<&str> = selected[0].services.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(services, ["worker"]);
+    }
+}
@@ -1,35 +1,1093 @@
+use crate::api_client::ApiError;
+use crate::commands::init_env::{ApiResponse, HostDeploymentStatus, StatusData};
+use crate::commands::prune::parse_age;
+use crate::models::Release;
 use crate::ui::PremiumUI;
-use anyhow::Result;
-use chrono::Local;
-use console::style;
-use dialoguer::Select;
-use std::{thread, time::Duration};
+use anyhow::{anyhow, Context, Result};
+use chrono::{DateTime, FixedOffset, Local};
+use clap::ArgMatches;
+use console::{style, StyledObject, Term};
+use dialoguer::{Input, Select};
+use futures::stream::{FuturesUnordered, StreamExt};
+use reqwest::header::CONTENT_TYPE;
+use reqwest::StatusCode;
+use serde::Deserialize;
+use std::collections::VecDeque;
+use std::fs;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::Semaphore;
+
+// Seconds between polls of the logs endpoint in `logs --watch`
+const WATCH_POLL_SECONDS: u64 = 2;
+
+// Lines requested per page by `logs --export`
+const EXPORT_PAGE_SIZE: usize = 1000;
+
+// Level markers recognized at the start of a log message
+const LOG_LEVELS: &[&str] = &[
+    "TRACE", "DEBUG", "INFO", "WARNING", "WARN", "ERROR", "FATAL",
+];
+
+// How log output is rendered, chosen with `logs --output-format`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogFormat {
+    Text,
+    Json,
+    Logfmt,
+}
+
+impl LogFormat {
+    pub fn from_arg(value: Option<&str>) -> Self {
+        match value {
+            Some("json") => LogFormat::Json,
+            Some("logfmt") => LogFormat::Logfmt,
+            _ => LogFormat::Text,
+        }
+    }
+}
+
+// File layout written by `logs --export`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    // One JSON document: {"metadata": {..}, "entries": [..]}
+    Json,
+    // A metadata line followed by one entry per line
+    Ndjson,
+}
+
+impl ExportFormat {
+    pub fn from_arg(value: &str) -> Self {
+        match value {
+            "ndjson" => ExportFormat::Ndjson,
+            _ => ExportFormat::Json,
+        }
+    }
+
+    fn extension(self) -> &'static str {
+        match self {
+            ExportFormat::Json => "json",
+            ExportFormat::Ndjson => "ndjson",
+        }
+    }
+}
+
+// A page of log lines. Servers that don't paginate return a bare array.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum LogPage {
+    Paged {
+        lines: Vec<String>,
+        #[serde(default)]
+        next_cursor: Option<String>,
+    },
+    Lines(Vec<String>),
+}
+
+// Parse `--since`/`--until`: an RFC 3339 timestamp, or an age such as `2h`
+// meaning that long before now
+pub fn parse_log_time(value: &str) -> std::result::Result<DateTime<FixedOffset>, String> {
+    if let Ok(time) = DateTime::parse_from_rfc3339(value) {
+        return Ok(time);
+    }
+    let age = parse_age(value)
+        .map_err(|_| format!("invalid time '{}' (use RFC 3339 or an age like 2h)", value))?;
+    Ok((Local::now() - age).fixed_offset())
+}
+
+// What to fetch and how to print it, shared by the log views
+#[derive(Debug, Clone, Copy)]
+pub struct LogView<'a> {
+    pub tail: Option<usize>,
+    pub since: Option<DateTime<FixedOffset>>,
+    pub format: LogFormat,
+    pub grep: Option<&'a str>,
+    pub context: usize,
+}
+
+impl<'a> LogView<'a> {
+    pub fn from_matches(matches: &'a ArgMatches) -> Self {
+        Self {
+            tail: matches
+                .get_one::<String>("tail")
+                .and_then(|tail| tail.parse::<usize>().ok()),
+            since: matches.get_one::<DateTime<FixedOffset>>("since").copied(),
+            format: LogFormat::from_arg(
+                matches
+                    .get_one::<String>("output-format")
+                    .map(String::as_str),
+            ),
+            grep: matches.get_one::<String>("grep").map(String::as_str),
+            context: matches.get_one::<usize>("context").copied().unwrap_or(0),
+        }
+    }
+}
+
+// `logs --grep` with grep-style `--context`. Lines are fed in order and the
+// ones to print come back, so the same filter serves batch output and a
+// running `--watch`, where only preceding lines still in the buffer can be
+// shown as context.
+struct GrepFilter<T> {
+    pattern: String,
+    context: usize,
+    before: VecDeque<T>,
+    after_remaining: usize,
+    printed_any: bool,
+    skipped: bool,
+}
+
+impl<T> GrepFilter<T> {
+    fn new(pattern: &str, context: usize) -> Self {
+        Self {
+            pattern: pattern.to_string(),
+            context,
+            before: VecDeque::new(),
+            after_remaining: 0,
+            printed_any: false,
+            skipped: false,
+        }
+    }
+
+    // Feed one line. Returns the lines to print now, and whether a `--`
+    // separator should precede them because lines were skipped since the
+    // last group (only with context, as grep does).
+    fn push(&mut self, text: &str, item: T) -> (bool, Vec<T>) {
+        if text.contains(&self.pattern) {
+            let separator = self.context > 0 && self.printed_any && self.skipped;
+            let mut lines: Vec<T> = self.before.drain(..).collect();
+            lines.push(item);
+            self.after_remaining = self.context;
+            self.printed_any = true;
+            self.skipped = false;
+            (separator, lines)
+        } else if self.after_remaining > 0 {
+            self.after_remaining -= 1;
+            (false, vec![item])
+        } else {
+            self.before.push_back(item);
+            if self.before.len() > self.context {
+                self.before.pop_front();
+                self.skipped = true;
+            }
+            (false, Vec::new())
+        }
+    }
+}
+
+// Print one service's log lines as `view` asks: filtered by `since` and
+// `grep`, and rendered in its format. Text output stays unprefixed since
+// there's only the one source.
+pub fn print_service_lines(host: &str, service: &str, lines: Vec<String>, view: &LogView<'_>) {
+    let mut filter = view
+        .grep
+        .map(|pattern| GrepFilter::new(pattern, view.context));
+    for line in lines {
+        let entry = LogEntry {
+            host: host.to_string(),
+            service: service.to_string(),
+            timestamp: log_timestamp(&line),
+            line,
+        };
+        if view
+            .since
+            .is_some_and(|since| entry.timestamp.is_some_and(|t| t < since))
+        {
+            continue;
+        }
+        let (separator, entries) = match filter.as_mut() {
+            Some(filter) => {
+                let text = entry.line.clone();
+                filter.push(&text, entry)
+            }
+            None => (false, vec![entry]),
+        };
+        if separator {
+            print_grep_separator(view.format);
+        }
+        for entry in entries {
+            match view.format {
+                LogFormat::Text => println!("{}", style_log_line(&entry.line)),
+                format => println!("{}", entry.render(format)),
+            }
+        }
+    }
+}
+
+// Group separator between non-contiguous --grep matches, as grep prints it
+fn print_grep_separator(format: LogFormat) {
+    // Structured formats keep stdout to one record per line
+    if format == LogFormat::Text {
+        println!("{}", style("--").dim());
+    }
+}
+
+// Prints a followed service's lines as they arrive, applying `--since` and
+// `--grep`. The grep filter is kept across calls so context can span them.
+struct LivePrinter<'a> {
+    host: &'a str,
+    service: &'a str,
+    since: Option<DateTime<FixedOffset>>,
+    format: LogFormat,
+    filter: Option<GrepFilter<LogEntry>>,
+}
+
+impl<'a> LivePrinter<'a> {
+    fn new(host: &'a str, service: &'a str, view: &LogView<'_>) -> Self {
+        Self {
+            host,
+            service,
+            since: view.since,
+            format: view.format,
+            filter: view
+                .grep
+                .map(|pattern| GrepFilter::new(pattern, view.context)),
+        }
+    }
+
+    fn print(&mut self, lines: &[String]) {
+        for line in lines {
+            let entry = LogEntry {
+                host: self.host.to_string(),
+                service: self.service.to_string(),
+                timestamp: log_timestamp(line),
+                line: line.clone(),
+            };
+            if self
+                .since
+                .is_some_and(|since| entry.timestamp.is_some_and(|t| t < since))
+            {
+                continue;
+            }
+            let (separator, entries) = match self.filter.as_mut() {
+                Some(filter) => filter.push(line, entry),
+                None => (false, vec![entry]),
+            };
+            if separator {
+                print_grep_separator(self.format);
+            }
+            for entry in entries {
+                println!("{}", entry.render(self.format));
+            }
+        }
+    }
+}
+
+// The log line carried by one line of a log stream. Server-sent events wrap
+// it in a `data:` field; their other fields and blank separators carry none.
+fn stream_line(raw: &[u8], event_stream: bool) -> Option<String> {
+    let text = String::from_utf8_lossy(raw);
+    let line = text.trim_end_matches(['\r', '\n']);
+    let line = if event_stream {
+        let data = line.strip_prefix("data:")?;
+        data.strip_prefix(' ').unwrap_or(data)
+    } else {
+        line
+    };
+    (!line.is_empty()).then(|| line.to_string())
+}
+
+// A single log line tagged with the host/service it came from
+struct LogEntry {
+    host: String,
+    service: String,
+    timestamp: Option<DateTime<FixedOffset>>,
+    line: String,
+}
+
+impl LogEntry {
+    fn to_json(&self) -> serde_json::Value {
+        let (level, message) = parse_log_line(&self.line);
+        serde_json::json!({
+            "time": self.timestamp.map(|t| t.to_rfc3339()),
+            "level": level,
+            "host": self.host,
+            "service": self.service,
+            "msg": message,
+        })
+    }
+
+    fn render(&self, format: LogFormat) -> String {
+        let (level, message) = parse_log_line(&self.line);
+        let time = self.timestamp.map(|t| t.to_rfc3339());
+
+        match format {
+            LogFormat::Text => format!(
+                "{} {}",
+                style(format!("[{}/{}]", self.host, self.service)).cyan(),
+                style_log_line(&self.line)
+            ),
+            LogFormat::Json => self.to_json().to_string(),
+            LogFormat::Logfmt => {
+                let mut pairs = Vec::new();
+                if let Some(time) = time {
+                    pairs.push(format!("time={}", logfmt_value(&time)));
+                }
+                if let Some(level) = level {
+                    pairs.push(format!("level={}", logfmt_value(&level)));
+                }
+                pairs.push(format!("host={}", logfmt_value(&self.host)));
+                pairs.push(format!("service={}", logfmt_value(&self.service)));
+                pairs.push(format!("msg={}", logfmt_value(&message)));
+                pairs.join(" ")
+            }
+        }
+    }
+}
+
+// Quote a logfmt value when it contains spaces, quotes or `=`
+fn logfmt_value(value: &str) -> String {
+    if !value.is_empty() && !value.contains(|c: char| c.is_whitespace() || c == '=' || c == '"') {
+        return value.to_string();
+    }
+    format!(
+        "\"{}\"",
+        value
+            .replace('\\', "\\\\")
+            .replace('"', "\\\"")
+            .replace('\n', "\\n")
+    )
+}
+
+// Best-effort split of a raw line into its level and message, dropping any
+// leading timestamp. Lines without a recognizable level keep their full text.
+fn parse_log_line(line: &str) -> (Option<String>, String) {
+    let mut rest = line.trim();
+    if log_timestamp(rest).is_some() {
+        rest = rest.trim_start_matches('[');
+        let end = rest
+            .find(|c: char| c.is_whitespace() || c == ']')
+            .unwrap_or(rest.len());
+        rest = rest[end..].trim_start_matches(']').trim_start();
+    }
+
+    for level in LOG_LEVELS {
+        for prefix in [format!("[{}]", level), format!("{}:", level)] {
+            let matches_prefix = rest
+                .get(..prefix.len())
+                .is_some_and(|head| head.eq_ignore_ascii_case(&prefix));
+            if matches_prefix {
+                let level = match *level {
+                    "WARNING" => "warn".to_string(),
+                    other => other.to_lowercase(),
+                };
+                return (Some(level), rest[prefix.len()..].trim().to_string());
+            }
+        }
+    }
+
+    (None, rest.to_string())
+}
+
+// Colorize a log line by its level marker
+pub fn style_log_line(line: &str) -> StyledObject<&str> {
+    if line.contains("[INFO]") {
+        style(line).dim()
+    } else if line.contains("[WARN]") {
+        style(line).yellow()
+    } else if line.contains("[ERROR]") {
+        style(line).red()
+    } else {
+        style(line)
+    }
+}
+
+// Best-effort extraction of a leading RFC 3339 timestamp, bracketed or not
+fn log_timestamp(line: &str) -> Option<DateTime<FixedOffset>> {
+    let first = line
+        .trim_start()
+        .trim_start_matches('[')
+        .split(|c: char| c.is_whitespace() || c == ']')
+        .next()?;
+    DateTime::parse_from_rfc3339(first).ok()
+}
 
 impl PremiumUI {
-    pub async fn logs_interactive(&self) -> Result<()> {
-        let components = vec!["Web Frontend", "API Backend", "Database", "All Components"];
-        let _component = Select::with_theme(&self.theme)
-            .with_prompt("Select component")
-            .items(&components)
-            .interact()?;
-
-        println!("\n{}", style("📋 Application Logs").cyan().bold());
-
-        let mut spinner = self.create_spinner("Fetching logs...");
-        thread::sleep(Duration::from_secs(1));
-
-        // Simulate log entries
-        let logs = vec![
-            format!("[{}] INFO: Service health check passed", Local::now()),
-            format!("[{}] DEBUG: Processing incoming request", Local::now()),
-            format!("[{}] INFO: Cache hit ratio: 78.5%", Local::now()),
-            format!("[{}] WARN: High memory usage detected", Local::now()),
-        ];
+    pub async fn logs_interactive(&self, matches: &ArgMatches) -> Result<()> {
+        let parallel = matches.get_one::<usize>("parallel").copied().unwrap_or(8);
+        let mut view = LogView::from_matches(matches);
+
+        if matches.get_flag("since-release") {
+            let app = match matches.get_one::<String>("app") {
+                Some(app) => app.clone(),
+                None => self
+                    .resolve_path(".")
+                    .canonicalize()?
+                    .file_name()
+                    .and_then(|s| s.to_str())
+                    .map(String::from)
+                    .context("Unable to determine app name; pass --app")?,
+            };
+            let started = self.latest_release_start(&app).await?;
+            if view.format == LogFormat::Text {
+                self.info(format!(
+                    "Showing logs since the latest release of {} ({})",
+                    app,
+                    started.to_rfc3339()
+                ));
+            }
+            view.since = Some(started);
+        }
+
+        if let Some(export) = matches.get_one::<String>("export") {
+            let host = matches.get_one::<String>("host").unwrap();
+            let service = matches.get_one::<String>("service").unwrap();
+            return self
+                .export_service_logs(
+                    host,
+                    service,
+                    view.since,
+                    matches.get_one::<DateTime<FixedOffset>>("until").copied(),
+                    ExportFormat::from_arg(export),
+                    matches.get_one::<String>("output").map(String::as_str),
+                )
+                .await;
+        }
+
+        if matches.get_flag("follow") {
+            let host = matches.get_one::<String>("host").unwrap();
+            let service = matches.get_one::<String>("service").unwrap();
+            return self.follow_service_logs(host, service, &view).await;
+        }
+
+        if matches.get_flag("watch") {
+            let host = matches.get_one::<String>("host").unwrap();
+            let service = matches.get_one::<String>("service").unwrap();
+            return self.watch_service_logs(host, service, &view).await;
+        }
+
+        let host_arg = matches.get_one::<String>("host");
+        let service_arg = matches.get_one::<String>("service");
+        if let (Some(host), Some(service)) = (host_arg, service_arg) {
+            return self.view_service_logs(host, service, &view).await;
+        }
+
+        // Pick among the services the platform reports as running, narrowed
+        // by whichever of --host/--service was given
+        let config = self.load_cloud_config()?;
+        let running = self
+            .running_services(&config.cloud_name)
+            .await
+            .ok()
+            .flatten()
+            .unwrap_or_default();
+        let targets: Vec<(String, String)> = running
+            .into_iter()
+            .filter(|(host, service)| {
+                host_arg.is_none_or(|wanted| wanted == host)
+                    && service_arg.is_none_or(|wanted| wanted == service)
+            })
+            .collect();
+
+        let (host, service) = if targets.is_empty() {
+            // Without a status report, pick a configured host and name the service
+            let host = match host_arg {
+                Some(host) => host.clone(),
+                None => {
+                    if config.ssh_hosts.is_empty() {
+                        return Err(anyhow!(
+                            "No SSH hosts in the cloud config; pass --host and --service"
+                        ));
+                    }
+                    let hosts: Vec<&str> =
+                        config.ssh_hosts.iter().map(|h| h.name.as_str()).collect();
+                    let selection = Select::with_theme(&self.theme)
+                        .with_prompt("Select host")
+                        .items(&hosts)
+                        .default(0)
+                        .interact()?;
+                    hosts[selection].to_string()
+                }
+            };
+            let service = match service_arg {
+                Some(service) => service.clone(),
+                None => Input::with_theme(&self.theme)
+                    .with_prompt("Service name")
+                    .interact_text()?,
+            };
+            (host, service)
+        } else {
+            let mut items: Vec<String> = targets
+                .iter()
+                .map(|(host, service)| format!("{} on {}", service, host))
+                .collect();
+            items.push("All services".to_string());
+            let selection = Select::with_theme(&self.theme)
+                .with_prompt("Select service")
+                .items(&items)
+                .default(0)
+                .interact()?;
+            if selection == targets.len() {
+                return self
+                    .print_merged_logs(
+                        &config.cloud_name,
+                        targets,
+                        "📋 Aggregated Logs",
+                        parallel,
+                        &view,
+                    )
+                    .await;
+            }
+            targets[selection].clone()
+        };
+
+        self.view_service_logs(&host, &service, &view).await
+    }
+
+    // (host, service) pairs running on the platform according to its status;
+    // `None` when the server has no status to report
+    async fn running_services(&self, cloud_name: &str) -> Result<Option<Vec<(String, String)>>> {
+        let response = self
+            .api_client
+            .get::<ApiResponse>(&format!("/platforms/{}/status", cloud_name))
+            .await?;
+        match self.parse_status_data::<Vec<HostDeploymentStatus>>(response.data) {
+            StatusData::Available(host_statuses) => Ok(Some(
+                host_statuses
+                    .iter()
+                    .flat_map(|h| h.services.iter().map(|s| (h.host.clone(), s.name.clone())))
+                    .collect(),
+            )),
+            StatusData::Missing => Ok(None),
+            StatusData::Malformed => {
+                Err(anyhow!("Unexpected server response while listing services"))
+            }
+        }
+    }
+
+    // Start time of the most recent release of `app`, used as the `since`
+    // bound for `logs --since-release`
+    pub async fn latest_release_start(&self, app: &str) -> Result<DateTime<FixedOffset>> {
+        let response = self
+            .api_client
+            .get::<ApiResponse>(&format!("/apps/{}/releases", app))
+            .await
+            .with_context(|| format!("Failed to list releases of {}", app))?;
+        let releases = match self.parse_status_data::<Vec<Release>>(response.data) {
+            StatusData::Available(releases) => releases,
+            StatusData::Missing => Vec::new(),
+            StatusData::Malformed => {
+                return Err(anyhow!("Unexpected server response for release data"));
+            }
+        };
+
+        releases
+            .iter()
+            .filter_map(|release| {
+                release
+                    .started_at
+                    .as_deref()
+                    .or(release.created_at.as_deref())
+                    .and_then(|t| DateTime::parse_from_rfc3339(t).ok())
+            })
+            .max()
+            .ok_or_else(|| anyhow!("No releases with a start time found for {}", app))
+    }
+
+    // Fetch the log lines of a single service
+    pub async fn fetch_service_logs(
+        &self,
+        cloud_name: &str,
+        host_name: &str,
+        service_name: &str,
+        tail: Option<usize>,
+        since: Option<DateTime<FixedOffset>>,
+    ) -> Result<Vec<String>> {
+        let mut endpoint = format!(
+            "/platforms/{}/hosts/{}/services/{}/logs",
+            cloud_name, host_name, service_name
+        );
+        let mut query = Vec::new();
+        if let Some(tail) = tail {
+            query.push(format!("tail={}", tail));
+        }
+        if let Some(since) = since {
+            query.push(format!("since={}", since.timestamp()));
+        }
+        if !query.is_empty() {
+            endpoint.push('?');
+            endpoint.push_str(&query.join("&"));
+        }
+
+        let response = self.api_client.get::<ApiResponse>(&endpoint).await?;
+        match self.parse_status_data::<Vec<String>>(response.data) {
+            StatusData::Available(lines) => Ok(lines),
+            StatusData::Missing => Ok(Vec::new()),
+            StatusData::Malformed => Err(anyhow!("Unexpected server response for log data")),
+        }
+    }
+
+    // Fetch one page of a service's logs for `logs --export`, returning the
+    // lines and the cursor of the next page, if any
+    async fn fetch_log_page(
+        &self,
+        cloud_name: &str,
+        host_name: &str,
+        service_name: &str,
+        since: Option<DateTime<FixedOffset>>,
+        until: Option<DateTime<FixedOffset>>,
+        cursor: Option<&str>,
+    ) -> Result<(Vec<String>, Option<String>)> {
+        let mut query = vec![format!("limit={}", EXPORT_PAGE_SIZE)];
+        if let Some(since) = since {
+            query.push(format!("since={}", since.timestamp()));
+        }
+        if let Some(until) = until {
+            query.push(format!("until={}", until.timestamp()));
+        }
+        if let Some(cursor) = cursor {
+            query.push(format!("cursor={}", cursor));
+        }
+        let endpoint = format!(
+            "/platforms/{}/hosts/{}/services/{}/logs?{}",
+            cloud_name,
+            host_name,
+            service_name,
+            query.join("&")
+        );
+
+        let response = self.api_client.get::<ApiResponse>(&endpoint).await?;
+        match self.parse_status_data::<LogPage>(response.data) {
+            StatusData::Available(LogPage::Paged { lines, next_cursor }) => {
+                Ok((lines, next_cursor))
+            }
+            StatusData::Available(LogPage::Lines(lines)) => Ok((lines, None)),
+            StatusData::Missing => Ok((Vec::new(), None)),
+            StatusData::Malformed => Err(anyhow!("Unexpected server response for log data")),
+        }
+    }
+
+    // Fetch a service's full log range, page by page, and write it to a
+    // self-contained file with a metadata header for sharing
+    pub async fn export_service_logs(
+        &self,
+        host: &str,
+        service: &str,
+        since: Option<DateTime<FixedOffset>>,
+        until: Option<DateTime<FixedOffset>>,
+        format: ExportFormat,
+        output: Option<&str>,
+    ) -> Result<()> {
+        if let (Some(since), Some(until)) = (since, until) {
+            if since >= until {
+                return Err(anyhow!("--since must be earlier than --until"));
+            }
+        }
+        let config = self.load_cloud_config()?;
+        let exported_at = Local::now();
+        let output = match output {
+            Some(output) => self.resolve_path(output),
+            None => self.resolve_path(format!(
+                "omni-logs-{}-{}-{}.{}",
+                host,
+                service,
+                exported_at.format("%Y%m%d%H%M%S"),
+                format.extension()
+            )),
+        };
+
+        let mut spinner = self.create_spinner(&format!("Exporting {}/{} logs...", host, service));
+        let mut entries = Vec::new();
+        let mut cursor: Option<String> = None;
+        let mut pages = 0;
+        loop {
+            let page = self
+                .fetch_log_page(
+                    &config.cloud_name,
+                    host,
+                    service,
+                    since,
+                    until,
+                    cursor.as_deref(),
+                )
+                .await;
+            let (lines, next_cursor) = match page {
+                Ok(page) => page,
+                Err(err) => {
+                    spinner.stop();
+                    return Err(err).with_context(|| {
+                        format!(
+                            "Failed to fetch page {} of {}/{} logs",
+                            pages + 1,
+                            host,
+                            service
+                        )
+                    });
+                }
+            };
+            pages += 1;
+            entries.extend(lines.into_iter().map(|line| LogEntry {
+                host: host.to_string(),
+                service: service.to_string(),
+                timestamp: log_timestamp(&line),
+                line,
+            }));
+            // Stop on a repeated cursor too, so a misbehaving server can't loop us
+            match next_cursor {
+                Some(next) if cursor.as_deref() != Some(next.as_str()) => cursor = Some(next),
+                _ => break,
+            }
+        }
+        spinner.stop();
+
+        // The server may ignore the range, so apply it again locally. Lines
+        // without a timestamp can't be placed and are kept.
+        entries.retain(|entry| {
+            entry.timestamp.is_none_or(|t| {
+                since.is_none_or(|since| t >= since) && until.is_none_or(|until| t < until)
+            })
+        });
+
+        let metadata = serde_json::json!({
+            "cloud": config.cloud_name,
+            "host": host,
+            "service": service,
+            "since": since.map(|t| t.to_rfc3339()),
+            "until": until.map(|t| t.to_rfc3339()),
+            "exported_at": exported_at.to_rfc3339(),
+            "line_count": entries.len(),
+            "client_version": env!("CARGO_PKG_VERSION"),
+        });
+        let records = entries.iter().map(LogEntry::to_json);
+        let contents = match format {
+            ExportFormat::Json => serde_json::to_string_pretty(&serde_json::json!({
+                "metadata": metadata,
+                "entries": records.collect::<Vec<_>>(),
+            }))?,
+            ExportFormat::Ndjson => std::iter::once(serde_json::json!({ "metadata": metadata }))
+                .chain(records)
+                .map(|record| record.to_string() + "\n")
+                .collect(),
+        };
+
+        if let Some(parent) = output.parent() {
+            if !parent.as_os_str().is_empty() {
+                fs::create_dir_all(parent).context("Failed to create export directory")?;
+            }
+        }
+        fs::write(&output, contents)
+            .with_context(|| format!("Failed to write {}", output.display()))?;
+
+        println!(
+            "{}",
+            style(format!(
+                "✅ Exported {} log line(s) from {}/{} ({} page(s)) to {}",
+                entries.len(),
+                host,
+                service,
+                pages,
+                output.display()
+            ))
+            .green()
+        );
+        Ok(())
+    }
+
+    // Follow one service's logs until Ctrl-C. When the service restarts its
+    // log endpoint errors out or starts over; either way wait for it to be
+    // Running again and pick the stream back up.
+    pub async fn watch_service_logs(
+        &self,
+        host: &str,
+        service: &str,
+        view: &LogView<'_>,
+    ) -> Result<()> {
+        let config = self.load_cloud_config()?;
+        if view.format == LogFormat::Text {
+            println!(
+                "\n{}",
+                style(format!("📋 Watching {}/{} (Ctrl-C to stop)", host, service))
+                    .cyan()
+                    .bold()
+            );
+        }
+        self.poll_service_logs(&config.cloud_name, host, service, view, true)
+            .await
+    }
+
+    // Stream one service's logs as they're written until the stream closes
+    // or Ctrl-C. Uses the server's streaming endpoint when it has one, and
+    // otherwise polls the logs endpoint for lines not yet printed.
+    pub async fn follow_service_logs(
+        &self,
+        host: &str,
+        service: &str,
+        view: &LogView<'_>,
+    ) -> Result<()> {
+        let config = self.load_cloud_config()?;
+        if view.format == LogFormat::Text {
+            println!(
+                "\n{}",
+                style(format!(
+                    "📋 Following {}/{} (Ctrl-C to stop)",
+                    host, service
+                ))
+                .cyan()
+                .bold()
+            );
+        }
+
+        let result = match self
+            .stream_service_logs(&config.cloud_name, host, service, view)
+            .await
+        {
+            Ok(true) => Ok(()),
+            Ok(false) => {
+                self.poll_service_logs(&config.cloud_name, host, service, view, false)
+                    .await
+            }
+            Err(err) => Err(err),
+        };
+        // Ctrl-C can land while a spinner has the cursor hidden
+        let term = Term::stdout();
+        if term.is_term() {
+            let _ = term.show_cursor();
+        }
+        result
+    }
+
+    // Read the chunked (or server-sent events) log stream of a service until
+    // it closes or Ctrl-C. Returns false if the server has no such endpoint.
+    async fn stream_service_logs(
+        &self,
+        cloud_name: &str,
+        host: &str,
+        service: &str,
+        view: &LogView<'_>,
+    ) -> Result<bool> {
+        let url = format!(
+            "{}/platforms/{}/hosts/{}/services/{}/logs/stream",
+            self.api_client.base_url, cloud_name, host, service
+        );
+        // The shared client's timeout covers the whole body, which would cut
+        // a long-running stream short; only bound the connect here
+        let client = reqwest::Client::builder()
+            .connect_timeout(Duration::from_secs(self.api_client.config.timeout_seconds))
+            .build()?;
+        let mut request = client.get(&url).headers(self.api_client.headers.clone());
+        if let Some(tail) = view.tail {
+            request = request.query(&[("tail", tail)]);
+        }
+
+        let mut response = request.send().await?;
+        match response.status() {
+            status if status.is_success() => {}
+            StatusCode::NOT_FOUND
+            | StatusCode::METHOD_NOT_ALLOWED
+            | StatusCode::NOT_IMPLEMENTED => return Ok(false),
+            status => {
+                let body = response.text().await.unwrap_or_default();
+                return Err(ApiError::new(status, body).into());
+            }
+        }
+        let event_stream = response
+            .headers()
+            .get(CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .is_some_and(|value| value.starts_with("text/event-stream"));
+
+        let mut printer = LivePrinter::new(host, service, view);
+        let mut pending: Vec<u8> = Vec::new();
+        loop {
+            let chunk = tokio::select! {
+                chunk = response.chunk() => chunk?,
+                _ = tokio::signal::ctrl_c() => return Ok(true),
+            };
+            let Some(chunk) = chunk else { break };
+            pending.extend_from_slice(&chunk);
+            while let Some(end) = pending.iter().position(|byte| *byte == b'\n') {
+                let raw: Vec<u8> = pending.drain(..=end).collect();
+                if let Some(line) = stream_line(&raw, event_stream) {
+                    printer.print(&[line]);
+                }
+            }
+        }
+        if let Some(line) = stream_line(&pending, event_stream) {
+            printer.print(&[line]);
+        }
+
+        eprintln!("{}", style("--- log stream closed ---").dim());
+        Ok(true)
+    }
+
+    // Poll a service's logs, printing lines that weren't there last time.
+    // With `reconnect`, an unreachable log endpoint means the service is
+    // restarting and is waited out; otherwise it ends the stream.
+    async fn poll_service_logs(
+        &self,
+        cloud_name: &str,
+        host: &str,
+        service: &str,
+        view: &LogView<'_>,
+        reconnect: bool,
+    ) -> Result<()> {
+        let mut printer = LivePrinter::new(host, service, view);
+        let mut last_line: Option<String> = None;
+        loop {
+            match self
+                .fetch_service_logs(cloud_name, host, service, view.tail, view.since)
+                .await
+            {
+                Ok(lines) => {
+                    let new_lines = match &last_line {
+                        None => &lines[..],
+                        Some(last) => match lines.iter().rposition(|line| line == last) {
+                            Some(index) => &lines[index + 1..],
+                            // The last line we printed is gone: the log started over
+                            None => {
+                                eprintln!(
+                                    "{}",
+                                    style("--- service restarted, reconnecting logs ---").yellow()
+                                );
+                                &lines[..]
+                            }
+                        },
+                    };
+                    printer.print(new_lines);
+                    if let Some(line) = lines.last() {
+                        last_line = Some(line.clone());
+                    }
+                }
+                Err(err) if reconnect => {
+                    eprintln!(
+                        "{}",
+                        style(format!(
+                            "--- service restarted, reconnecting logs ({}) ---",
+                            err
+                        ))
+                        .yellow()
+                    );
+                    tokio::select! {
+                        result = self.wait_for_service_restart(cloud_name, host, service) => result?,
+                        _ = tokio::signal::ctrl_c() => break,
+                    }
+                    // The restarted service logs from scratch
+                    last_line = None;
+                    continue;
+                }
+                Err(err) => {
+                    eprintln!(
+                        "{}",
+                        style(format!("--- log stream closed ({}) ---", err)).dim()
+                    );
+                    break;
+                }
+            }
+
+            tokio::select! {
+                _ = tokio::time::sleep(Duration::from_secs(WATCH_POLL_SECONDS)) => {}
+                _ = tokio::signal::ctrl_c() => break,
+            }
+        }
+
+        Ok(())
+    }
+
+    // Fetch the logs of `targets` (host, service) pairs, at most `parallel`
+    // at a time, and print them merged by timestamp under `title`
+    pub async fn print_merged_logs(
+        &self,
+        cloud_name: &str,
+        targets: Vec<(String, String)>,
+        title: &str,
+        parallel: usize,
+        view: &LogView<'_>,
+    ) -> Result<()> {
+        let LogView {
+            tail,
+            since,
+            format,
+            grep,
+            context,
+        } = *view;
+        let mut spinner =
+            self.create_spinner(&format!("Fetching logs from {} services...", targets.len()));
+
+        let semaphore = Arc::new(Semaphore::new(parallel.max(1)));
+        let mut fetches = targets
+            .into_iter()
+            .map(|(host, service)| {
+                let semaphore = Arc::clone(&semaphore);
+                async move {
+                    let _permit = semaphore.acquire().await;
+                    let result = self
+                        .fetch_service_logs(cloud_name, &host, &service, tail, since)
+                        .await;
+                    (host, service, result)
+                }
+            })
+            .collect::<FuturesUnordered<_>>();
+
+        // One failing host shouldn't abort the others; collect and report at the end
+        let mut entries = Vec::new();
+        let mut failures = Vec::new();
+        while let Some((host, service, result)) = fetches.next().await {
+            match result {
+                Ok(lines) => entries.extend(lines.into_iter().map(|line| LogEntry {
+                    host: host.clone(),
+                    service: service.clone(),
+                    timestamp: log_timestamp(&line),
+                    line,
+                })),
+                Err(err) => failures.push((host, service, err)),
+            }
+        }
 
         spinner.stop();
 
-        for log in logs {
-            println!("{}", log);
+        // The server may ignore `since`, so filter again locally. Lines without a
+        // timestamp can't be placed and are kept.
+        if let Some(since) = since {
+            entries.retain(|entry| entry.timestamp.is_none_or(|t| t >= since));
+        }
+
+        // Results arrive in completion order, so sort for a deterministic merge
+        entries.sort_by(|a, b| {
+            a.timestamp
+                .cmp(&b.timestamp)
+                .then_with(|| a.host.cmp(&b.host))
+                .then_with(|| a.service.cmp(&b.service))
+        });
+
+        // Structured formats keep stdout to one record per line
+        if format == LogFormat::Text {
+            println!("\n{}", style(title).cyan().bold());
+            if entries.is_empty() {
+                println!("{}", style("No logs available.").yellow());
+            }
+        }
+        match grep {
+            Some(pattern) => {
+                let mut filter = GrepFilter::new(pattern, context);
+                for entry in &entries {
+                    let (separator, matched) = filter.push(&entry.line, entry);
+                    if separator {
+                        print_grep_separator(format);
+                    }
+                    for entry in matched {
+                        println!("{}", entry.render(format));
+                    }
+                }
+            }
+            None => {
+                for entry in &entries {
+                    println!("{}", entry.render(format));
+                }
+            }
+        }
+
+        if !failures.is_empty() {
+            eprintln!(
+                "\n{}",
+                style(format!(
+                    "⚠️  Failed to fetch logs from {} service(s):",
+                    failures.len()
+                ))
+                .yellow()
+                .bold()
+            );
+            for (host, service, err) in &failures {
+                eprintln!("  {}/{}: {}", host, service, err);
+            }
         }
 
         Ok(())
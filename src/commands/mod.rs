@@ -1,8 +1,27 @@
+pub mod apps;
+pub mod audit;
+pub mod auth;
+pub mod backup;
 pub mod config;
+pub mod context;
+pub mod deploy_log;
+pub mod doctor;
+pub mod explain;
+pub mod health;
+pub mod host;
+pub mod host_import;
 pub mod logs;
+pub mod notify;
+pub mod ping;
+pub mod preflight;
+pub mod prune;
 pub mod push;
+pub mod registry;
+pub mod releases;
 pub mod rollback;
 pub mod scale;
+pub mod secrets;
 pub mod status;
 pub mod up;
+pub mod wait;
 pub mod init_env;
\ No newline at end of file
@@ -0,0 +1,73 @@
+use crate::commands::audit::leaf_command;
+use crate::ui::PremiumUI;
+use clap::ArgMatches;
+use console::style;
+use std::io::Write;
+use std::process::Command;
+
+// Commands that can run long enough for the user to switch away
+const LONG_RUNNING_COMMANDS: &[&str] = &[
+    "init",
+    "up",
+    "backup now",
+    "backup restore",
+    "backup download",
+    "backup verify",
+    "config push",
+];
+
+// The command to notify about when it finishes, if it's a long-running one
+pub fn long_running_command(cli: &ArgMatches) -> Option<String> {
+    let (command, _) = leaf_command(cli);
+    LONG_RUNNING_COMMANDS
+        .contains(&command.as_str())
+        .then_some(command)
+}
+
+impl PremiumUI {
+    // Ring the terminal bell and, if a `notify_command` setting is configured,
+    // run it with a success/failure message as its last argument
+    pub fn notify_completion<T>(&self, command: &str, result: &anyhow::Result<T>) {
+        let message = match result {
+            Ok(_) => format!("omni {} finished successfully", command),
+            Err(err) => format!("omni {} failed: {}", command, err),
+        };
+
+        let mut stderr = std::io::stderr();
+        let _ = stderr.write_all(b"\x07");
+        let _ = stderr.flush();
+
+        let Some(notify_command) = self.api_client.get_setting::<String>("notify_command") else {
+            return;
+        };
+        // The message goes in as a separate argument so it's never parsed by the shell
+        #[cfg(unix)]
+        let status = Command::new("sh")
+            .arg("-c")
+            .arg(format!("{} \"$1\"", notify_command))
+            .arg("omni-notify")
+            .arg(&message)
+            .status();
+        #[cfg(not(unix))]
+        let status = Command::new("cmd")
+            .arg("/C")
+            .arg(format!(
+                "{} \"{}\"",
+                notify_command,
+                message.replace('"', "'")
+            ))
+            .status();
+
+        match status {
+            Ok(status) if status.success() => {}
+            Ok(status) => eprintln!(
+                "{}",
+                style(format!("notify_command exited with {}", status)).yellow()
+            ),
+            Err(err) => eprintln!(
+                "{}",
+                style(format!("Failed to run notify_command: {}", err)).yellow()
+            ),
+        }
+    }
+}
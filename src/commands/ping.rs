@@ -0,0 +1,65 @@
+use crate::ui::PremiumUI;
+use anyhow::Result;
+use console::style;
+use std::time::Instant;
+use tokio::time::Duration;
+
+impl PremiumUI {
+    // Repeatedly hit the health endpoint and report round-trip times, like
+    // the unix `ping`
+    pub async fn ping(&self, count: u32) -> Result<()> {
+        println!(
+            "{}",
+            style(format!("PING {}/health", self.api_client.base_url))
+                .cyan()
+                .bold()
+        );
+
+        let mut times_ms = Vec::new();
+        for seq in 1..=count {
+            let started = Instant::now();
+            match self.api_client.get::<serde_json::Value>("/health").await {
+                Ok(_) => {
+                    let elapsed_ms = started.elapsed().as_secs_f64() * 1000.0;
+                    times_ms.push(elapsed_ms);
+                    println!("reply: seq={} time={:.1} ms", seq, elapsed_ms);
+                }
+                Err(err) => {
+                    println!("{}", style(format!("seq={} failed: {}", seq, err)).red());
+                }
+            }
+
+            if seq < count {
+                tokio::time::sleep(Duration::from_secs(1)).await;
+            }
+        }
+
+        let failed = count as usize - times_ms.len();
+        println!("\n{}", style("--- ping statistics ---").bold());
+        println!(
+            "{} requests, {} succeeded, {:.0}% failed",
+            count,
+            times_ms.len(),
+            failed as f64 * 100.0 / count as f64
+        );
+
+        if times_ms.is_empty() {
+            anyhow::bail!("No successful responses from {}", self.api_client.base_url);
+        }
+
+        let min = times_ms.iter().cloned().fold(f64::INFINITY, f64::min);
+        let max = times_ms.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+        let avg = times_ms.iter().sum::<f64>() / times_ms.len() as f64;
+        let variance =
+            times_ms.iter().map(|t| (t - avg).powi(2)).sum::<f64>() / times_ms.len() as f64;
+        println!(
+            "rtt min/avg/max/stddev = {:.1}/{:.1}/{:.1}/{:.1} ms",
+            min,
+            avg,
+            max,
+            variance.sqrt()
+        );
+
+        Ok(())
+    }
+}
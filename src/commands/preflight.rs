@@ -0,0 +1,398 @@
+use crate::commands::context::response_field;
+use crate::commands::init_env::{
+    parse_cloud_config, resolve_env_references, validate_ssh_host, CloudConfig, ConfigFileFormat,
+    SshHost,
+};
+use crate::ui::PremiumUI;
+use anyhow::{anyhow, Result};
+use console::style;
+use dialoguer::Confirm;
+use libomni::types::db::v1 as types;
+use serde_json::Value;
+use std::fs;
+use std::io::Read;
+use std::net::{TcpStream, ToSocketAddrs};
+use std::path::Path;
+use std::time::Duration;
+use tabled::{Table, Tabled};
+
+// How long to wait for a host's SSH port to accept a connection
+const HOST_CONNECT_TIMEOUT_SECONDS: u64 = 5;
+// How long to wait for the server's identification line once connected
+const SSH_BANNER_TIMEOUT_SECONDS: u64 = 3;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CheckStatus {
+    Pass,
+    Warn,
+    Fail,
+}
+
+impl CheckStatus {
+    fn label(self) -> &'static str {
+        match self {
+            CheckStatus::Pass => "✅ pass",
+            CheckStatus::Warn => "⚠️  warn",
+            CheckStatus::Fail => "❌ fail",
+        }
+    }
+}
+
+// One line of the readiness report
+pub struct ReadinessCheck {
+    pub name: String,
+    pub status: CheckStatus,
+    pub detail: String,
+}
+
+impl ReadinessCheck {
+    pub fn new(name: impl Into<String>, status: CheckStatus, detail: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            status,
+            detail: detail.into(),
+        }
+    }
+}
+
+#[derive(Tabled)]
+struct ReadinessDisplay {
+    #[tabled(rename = "Check")]
+    name: String,
+    #[tabled(rename = "Status")]
+    status: String,
+    #[tabled(rename = "Details")]
+    detail: String,
+}
+
+// Open a TCP connection to the host's SSH port, trying each resolved address
+pub fn check_host_reachable(host: &SshHost, timeout: Duration) -> Result<TcpStream> {
+    let addresses = (host.hostname.as_str(), host.port)
+        .to_socket_addrs()
+        .map_err(|err| anyhow!("cannot resolve {}: {}", host.hostname, err))?;
+
+    let mut last_error = None;
+    for address in addresses {
+        match TcpStream::connect_timeout(&address, timeout) {
+            Ok(stream) => return Ok(stream),
+            Err(err) => last_error = Some(err),
+        }
+    }
+    match last_error {
+        Some(err) => Err(anyhow!("{}:{}: {}", host.hostname, host.port, err)),
+        None => Err(anyhow!("{} resolved to no addresses", host.hostname)),
+    }
+}
+
+// SSH servers announce themselves with an "SSH-2.0-..." line before the
+// client sends anything, so reading it is a handshake check without auth
+fn read_ssh_banner(stream: &mut TcpStream) -> Option<String> {
+    stream
+        .set_read_timeout(Some(Duration::from_secs(SSH_BANNER_TIMEOUT_SECONDS)))
+        .ok()?;
+    let mut buffer = [0u8; 255];
+    let read = stream.read(&mut buffer).ok()?;
+    let banner = String::from_utf8_lossy(&buffer[..read]);
+    let line = banner.lines().next()?.trim();
+    line.starts_with("SSH-").then(|| line.to_string())
+}
+
+// Result of probing one host's SSH port
+pub enum HostProbe {
+    Ssh(String),
+    NoBanner,
+    Unreachable(String),
+}
+
+// Probe every host's SSH port in parallel
+pub async fn probe_hosts(hosts: &[SshHost]) -> Vec<(SshHost, HostProbe)> {
+    let timeout = Duration::from_secs(HOST_CONNECT_TIMEOUT_SECONDS);
+    let probes = hosts.iter().cloned().map(|host| {
+        tokio::task::spawn_blocking(move || {
+            let probe = match check_host_reachable(&host, timeout) {
+                Ok(mut stream) => match read_ssh_banner(&mut stream) {
+                    Some(banner) => HostProbe::Ssh(banner),
+                    None => HostProbe::NoBanner,
+                },
+                Err(err) => HostProbe::Unreachable(err.to_string()),
+            };
+            (host, probe)
+        })
+    });
+
+    futures::future::join_all(probes)
+        .await
+        .into_iter()
+        .zip(hosts)
+        .map(|(probe, host)| {
+            probe.unwrap_or_else(|err| (host.clone(), HostProbe::Unreachable(err.to_string())))
+        })
+        .collect()
+}
+
+#[derive(Tabled)]
+struct HostProbeDisplay {
+    #[tabled(rename = "Host")]
+    name: String,
+    #[tabled(rename = "Address")]
+    address: String,
+    #[tabled(rename = "Result")]
+    result: String,
+}
+
+impl PremiumUI {
+    // `init --validate-only`: everything a bootstrap needs, checked without
+    // POSTing to /platforms/init
+    pub async fn init_validate(&self, config_path: &Path) -> Result<()> {
+        println!("\n{}", style("🔎 Bootstrap Readiness").cyan().bold());
+        let mut spinner = self.create_spinner("Running pre-flight checks...");
+
+        let mut checks = Vec::new();
+        let config = self.check_config_file(config_path, &mut checks);
+        if let Some(config) = &config {
+            self.check_hosts(config, &mut checks).await;
+            self.check_region(config, &mut checks).await;
+        }
+        self.check_api(&mut checks).await;
+
+        spinner.stop();
+        self.print_readiness(&checks);
+
+        let failures = checks
+            .iter()
+            .filter(|check| check.status == CheckStatus::Fail)
+            .count();
+        if failures > 0 {
+            return Err(anyhow!(
+                "{} readiness check(s) failed; fix them before bootstrapping",
+                failures
+            ));
+        }
+        println!(
+            "\n{}",
+            style("Ready to bootstrap. Run 'omni init' to proceed.").green()
+        );
+        Ok(())
+    }
+
+    pub fn check_config_file(
+        &self,
+        config_path: &Path,
+        checks: &mut Vec<ReadinessCheck>,
+    ) -> Option<CloudConfig> {
+        let name = "Config file";
+        let contents = match fs::read_to_string(config_path) {
+            Ok(contents) => contents,
+            Err(err) => {
+                checks.push(ReadinessCheck::new(
+                    name,
+                    CheckStatus::Fail,
+                    format!("cannot read {}: {}", config_path.display(), err),
+                ));
+                return None;
+            }
+        };
+        let config = match parse_cloud_config(&contents, ConfigFileFormat::from_path(config_path)) {
+            Ok(config) => config,
+            Err(problem) => {
+                checks.push(ReadinessCheck::new(name, CheckStatus::Fail, problem));
+                return None;
+            }
+        };
+        let config = match resolve_env_references(config) {
+            Ok(config) => config,
+            Err(err) => {
+                checks.push(ReadinessCheck::new(
+                    name,
+                    CheckStatus::Fail,
+                    err.to_string(),
+                ));
+                return None;
+            }
+        };
+
+        let problems: Vec<String> = config
+            .ssh_hosts
+            .iter()
+            .enumerate()
+            .filter_map(|(index, host)| {
+                validate_ssh_host(host, &config.ssh_hosts[..index])
+                    .err()
+                    .map(|err| format!("{}: {}", host.name, err))
+            })
+            .collect();
+        if config.ssh_hosts.is_empty() {
+            checks.push(ReadinessCheck::new(
+                name,
+                CheckStatus::Fail,
+                "no SSH hosts configured",
+            ));
+        } else if !problems.is_empty() {
+            checks.push(ReadinessCheck::new(
+                name,
+                CheckStatus::Fail,
+                problems.join("; "),
+            ));
+        } else {
+            checks.push(ReadinessCheck::new(
+                name,
+                CheckStatus::Pass,
+                format!(
+                    "{} ({} host(s))",
+                    config_path.display(),
+                    config.ssh_hosts.len()
+                ),
+            ));
+        }
+        Some(config)
+    }
+
+    async fn check_hosts(&self, config: &CloudConfig, checks: &mut Vec<ReadinessCheck>) {
+        for (host, probe) in probe_hosts(&config.ssh_hosts).await {
+            let name = format!("Host {}", host.name);
+            let check = match probe {
+                HostProbe::Ssh(banner) => ReadinessCheck::new(
+                    name,
+                    CheckStatus::Pass,
+                    format!("{}:{} reachable ({})", host.hostname, host.port, banner),
+                ),
+                HostProbe::NoBanner => ReadinessCheck::new(
+                    name,
+                    CheckStatus::Warn,
+                    format!(
+                        "{}:{} accepts connections but sent no SSH banner",
+                        host.hostname, host.port
+                    ),
+                ),
+                HostProbe::Unreachable(err) => ReadinessCheck::new(name, CheckStatus::Fail, err),
+            };
+            checks.push(check);
+        }
+    }
+
+    // Check every host's SSH port before bootstrapping. Returns false when
+    // some host is unreachable and the user chooses to abort.
+    pub async fn preflight_ssh_hosts(&self, hosts: &[SshHost]) -> Result<bool> {
+        let mut spinner = self.create_spinner("Checking SSH connectivity...");
+        let probes = probe_hosts(hosts).await;
+        spinner.stop();
+
+        let mut unreachable = 0;
+        let rows: Vec<HostProbeDisplay> = probes
+            .into_iter()
+            .map(|(host, probe)| {
+                let result = match probe {
+                    HostProbe::Ssh(banner) => format!("✅ reachable ({})", banner),
+                    HostProbe::NoBanner => "⚠️  reachable, no SSH banner".to_string(),
+                    HostProbe::Unreachable(err) => {
+                        unreachable += 1;
+                        format!("❌ unreachable: {}", err)
+                    }
+                };
+                HostProbeDisplay {
+                    name: host.name,
+                    address: format!("{}:{}", host.hostname, host.port),
+                    result,
+                }
+            })
+            .collect();
+
+        println!("\n{}", style("🔌 SSH Connectivity").cyan().bold());
+        println!("{}", self.render_table(Table::new(rows)));
+
+        if unreachable == 0 {
+            return Ok(true);
+        }
+        let proceed = Confirm::with_theme(&self.theme)
+            .with_prompt(format!(
+                "{} of {} host(s) are unreachable. Continue bootstrapping anyway?",
+                unreachable,
+                hosts.len()
+            ))
+            .default(false)
+            .interact()?;
+        Ok(proceed)
+    }
+
+    async fn check_region(&self, config: &CloudConfig, checks: &mut Vec<ReadinessCheck>) {
+        let name = "Region";
+        let check = match self
+            .api_client
+            .get_cached::<Vec<types::region::Region>>("/regions")
+            .await
+        {
+            Ok(regions) if regions.iter().any(|r| r.name == config.region) => {
+                ReadinessCheck::new(name, CheckStatus::Pass, config.region.clone())
+            }
+            // Custom regions are allowed, so an unknown one is only a warning
+            Ok(_) => ReadinessCheck::new(
+                name,
+                CheckStatus::Warn,
+                format!("'{}' is not a region known to the server", config.region),
+            ),
+            Err(err) => ReadinessCheck::new(
+                name,
+                CheckStatus::Fail,
+                format!("cannot list regions: {}", err),
+            ),
+        };
+        checks.push(check);
+    }
+
+    async fn check_api(&self, checks: &mut Vec<ReadinessCheck>) {
+        match self.api_client.get::<Value>("/version").await {
+            Ok(response) => checks.push(ReadinessCheck::new(
+                "API",
+                CheckStatus::Pass,
+                format!(
+                    "{} (server {})",
+                    self.api_client.base_url,
+                    response_field(&response, "version").unwrap_or_else(|| "unknown".into())
+                ),
+            )),
+            Err(err) => {
+                checks.push(ReadinessCheck::new(
+                    "API",
+                    CheckStatus::Fail,
+                    format!("{} unreachable: {}", self.api_client.base_url, err),
+                ));
+                // Authentication can't be checked against an API that's down
+                return;
+            }
+        }
+        self.check_authentication(checks).await;
+    }
+
+    // Whether a token is stored and the server still accepts it
+    pub async fn check_authentication(&self, checks: &mut Vec<ReadinessCheck>) {
+        let check = if self.api_client.get_setting::<String>("api_key").is_none() {
+            ReadinessCheck::new("Authentication", CheckStatus::Warn, "not logged in")
+        } else {
+            match self.api_client.get::<Value>("/auth/me").await {
+                Ok(response) => ReadinessCheck::new(
+                    "Authentication",
+                    CheckStatus::Pass,
+                    response_field(&response, "username").unwrap_or_else(|| "authenticated".into()),
+                ),
+                Err(err) => ReadinessCheck::new(
+                    "Authentication",
+                    CheckStatus::Fail,
+                    format!("stored token rejected: {}", err),
+                ),
+            }
+        };
+        checks.push(check);
+    }
+
+    pub fn print_readiness(&self, checks: &[ReadinessCheck]) {
+        let rows: Vec<ReadinessDisplay> = checks
+            .iter()
+            .map(|check| ReadinessDisplay {
+                name: check.name.clone(),
+                status: check.status.label().to_string(),
+                detail: check.detail.clone(),
+            })
+            .collect();
+        println!("{}", self.render_table(Table::new(rows)));
+    }
+}
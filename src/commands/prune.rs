@@ -0,0 +1,272 @@
+use crate::commands::init_env::{ApiResponse, StatusData};
+use crate::models::AppState;
+use crate::ui::PremiumUI;
+use anyhow::{anyhow, Context, Result};
+use chrono::{DateTime, FixedOffset, Utc};
+use console::style;
+use dialoguer::Confirm;
+
+// Parse an age like `30d`, `12h`, `2w` or `45m` for `prune --older-than`
+pub fn parse_age(value: &str) -> std::result::Result<chrono::Duration, String> {
+    let value = value.trim();
+    let split = value
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(value.len());
+    let (amount, unit) = value.split_at(split);
+    let amount: i64 = amount
+        .parse()
+        .map_err(|_| format!("invalid age '{}' (expected e.g. 30d, 12h, 2w)", value))?;
+    match unit {
+        "m" => Ok(chrono::Duration::minutes(amount)),
+        "h" => Ok(chrono::Duration::hours(amount)),
+        "d" | "" => Ok(chrono::Duration::days(amount)),
+        "w" => Ok(chrono::Duration::weeks(amount)),
+        _ => Err(format!(
+            "unknown unit '{}' in '{}' (use m, h, d or w)",
+            unit, value
+        )),
+    }
+}
+
+fn parse_time(value: Option<&str>) -> Option<DateTime<FixedOffset>> {
+    value.and_then(|value| DateTime::parse_from_rfc3339(value).ok())
+}
+
+// Something `prune` may delete
+struct PruneCandidate {
+    id: String,
+    created: Option<DateTime<FixedOffset>>,
+}
+
+// Pick what to delete: everything outside the newest `keep` (when given)
+// that is also older than `older_than` (when given). Items without a
+// timestamp can't be ordered or aged, so they are never pruned and don't
+// count towards `keep`.
+fn select_for_pruning(
+    candidates: Vec<PruneCandidate>,
+    keep: Option<usize>,
+    older_than: Option<chrono::Duration>,
+) -> Vec<PruneCandidate> {
+    let mut dated: Vec<(DateTime<FixedOffset>, PruneCandidate)> = candidates
+        .into_iter()
+        .filter_map(|candidate| candidate.created.map(|created| (created, candidate)))
+        .collect();
+    dated.sort_by_key(|(created, _)| std::cmp::Reverse(*created));
+    let cutoff = older_than.map(|age| Utc::now() - age);
+    dated
+        .into_iter()
+        .enumerate()
+        .filter(|(index, _)| keep.is_none_or(|keep| *index >= keep))
+        .filter(|(_, (created, _))| cutoff.is_none_or(|cutoff| *created < cutoff))
+        .map(|(_, (_, candidate))| candidate)
+        .collect()
+}
+
+impl PremiumUI {
+    // List what will be deleted, confirm, then delete each item at its
+    // endpoint. Failures are reported together at the end.
+    async fn prune_items(
+        &self,
+        kind: &str,
+        items: Vec<PruneCandidate>,
+        endpoint: impl Fn(&str) -> String,
+        dry_run: bool,
+        yes: bool,
+    ) -> Result<()> {
+        if items.is_empty() {
+            println!("{}", style(format!("No {} to prune.", kind)).green());
+            return Ok(());
+        }
+
+        println!(
+            "\n{}",
+            style(format!("🧹 {} {} to delete:", items.len(), kind))
+                .cyan()
+                .bold()
+        );
+        for item in &items {
+            let created = item
+                .created
+                .map(|created| created.format("%Y-%m-%d %H:%M").to_string())
+                .unwrap_or_else(|| "-".to_string());
+            println!("  {}  {}", item.id, style(created).dim());
+        }
+
+        if dry_run {
+            println!("\n{}", style("Dry run: nothing was deleted.").dim());
+            return Ok(());
+        }
+        if !yes {
+            let confirm = Confirm::with_theme(&self.theme)
+                .with_prompt(format!("⚠️  Delete {} {}?", items.len(), kind))
+                .default(false)
+                .interact()?;
+            if !confirm {
+                println!("{}", style("Prune cancelled.").yellow());
+                return Ok(());
+            }
+        }
+
+        let mut failures = Vec::new();
+        for item in &items {
+            if let Err(err) = self
+                .api_client
+                .delete::<ApiResponse>(&endpoint(&item.id))
+                .await
+            {
+                failures.push((item.id.clone(), err));
+            }
+        }
+
+        let deleted = items.len() - failures.len();
+        println!(
+            "{}",
+            style(format!("✓ Deleted {} {}", deleted, kind)).green()
+        );
+        if !failures.is_empty() {
+            for (id, err) in &failures {
+                eprintln!("  {}: {}", id, err);
+            }
+            return Err(anyhow!("Failed to delete {} {}", failures.len(), kind));
+        }
+        Ok(())
+    }
+
+    // Delete all but the `keep` most recent releases of an app. The active
+    // release is never deleted.
+    pub async fn prune_releases(
+        &self,
+        app: &str,
+        keep: usize,
+        dry_run: bool,
+        yes: bool,
+    ) -> Result<()> {
+        let releases = self.list_releases(app).await?;
+        if releases.is_empty() {
+            return self
+                .prune_items("release(s)", Vec::new(), |id| id.to_string(), dry_run, yes)
+                .await;
+        }
+
+        // Deleting is only safe once we know which release is live, so don't
+        // guess from the release statuses
+        let response = self
+            .api_client
+            .get::<ApiResponse>(&format!("/apps/{}", app))
+            .await
+            .with_context(|| format!("Failed to find the active release of {}", app))?;
+        let active = match self.parse_status_data::<AppState>(response.data) {
+            StatusData::Available(AppState {
+                release: Some(release),
+                ..
+            }) => release,
+            StatusData::Available(_) | StatusData::Missing => {
+                return Err(anyhow!(
+                    "The server didn't report an active release for {}; nothing was pruned",
+                    app
+                ));
+            }
+            StatusData::Malformed => {
+                return Err(anyhow!("Unexpected server response for app data"));
+            }
+        };
+
+        let candidates = releases
+            .into_iter()
+            .filter(|release| {
+                release.id != active && !release.status.eq_ignore_ascii_case("active")
+            })
+            .map(|release| PruneCandidate {
+                created: parse_time(
+                    release
+                        .started_at
+                        .as_deref()
+                        .or(release.created_at.as_deref()),
+                ),
+                id: release.id,
+            })
+            .collect();
+        // The active release counts towards the ones kept
+        let doomed = select_for_pruning(candidates, Some(keep.saturating_sub(1)), None);
+
+        self.prune_items(
+            "release(s)",
+            doomed,
+            |id| format!("/apps/{}/releases/{}", app, id),
+            dry_run,
+            yes,
+        )
+        .await
+    }
+
+    pub async fn prune_backups(
+        &self,
+        keep: Option<usize>,
+        older_than: Option<chrono::Duration>,
+        dry_run: bool,
+        yes: bool,
+    ) -> Result<()> {
+        let config = self.load_cloud_config()?;
+        if config.enable_backups {
+            self.info(format!(
+                "Retention policy: backups are kept for {} days",
+                config.backup_retention_days
+            ));
+        }
+
+        let candidates = self
+            .list_backups(&config.cloud_name)
+            .await
+            .context("Failed to list backups")?
+            .into_iter()
+            .map(|backup| PruneCandidate {
+                created: parse_time(backup.created_at.as_deref()),
+                id: backup.id,
+            })
+            .collect();
+        let doomed = select_for_pruning(candidates, keep, older_than);
+
+        let cloud_name = config.cloud_name.clone();
+        self.prune_items(
+            "backup(s)",
+            doomed,
+            |id| format!("/platforms/{}/backups/{}", cloud_name, id),
+            dry_run,
+            yes,
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn candidate(id: &str, created: Option<&str>) -> PruneCandidate {
+        PruneCandidate {
+            id: id.to_string(),
+            created: parse_time(created),
+        }
+    }
+
+    #[test]
+    fn undated_items_are_never_pruned() {
+        let candidates = vec![
+            candidate("undated", None),
+            candidate("old", Some("2024-01-01T00:00:00Z")),
+            candidate("new", Some("2024-06-01T00:00:00Z")),
+        ];
+        let doomed: Vec<String> = select_for_pruning(candidates, Some(1), None)
+            .into_iter()
+            .map(|candidate| candidate.id)
+            .collect();
+        assert_eq!(doomed, vec!["old"]);
+
+        let aged = select_for_pruning(
+            vec![candidate("undated", None)],
+            None,
+            Some(chrono::Duration::days(1)),
+        );
+        assert!(aged.is_empty());
+    }
+}
@@ -1,44 +1,231 @@
+use crate::api_client::ApiError;
+use crate::commands::init_env::{ApiResponse, StatusData};
+use crate::commands::registry::{DEFAULT_REGISTRY_SETTING, REGISTRY_NAMESPACE_SETTING};
+use crate::commands::wait::WaitKind;
+use crate::models::PushStatus;
 use crate::ui::PremiumUI;
-use anyhow::Result;
+use anyhow::{anyhow, Context, Result};
+use clap::ArgMatches;
 use console::style;
-use dialoguer::{Input, Select};
-use std::{thread, time::Duration};
+use dialoguer::Select;
+use indicatif::ProgressBar;
+use reqwest::StatusCode;
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+// Seconds between polls of the push status endpoint
+const PUSH_POLL_INTERVAL_SECONDS: u64 = 2;
+// How long a push may take before we stop waiting on it
+const PUSH_TIMEOUT: Duration = Duration::from_secs(30 * 60);
+
+// Docker's tag rules: up to 128 characters of letters, digits, '_', '.'
+// and '-', not starting with '.' or '-'
+fn validate_tag(tag: &str) -> Result<(), String> {
+    if tag.is_empty() || tag.len() > 128 {
+        return Err("must be between 1 and 128 characters".to_string());
+    }
+    if !tag
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '.' | '-'))
+    {
+        return Err("may only contain letters, digits, '_', '.' and '-'".to_string());
+    }
+    if tag.starts_with(['.', '-']) {
+        return Err("must not start with '.' or '-'".to_string());
+    }
+    Ok(())
+}
 
 impl PremiumUI {
-    pub async fn push_interactive(&self) -> Result<()> {
-        let tag: String = Input::with_theme(&self.theme)
-            .with_prompt("Enter image tag")
-            .default("latest".into())
-            .interact_text()?;
-
-        let registries = vec!["Docker Hub", "Google Container Registry", "Amazon ECR"];
-        let registry = Select::with_theme(&self.theme)
-            .with_prompt("Select registry")
-            .items(&registries)
-            .interact()?;
-
-        println!("\n{}", style("📦 Pushing image...").cyan().bold());
-
-        let pb = self.create_progress_bar(100, "Preparing image");
-        for i in 0..100 {
-            pb.inc(1);
-            thread::sleep(Duration::from_millis(50));
-
-            match i {
-                20 => pb.set_message("Building layers..."),
-                50 => pb.set_message("Optimizing image..."),
-                80 => pb.set_message("Pushing to registry..."),
-                _ => {}
+    pub async fn push_interactive(&mut self, matches: &ArgMatches) -> Result<()> {
+        let tag = matches
+            .get_one::<String>("tag")
+            .map(String::as_str)
+            .unwrap_or("latest");
+        validate_tag(tag).map_err(|problem| anyhow!("Invalid image tag '{}': {}", tag, problem))?;
+
+        let app = self
+            .select_app(
+                matches.get_one::<String>("app").map(String::as_str),
+                "Select app to push",
+            )
+            .await?;
+
+        let resolved = self.resolve_registry(
+            matches.get_one::<String>("registry").map(String::as_str),
+            matches.get_one::<String>("environment").map(String::as_str),
+        );
+        let registry = match resolved {
+            Some(registry) => registry,
+            None => {
+                // Offer the registries the user has logged in to, if any
+                let configured: Vec<String> =
+                    self.registries().into_iter().map(|r| r.url).collect();
+                let registries: Vec<String> = if configured.is_empty() {
+                    vec![
+                        "docker.io".to_string(),
+                        "gcr.io".to_string(),
+                        "public.ecr.aws".to_string(),
+                    ]
+                } else {
+                    configured
+                };
+                let selection = Select::with_theme(&self.theme)
+                    .with_prompt("Select registry")
+                    .items(&registries)
+                    .interact()?;
+                let registry = registries[selection].clone();
+                self.api_client
+                    .set_setting(DEFAULT_REGISTRY_SETTING, &registry)?;
+                self.info(format!("Saved {} as the default registry", registry));
+                registry
             }
+        };
+        let namespace = self
+            .api_client
+            .get_setting::<String>(REGISTRY_NAMESPACE_SETTING);
+
+        let mut body = serde_json::json!({
+            "tag": tag,
+            "registry": registry,
+            "namespace": namespace,
+        });
+        match self.registry_credentials(&registry) {
+            Some((username, password)) => {
+                self.info(format!("Authenticating to {} as {}", registry, username));
+                body["credentials"] = serde_json::json!({
+                    "username": username,
+                    "password": password,
+                });
+            }
+            None => println!(
+                "{}",
+                style(format!(
+                    "⚠️  No credentials stored for {}. Run 'omni registry login {}' first if it requires authentication.",
+                    registry, registry
+                ))
+                .yellow()
+            ),
         }
-        pb.finish_with_message("✓ Image pushed successfully!");
 
-        println!("\n{}", style("🏷️  Image Details").cyan().bold());
-        println!("Registry: {}", style(registries[registry]).green());
-        println!("Tag:      {}", style(tag).green());
-        println!("Size:     {}", style("156.4 MB").green());
-        println!("Layers:   {}", style("12").green());
+        let image = match &namespace {
+            Some(namespace) => format!("{}/{}/{}:{}", registry, namespace, app, tag),
+            None => format!("{}/{}:{}", registry, app, tag),
+        };
+        println!(
+            "\n{}",
+            style(format!("📦 Pushing {}...", image)).cyan().bold()
+        );
+
+        self.api_client
+            .post::<_, ApiResponse>(&format!("/apps/{}/images/push", app), &body)
+            .await
+            .with_context(|| format!("Failed to start pushing {}", image))?;
 
+        if self.wait_for_push(&app, tag).await? {
+            println!("{}", style(format!("✨ Pushed {}", image)).green().bold());
+        }
         Ok(())
     }
+
+    // Poll the push until the server reports it finished, with a progress
+    // bar per layer. Returns false when the server doesn't report progress.
+    async fn wait_for_push(&self, app: &str, tag: &str) -> Result<bool> {
+        let endpoint = format!("/apps/{}/images/{}/push/status", app, tag);
+        let mut layers: HashMap<String, ProgressBar> = HashMap::new();
+        let started = Instant::now();
+        let deadline = self.wait_deadline_or(WaitKind::Push, PUSH_TIMEOUT);
+        let mut last_status: Option<PushStatus> = None;
+
+        while Instant::now() < deadline {
+            let status = match self.api_client.get::<ApiResponse>(&endpoint).await {
+                Ok(response) => match self.parse_status_data::<PushStatus>(response.data) {
+                    StatusData::Available(status) => Some(status),
+                    StatusData::Missing => None,
+                    StatusData::Malformed => {
+                        for pb in layers.values() {
+                            pb.abandon();
+                        }
+                        return Err(anyhow!("Unexpected server response for push status"));
+                    }
+                },
+                Err(err)
+                    if matches!(
+                        ApiError::status_of(&err),
+                        Some(
+                            StatusCode::NOT_FOUND
+                                | StatusCode::METHOD_NOT_ALLOWED
+                                | StatusCode::NOT_IMPLEMENTED
+                        )
+                    ) =>
+                {
+                    println!(
+                        "{}",
+                        style(
+                            "⚠️  This server doesn't report push progress. The push was \
+                             started; check the registry to confirm it completed."
+                        )
+                        .yellow()
+                    );
+                    return Ok(false);
+                }
+                Err(err) => {
+                    for pb in layers.values() {
+                        pb.abandon();
+                    }
+                    return Err(err.context("Failed to fetch the push status"));
+                }
+            };
+
+            if let Some(status) = status {
+                for layer in &status.layers {
+                    let pb = layers.entry(layer.id.clone()).or_insert_with(|| {
+                        let short_id: String = layer.id.chars().take(12).collect();
+                        self.create_bytes_progress_bar(0, &short_id)
+                    });
+                    if let Some(total) = layer.total_bytes {
+                        pb.set_length(total);
+                    }
+                    pb.set_position(layer.current_bytes);
+                    if matches!(layer.status.as_str(), "pushed" | "exists") && !pb.is_finished() {
+                        let short_id: String = layer.id.chars().take(12).collect();
+                        pb.finish_with_message(format!("{} {} ✓", short_id, layer.status));
+                    }
+                }
+
+                match status.status.as_str() {
+                    "succeeded" => {
+                        for pb in layers.values().filter(|pb| !pb.is_finished()) {
+                            pb.finish();
+                        }
+                        return Ok(true);
+                    }
+                    "failed" => {
+                        for pb in layers.values() {
+                            pb.abandon();
+                        }
+                        return Err(anyhow!(
+                            "The server reported that pushing {}:{} failed: {}",
+                            app,
+                            tag,
+                            status.message.as_deref().unwrap_or("no reason given")
+                        ));
+                    }
+                    _ => {}
+                }
+                last_status = Some(status);
+            }
+
+            tokio::time::sleep(Duration::from_secs(PUSH_POLL_INTERVAL_SECONDS)).await;
+        }
+
+        for pb in layers.values() {
+            pb.abandon();
+        }
+        Err(self.wait_timed_out(
+            &format!("the push of {}:{}", app, tag),
+            started.elapsed(),
+            last_status.as_ref(),
+        ))
+    }
 }
@@ -0,0 +1,253 @@
+use crate::ui::PremiumUI;
+use anyhow::{anyhow, Result};
+use console::style;
+use dialoguer::{Input, Password};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use tabled::{Table, Tabled};
+
+// Settings key holding the configured registries
+const REGISTRIES_SETTING: &str = "registries";
+// Registry used when neither --registry nor an environment mapping applies
+pub const DEFAULT_REGISTRY_SETTING: &str = "registry.default";
+// Namespace (organization or project) images are pushed under
+pub const REGISTRY_NAMESPACE_SETTING: &str = "registry.namespace";
+// Map of deploy environment name to registry URL
+const ENVIRONMENT_REGISTRIES_SETTING: &str = "registry.environments";
+// Keyring service name under which registry passwords are stored
+const KEYRING_SERVICE: &str = "omni-registry";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RegistryEntry {
+    pub url: String,
+    pub username: String,
+    // Only set when the system keyring couldn't be used
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub password: Option<String>,
+}
+
+#[derive(Tabled)]
+struct RegistryDisplay {
+    #[tabled(rename = "Registry")]
+    url: String,
+    #[tabled(rename = "Username")]
+    username: String,
+    #[tabled(rename = "Credentials")]
+    storage: String,
+    #[tabled(rename = "Default")]
+    is_default: String,
+}
+
+// Registries are keyed by host and path only, so `https://ghcr.io/` and
+// `ghcr.io` refer to the same credentials
+pub fn normalize_registry_url(url: &str) -> String {
+    let url = url.trim();
+    let url = url
+        .strip_prefix("https://")
+        .or_else(|| url.strip_prefix("http://"))
+        .unwrap_or(url);
+    url.trim_end_matches('/').to_lowercase()
+}
+
+impl PremiumUI {
+    pub fn registries(&self) -> Vec<RegistryEntry> {
+        self.api_client
+            .get_setting_or::<Vec<RegistryEntry>>(REGISTRIES_SETTING, Vec::new())
+    }
+
+    pub fn default_registry(&self) -> Option<String> {
+        self.api_client
+            .get_setting::<String>(DEFAULT_REGISTRY_SETTING)
+            .map(|url| normalize_registry_url(&url))
+    }
+
+    // Pick the registry to push to: an explicit --registry wins, then the
+    // registry mapped to the target environment, then the default
+    pub fn resolve_registry(
+        &self,
+        requested: Option<&str>,
+        environment: Option<&str>,
+    ) -> Option<String> {
+        if let Some(url) = requested {
+            return Some(normalize_registry_url(url));
+        }
+
+        let by_environment = environment.and_then(|environment| {
+            self.api_client
+                .get_setting::<HashMap<String, String>>(ENVIRONMENT_REGISTRIES_SETTING)?
+                .into_iter()
+                .find(|(name, _)| name.eq_ignore_ascii_case(environment))
+                .map(|(_, url)| normalize_registry_url(&url))
+        });
+
+        by_environment.or_else(|| self.default_registry())
+    }
+
+    // Look up the username and password stored for a registry
+    pub fn registry_credentials(&self, url: &str) -> Option<(String, String)> {
+        let url = normalize_registry_url(url);
+        let entry = self.registries().into_iter().find(|r| r.url == url)?;
+        let password = match entry.password {
+            Some(password) => password,
+            None => keyring::Entry::new(KEYRING_SERVICE, &url)
+                .and_then(|e| e.get_password())
+                .ok()?,
+        };
+        Some((entry.username, password))
+    }
+
+    pub async fn registry_login(&mut self, url: &str) -> Result<()> {
+        let url = normalize_registry_url(url);
+        let mut registries = self.registries();
+        let existing = registries.iter().position(|r| r.url == url);
+
+        println!("\n{}", style(format!("🔐 Log in to {}", url)).cyan().bold());
+        let mut username_input = Input::<String>::with_theme(&self.theme).with_prompt("Username");
+        if let Some(index) = existing {
+            username_input = username_input.default(registries[index].username.clone());
+        }
+        let username = username_input.interact_text()?;
+        let password = Password::with_theme(&self.theme)
+            .with_prompt("Password")
+            .interact()?;
+
+        // Prefer the system keyring; fall back to the settings file
+        let stored_password = match keyring::Entry::new(KEYRING_SERVICE, &url)
+            .and_then(|e| e.set_password(&password))
+        {
+            Ok(()) => None,
+            Err(err) => {
+                println!(
+                    "{}",
+                    style(format!(
+                        "⚠️  System keyring unavailable ({}); storing the password in the config file",
+                        err
+                    ))
+                    .yellow()
+                );
+                Some(password)
+            }
+        };
+
+        let entry = RegistryEntry {
+            url: url.clone(),
+            username,
+            password: stored_password,
+        };
+        match existing {
+            Some(index) => registries[index] = entry,
+            None => registries.push(entry),
+        }
+        self.api_client
+            .set_setting(REGISTRIES_SETTING, &registries)?;
+        // The first registry logged in to becomes the default
+        if self.default_registry().is_none() {
+            self.api_client
+                .set_setting(DEFAULT_REGISTRY_SETTING, &url)?;
+        }
+
+        println!(
+            "{}",
+            style(format!("✅ Credentials saved for {}", url)).green()
+        );
+        Ok(())
+    }
+
+    pub async fn registry_logout(&mut self, url: &str) -> Result<()> {
+        let url = normalize_registry_url(url);
+        let mut registries = self.registries();
+        let index = registries
+            .iter()
+            .position(|r| r.url == url)
+            .ok_or_else(|| anyhow!("Not logged in to {}", url))?;
+        registries.remove(index);
+
+        // The password may have been stored in the settings file instead
+        if let Ok(entry) = keyring::Entry::new(KEYRING_SERVICE, &url) {
+            let _ = entry.delete_credential();
+        }
+        self.api_client
+            .set_setting(REGISTRIES_SETTING, &registries)?;
+        if self.default_registry().as_deref() == Some(url.as_str()) {
+            self.api_client.remove_setting(DEFAULT_REGISTRY_SETTING);
+        }
+
+        println!(
+            "{}",
+            style(format!("✅ Removed credentials for {}", url)).green()
+        );
+        Ok(())
+    }
+
+    pub async fn registry_set_default(&mut self, url: &str) -> Result<()> {
+        let url = normalize_registry_url(url);
+        if !self.registries().iter().any(|r| r.url == url) {
+            return Err(anyhow!(
+                "Unknown registry {}. Run 'omni registry login {}' first.",
+                url,
+                url
+            ));
+        }
+
+        self.api_client
+            .set_setting(DEFAULT_REGISTRY_SETTING, &url)?;
+        println!(
+            "{}",
+            style(format!("✅ {} is now the default registry", url)).green()
+        );
+        Ok(())
+    }
+
+    pub async fn registry_list(&self) -> Result<()> {
+        let registries = self.registries();
+        let default_registry = self.default_registry();
+        // Credentials themselves are never printed, only where they live
+        if self.output.is_structured() {
+            let rows: Vec<serde_json::Value> = registries
+                .iter()
+                .map(|r| {
+                    serde_json::json!({
+                        "url": r.url,
+                        "username": r.username,
+                        "storage": if r.password.is_some() { "config file" } else { "keyring" },
+                        "default": default_registry.as_deref() == Some(r.url.as_str()),
+                    })
+                })
+                .collect();
+            println!("{}", self.output.render(&rows)?.trim_end());
+            return Ok(());
+        }
+        if registries.is_empty() {
+            println!(
+                "{}",
+                style("No registries configured. Run 'omni registry login <url>' to add one.")
+                    .yellow()
+            );
+            return Ok(());
+        }
+
+        let display: Vec<RegistryDisplay> = registries
+            .iter()
+            .map(|r| RegistryDisplay {
+                url: r.url.clone(),
+                username: r.username.clone(),
+                storage: if r.password.is_some() {
+                    "config file"
+                } else {
+                    "keyring"
+                }
+                .to_string(),
+                is_default: if default_registry.as_deref() == Some(r.url.as_str()) {
+                    "✓"
+                } else {
+                    ""
+                }
+                .to_string(),
+            })
+            .collect();
+
+        println!("\n{}", style("📦 Container Registries").cyan().bold());
+        println!("{}", self.render_table(Table::new(display)));
+        Ok(())
+    }
+}
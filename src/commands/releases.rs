@@ -0,0 +1,158 @@
+use crate::commands::init_env::{ApiResponse, StatusData};
+use crate::models::Release;
+use crate::ui::PremiumUI;
+use anyhow::{anyhow, Context, Result};
+use console::style;
+use serde::Serialize;
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+// One field that differs between two releases; `None` means the field is
+// absent on that side
+#[derive(Debug, Serialize)]
+struct FieldChange {
+    path: String,
+    before: Option<Value>,
+    after: Option<Value>,
+}
+
+// Flatten a JSON document into dotted paths. Arrays whose items all carry a
+// `name` (components, env entries, ...) are keyed by it so reordering them
+// doesn't show up as a change.
+fn flatten_fields(prefix: &str, value: &Value, fields: &mut BTreeMap<String, Value>) {
+    let join = |key: &str| {
+        if prefix.is_empty() {
+            key.to_string()
+        } else {
+            format!("{}.{}", prefix, key)
+        }
+    };
+
+    match value {
+        Value::Object(map) if !map.is_empty() => {
+            for (key, child) in map {
+                flatten_fields(&join(key), child, fields);
+            }
+        }
+        Value::Array(items) if !items.is_empty() => {
+            let names: Option<Vec<&str>> = items
+                .iter()
+                .map(|item| item.get("name").and_then(Value::as_str))
+                .collect();
+            for (index, item) in items.iter().enumerate() {
+                let key = match &names {
+                    Some(names) => names[index].to_string(),
+                    None => index.to_string(),
+                };
+                flatten_fields(&join(&key), item, fields);
+            }
+        }
+        _ => {
+            fields.insert(prefix.to_string(), value.clone());
+        }
+    }
+}
+
+fn diff_releases(before: &Value, after: &Value) -> Vec<FieldChange> {
+    let mut before_fields = BTreeMap::new();
+    let mut after_fields = BTreeMap::new();
+    flatten_fields("", before, &mut before_fields);
+    flatten_fields("", after, &mut after_fields);
+
+    let mut paths: Vec<&String> = before_fields.keys().chain(after_fields.keys()).collect();
+    paths.sort();
+    paths.dedup();
+
+    paths
+        .into_iter()
+        .filter(|path| before_fields.get(*path) != after_fields.get(*path))
+        .map(|path| FieldChange {
+            path: path.clone(),
+            before: before_fields.get(path).cloned(),
+            after: after_fields.get(path).cloned(),
+        })
+        .collect()
+}
+
+impl PremiumUI {
+    // All releases of an app, in whatever order the server returns them
+    pub async fn list_releases(&self, app: &str) -> Result<Vec<Release>> {
+        let response = self
+            .api_client
+            .get::<ApiResponse>(&format!("/apps/{}/releases", app))
+            .await
+            .with_context(|| format!("Failed to list releases of {}", app))?;
+        match self.parse_status_data::<Vec<Release>>(response.data) {
+            StatusData::Available(releases) => Ok(releases),
+            StatusData::Missing => Ok(Vec::new()),
+            StatusData::Malformed => Err(anyhow!("Unexpected server response for release data")),
+        }
+    }
+
+    async fn fetch_release(&self, app: &str, release: &str) -> Result<Value> {
+        let response = self
+            .api_client
+            .get::<ApiResponse>(&format!("/apps/{}/releases/{}", app, release))
+            .await
+            .with_context(|| format!("Failed to fetch release {} of {}", release, app))?;
+        match self.parse_status_data::<Value>(response.data) {
+            StatusData::Available(release) => Ok(release),
+            StatusData::Missing => Err(anyhow!("Release {} of {} not found", release, app)),
+            StatusData::Malformed => Err(anyhow!("Unexpected server response for release data")),
+        }
+    }
+
+    // Show the field-level differences between two releases of an app
+    pub async fn releases_diff(
+        &self,
+        app: &str,
+        release_a: &str,
+        release_b: &str,
+        json: bool,
+    ) -> Result<()> {
+        let before = self.fetch_release(app, release_a).await?;
+        let after = self.fetch_release(app, release_b).await?;
+        let changes = diff_releases(&before, &after);
+
+        if json {
+            println!("{}", serde_json::to_string_pretty(&changes)?);
+            return Ok(());
+        }
+
+        println!(
+            "\n{}",
+            style(format!("🔍 {}: {} → {}", app, release_a, release_b))
+                .cyan()
+                .bold()
+        );
+        if changes.is_empty() {
+            println!("{}", style("No differences.").green());
+            return Ok(());
+        }
+
+        for change in &changes {
+            match (&change.before, &change.after) {
+                (None, Some(after)) => {
+                    println!("{}", style(format!("+ {}: {}", change.path, after)).green())
+                }
+                (Some(before), None) => {
+                    println!("{}", style(format!("- {}: {}", change.path, before)).red())
+                }
+                (Some(before), Some(after)) => println!(
+                    "{} {}: {} → {}",
+                    style("~").yellow().bold(),
+                    change.path,
+                    style(before).red(),
+                    style(after).green()
+                ),
+                (None, None) => {}
+            }
+        }
+        println!(
+            "\n{}",
+            style(format!("{} field(s) changed", changes.len())).dim()
+        );
+
+        Ok(())
+    }
+}
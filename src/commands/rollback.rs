@@ -1,33 +1,95 @@
+use crate::commands::init_env::{ApiResponse, StatusData};
+use crate::commands::wait::WaitKind;
+use crate::models::Release;
 use crate::ui::PremiumUI;
-use anyhow::Result;
+use anyhow::{anyhow, Context, Result};
 use console::style;
 use dialoguer::{Confirm, Select};
-use std::{thread, time::Duration};
+use std::time::{Duration, Instant};
+
+// Releases offered by the rollback picker
+const MAX_LISTED_RELEASES: usize = 10;
+const ROLLBACK_TIMEOUT: Duration = Duration::from_secs(5 * 60);
+
+// How a release is named to users: its version, or its id without one
+fn release_label(release: &Release) -> &str {
+    release.version.as_deref().unwrap_or(&release.id)
+}
 
 impl PremiumUI {
-    pub async fn rollback_interactive(&self) -> Result<()> {
-        let versions = vec![
-            "v1.2.3 (Current)",
-            "v1.2.2 (2 days ago)",
-            "v1.2.1 (5 days ago)",
-            "v1.2.0 (1 week ago)",
-        ];
-
-        let version = Select::with_theme(&self.theme)
-            .with_prompt("Select version to rollback to")
-            .items(&versions)
-            .default(0)
-            .interact()?;
+    // Roll an app back to an earlier release: `version` if given, otherwise
+    // one picked from its recent history
+    pub async fn rollback_interactive(
+        &self,
+        app: Option<&str>,
+        version: Option<&str>,
+    ) -> Result<()> {
+        let app = self.select_app(app, "Select app to roll back").await?;
+
+        let mut releases = self.list_releases(&app).await?;
+        if releases.is_empty() {
+            return Err(anyhow!("{} has no releases to roll back to", app));
+        }
+        releases.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+        let current = self
+            .fetch_app_state(&app)
+            .await
+            .and_then(|state| state.release);
+
+        let target = match version {
+            Some(version) => releases
+                .iter()
+                .find(|r| r.id == version || r.version.as_deref() == Some(version))
+                .ok_or_else(|| {
+                    let available: Vec<&str> = releases.iter().map(release_label).collect();
+                    anyhow!(
+                        "{} has no release '{}'. Available versions: {}",
+                        app,
+                        version,
+                        available.join(", ")
+                    )
+                })?,
+            None => {
+                let recent = &releases[..releases.len().min(MAX_LISTED_RELEASES)];
+                let items: Vec<String> = recent
+                    .iter()
+                    .map(|release| {
+                        format!(
+                            "{} ({}, {}){}",
+                            release_label(release),
+                            release.created_at.as_deref().unwrap_or("unknown time"),
+                            if release.status.is_empty() {
+                                "unknown status"
+                            } else {
+                                &release.status
+                            },
+                            if current.as_ref() == Some(&release.id) {
+                                " — current"
+                            } else {
+                                ""
+                            }
+                        )
+                    })
+                    .collect();
+                let index = Select::with_theme(&self.theme)
+                    .with_prompt("Select version to rollback to")
+                    .items(&items)
+                    .default(0)
+                    .interact()?;
+                &recent[index]
+            }
+        };
 
-        if version == 0 {
+        if current.as_ref() == Some(&target.id) {
             println!("{}", style("Cannot rollback to current version.").yellow());
             return Ok(());
         }
 
         let confirm = Confirm::with_theme(&self.theme)
-            .with_prompt(&format!(
-                "⚠️  Are you sure you want to rollback to {}?",
-                versions[version]
+            .with_prompt(format!(
+                "⚠️  Are you sure you want to rollback {} to {}?",
+                app,
+                release_label(target)
             ))
             .default(false)
             .interact()?;
@@ -39,26 +101,84 @@ impl PremiumUI {
 
         println!("\n{}", style("🔄 Initiating rollback...").cyan().bold());
 
-        let pb = self.create_progress_bar(100, "Preparing rollback");
-        for i in 0..100 {
-            pb.inc(1);
-            thread::sleep(Duration::from_millis(50));
-
-            match i {
-                20 => pb.set_message("Stopping current version..."),
-                40 => pb.set_message("Loading previous version..."),
-                60 => pb.set_message("Updating configuration..."),
-                80 => pb.set_message("Starting services..."),
-                _ => {}
-            }
-        }
-        pb.finish_with_message("✓ Rollback completed successfully!");
+        let response = self
+            .api_client
+            .post::<_, ApiResponse>(
+                &format!("/apps/{}/releases/{}/rollback", app, target.id),
+                &(),
+            )
+            .await
+            .with_context(|| format!("Failed to roll {} back to {}", app, release_label(target)))?;
+        println!("{}", style("Rollback request accepted ✓").green());
+        println!(
+            "{}",
+            style(format!("API response: {}", response.message)).green()
+        );
 
-        println!("\n{}", style("Current System Version").cyan().bold());
-        println!("Version:    {}", style(versions[version]).green());
-        println!("Deployed:   {}", style("Just now").green());
-        println!("Status:     {}", style("Healthy").green());
+        self.wait_for_rollback(&app, target).await?;
 
+        println!("\n{}", style("Current System Version").cyan().bold());
+        println!("App:        {}", style(&app).green());
+        println!("Version:    {}", style(release_label(target)).green());
         Ok(())
     }
+
+    // Poll the target release until the server reports it active. The
+    // platform status can't tell us this: it reads as complete both before
+    // the rollback starts and when there are no hosts at all.
+    async fn wait_for_rollback(&self, app: &str, target: &Release) -> Result<()> {
+        let mut spinner = self.create_spinner("Rolling back...");
+        let started = Instant::now();
+        let mut last_status: Option<Release> = None;
+
+        let deadline = self.wait_deadline_or(WaitKind::Rollback, ROLLBACK_TIMEOUT);
+        while Instant::now() < deadline {
+            match self
+                .api_client
+                .get::<ApiResponse>(&format!("/apps/{}/releases/{}", app, target.id))
+                .await
+            {
+                Ok(response) => match self.parse_status_data::<Release>(response.data) {
+                    StatusData::Available(release) => {
+                        if release.status.eq_ignore_ascii_case("active") {
+                            spinner.stop_with_message(
+                                "✓ Rollback completed successfully!".to_string(),
+                            );
+                            return Ok(());
+                        }
+                        if release.status.eq_ignore_ascii_case("failed") {
+                            spinner.stop_with_message(
+                                "✗ Server reported the rollback failed".to_string(),
+                            );
+                            return Err(anyhow!(
+                                "Rollback of {} to {} failed: {}",
+                                app,
+                                release_label(target),
+                                response.message
+                            ));
+                        }
+                        last_status = Some(release);
+                    }
+                    StatusData::Missing => {}
+                    StatusData::Malformed => {
+                        spinner.stop();
+                        return Err(anyhow!("Unexpected server response for release data"));
+                    }
+                },
+                Err(err) => {
+                    if self.debug {
+                        println!(
+                            "{}",
+                            style(format!("Error polling rollback status: {}", err)).dim()
+                        );
+                    }
+                }
+            }
+
+            tokio::time::sleep(Duration::from_secs(1)).await;
+        }
+
+        spinner.stop_with_message("Rollback timed out locally".to_string());
+        Err(self.wait_timed_out("the rollback", started.elapsed(), last_status.as_ref()))
+    }
 }
@@ -1,44 +1,201 @@
-use crate::models::ComponentStatus;
-use crate::ui::PremiumUI;
-use anyhow::Result;
+use crate::commands::init_env::{ApiResponse, StatusData};
+use crate::models::{AppScale, AutoscalingPolicy, ComponentStatus};
+use crate::ui::{OutputMode, PremiumUI};
+use anyhow::{anyhow, Context, Result};
 use console::style;
 use dialoguer::{Input, Select};
-use std::{thread, time::Duration};
-use tabled::Table;
+use serde_json::json;
+use tabled::{Table, Tabled};
+
+// Replica range accepted by `scale --replicas`
+pub const MIN_REPLICAS: u32 = 1;
+pub const MAX_REPLICAS: u32 = 10;
+
+#[derive(Tabled)]
+struct ScaleDisplay {
+    #[tabled(rename = "Component")]
+    name: String,
+    #[tabled(rename = "Desired")]
+    desired: u32,
+    #[tabled(rename = "Ready")]
+    ready: u32,
+    #[tabled(rename = "Autoscaling")]
+    autoscaling: String,
+}
+
+// One-line summary of an autoscaling policy, e.g. "2-8 replicas, CPU 70%"
+fn describe_autoscaling(policy: Option<&AutoscalingPolicy>) -> String {
+    let Some(policy) = policy else {
+        return "off".to_string();
+    };
+    let mut parts = vec![format!(
+        "{}-{} replicas",
+        policy.min_replicas, policy.max_replicas
+    )];
+    if let Some(cpu) = policy.target_cpu_percent {
+        parts.push(format!("CPU {}%", cpu));
+    }
+    if let Some(memory) = policy.target_memory_percent {
+        parts.push(format!("memory {}%", memory));
+    }
+    parts.join(", ")
+}
 
 impl PremiumUI {
-    pub async fn scale_interactive(&self) -> Result<()> {
-        let components = vec!["Web Frontend", "API Backend", "Database"];
-        let component = Select::with_theme(&self.theme)
-            .with_prompt("Select component to scale")
-            .items(&components)
-            .interact()?;
-
-        let replicas: u32 = Input::with_theme(&self.theme)
-            .with_prompt("Enter number of replicas")
-            .validate_with(|input: &String| -> Result<(), &str> {
-                match input.parse::<u32>() {
-                    Ok(n) if n > 0 && n <= 10 => Ok(()),
-                    _ => Err("Please enter a number between 1 and 10"),
-                }
+    // Show current replica counts and autoscaling policy without changing them
+    pub async fn scale_show(&self, app: &str, json: bool) -> Result<()> {
+        let mut spinner = self.create_spinner("Fetching scaling state...");
+        let response = self
+            .api_client
+            .get::<ApiResponse>(&format!("/apps/{}/scale", app))
+            .await;
+        spinner.stop();
+        let response =
+            response.with_context(|| format!("Failed to fetch scaling state of {}", app))?;
+        let scale = match self.parse_status_data::<AppScale>(response.data) {
+            StatusData::Available(scale) => scale,
+            StatusData::Missing => AppScale::default(),
+            StatusData::Malformed => {
+                return Err(anyhow!("Unexpected server response for scaling data"));
+            }
+        };
+
+        // `--json` predates the global --output and is kept as its shorthand
+        let output = if json { OutputMode::Json } else { self.output };
+        if output.is_structured() {
+            println!("{}", output.render(&scale)?.trim_end());
+            return Ok(());
+        }
+
+        println!(
+            "\n{}",
+            style(format!("📊 Scaling of {}", app)).cyan().bold()
+        );
+        if scale.components.is_empty() {
+            println!("{}", style("No components found.").yellow());
+            return Ok(());
+        }
+
+        let rows: Vec<ScaleDisplay> = scale
+            .components
+            .iter()
+            .map(|component| ScaleDisplay {
+                name: component.name.clone(),
+                desired: component.desired_replicas,
+                ready: component.ready_replicas,
+                autoscaling: describe_autoscaling(component.autoscaling.as_ref()),
             })
-            .interact_text()?
-            .parse()?;
+            .collect();
+        println!("{}", self.render_table(Table::new(rows)));
+
+        Ok(())
+    }
+
+    // Set a component's replica count, prompting for whatever wasn't given
+    // on the command line. `replicas` is range-checked by clap.
+    pub async fn scale_interactive(
+        &self,
+        app: Option<&str>,
+        component: Option<&str>,
+        replicas: Option<u32>,
+    ) -> Result<()> {
+        let app = self.select_app(app, "Select app to scale").await?;
+        let components: Vec<String> = self
+            .fetch_app_state(&app)
+            .await
+            .map(|state| state.components.into_iter().map(|c| c.name).collect())
+            .unwrap_or_default();
+
+        let component = match component {
+            // The server has the final say when the app's state couldn't be read
+            Some(component)
+                if components.is_empty() || components.iter().any(|c| c == component) =>
+            {
+                component.to_string()
+            }
+            Some(component) => {
+                return Err(anyhow!(
+                    "{} has no component '{}'. Components: {}",
+                    app,
+                    component,
+                    components.join(", ")
+                ));
+            }
+            None if components.is_empty() => {
+                return Err(anyhow!(
+                    "Couldn't list the components of {}; pass --component",
+                    app
+                ));
+            }
+            None => {
+                let index = Select::with_theme(&self.theme)
+                    .with_prompt("Select component to scale")
+                    .items(&components)
+                    .default(0)
+                    .interact()?;
+                components[index].clone()
+            }
+        };
+
+        let replicas: u32 = match replicas {
+            Some(replicas) => replicas,
+            None => Input::with_theme(&self.theme)
+                .with_prompt("Enter number of replicas")
+                .validate_with(|input: &String| -> Result<(), String> {
+                    match input.parse::<u32>() {
+                        Ok(n) if (MIN_REPLICAS..=MAX_REPLICAS).contains(&n) => Ok(()),
+                        _ => Err(format!(
+                            "Please enter a number between {} and {}",
+                            MIN_REPLICAS, MAX_REPLICAS
+                        )),
+                    }
+                })
+                .interact_text()?
+                .parse()?,
+        };
 
         let mut spinner = self.create_spinner("Scaling component...");
-        thread::sleep(Duration::from_secs(2));
-        spinner.stop_with_message("✓ Scaling completed successfully!".to_string());
+        let response = self
+            .api_client
+            .put::<_, ApiResponse>(
+                &format!("/apps/{}/components/{}/scale", app, component),
+                &json!({ "replicas": replicas }),
+            )
+            .await;
+        match response {
+            Ok(_) => spinner.stop_with_message("✓ Scaling completed successfully!".to_string()),
+            Err(err) => {
+                spinner.stop_with_message("✗ Scaling failed".to_string());
+                return Err(err)
+                    .with_context(|| format!("Failed to scale {} of {}", component, app));
+            }
+        }
 
         println!("\n{}", style("📊 Updated Component Status").cyan().bold());
-        let status = Table::new(vec![ComponentStatus {
-            name: components[component].into(),
-            status: "Running".into(),
-            replicas: format!("{}/{}", replicas, replicas),
-            cpu: format!("{}m", replicas * 150),
-            memory: format!("{}Mi", replicas * 256),
-        }])
-        .to_string();
-        println!("{}", status);
+        let updated: Vec<ComponentStatus> = self
+            .fetch_app_state(&app)
+            .await
+            .map(|state| {
+                state
+                    .components
+                    .iter()
+                    .filter(|c| c.name == component)
+                    .map(ComponentStatus::from)
+                    .collect()
+            })
+            .unwrap_or_default();
+        if updated.is_empty() {
+            println!(
+                "{}",
+                style(format!(
+                    "Requested {} replica(s) of {}; run 'omni scale show {}' to follow progress.",
+                    replicas, component, app
+                ))
+                .dim()
+            );
+        } else {
+            println!("{}", self.render_table(Table::new(updated)));
+        }
 
         Ok(())
     }
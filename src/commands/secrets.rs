@@ -0,0 +1,323 @@
+use crate::commands::init_env::SshHost;
+use crate::ui::PremiumUI;
+use aes_gcm::aead::rand_core::RngCore;
+use aes_gcm::aead::{Aead, AeadCore, KeyInit, OsRng};
+use aes_gcm::{Aes256Gcm, Key, Nonce};
+use anyhow::{anyhow, Context, Result};
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use console::{style, Term};
+use dialoguer::theme::ColorfulTheme;
+use dialoguer::Password;
+use sha2::Sha256;
+use std::collections::HashMap;
+
+// Keyring service name under which config secrets are stored
+const KEYRING_SERVICE: &str = "omni-secrets";
+// Settings key listing stored secret names; the keyring can't enumerate them
+const SECRET_NAMES_SETTING: &str = "secrets";
+// Prefix marking a config reference as a secret: `${secret:NAME}`
+pub const SECRET_REF_PREFIX: &str = "secret:";
+
+// Encrypted SSH passwords in the cloud config look like
+// `enc:v1:<key source>:<base64>`
+const ENCRYPTED_PREFIX: &str = "enc:v1:";
+// Passphrase for encrypting SSH passwords, for when there's no keyring
+const PASSPHRASE_ENV: &str = "OMNI_CONFIG_PASSPHRASE";
+// Keyring entry holding the random key SSH passwords are encrypted with
+const ENCRYPTION_KEY_USER: &str = "cloud-config-key";
+const PBKDF2_ROUNDS: u32 = 600_000;
+const SALT_LEN: usize = 16;
+const NONCE_LEN: usize = 12;
+
+// Value of a stored secret, if there is one
+pub fn lookup_secret(name: &str) -> Option<String> {
+    keyring::Entry::new(KEYRING_SERVICE, name)
+        .and_then(|entry| entry.get_password())
+        .ok()
+}
+
+// Secret names are used inside `${secret:NAME}` references
+fn validate_secret_name(name: &str) -> Result<()> {
+    if name.is_empty()
+        || !name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.'))
+    {
+        return Err(anyhow!(
+            "Invalid secret name '{}': use letters, digits, '_', '-' and '.'",
+            name
+        ));
+    }
+    Ok(())
+}
+
+// Encrypts SSH passwords for the cloud config and decrypts them again. The
+// key comes from $OMNI_CONFIG_PASSPHRASE, else a random key kept in the
+// system keyring, else a passphrase prompted for once per run.
+struct PasswordCipher<'a> {
+    theme: &'a ColorfulTheme,
+    passphrase: Option<String>,
+    salt: Option<[u8; SALT_LEN]>,
+    derived: HashMap<Vec<u8>, Key<Aes256Gcm>>,
+}
+
+impl<'a> PasswordCipher<'a> {
+    fn new(theme: &'a ColorfulTheme) -> Self {
+        Self {
+            theme,
+            passphrase: std::env::var(PASSPHRASE_ENV).ok().filter(|p| !p.is_empty()),
+            salt: None,
+            derived: HashMap::new(),
+        }
+    }
+
+    fn passphrase(&mut self, confirm: bool) -> Result<String> {
+        if let Some(passphrase) = &self.passphrase {
+            return Ok(passphrase.clone());
+        }
+        if !Term::stderr().is_term() {
+            return Err(anyhow!(
+                "SSH passwords in the cloud config need a key: set {} or make the \
+                 system keyring available",
+                PASSPHRASE_ENV
+            ));
+        }
+        let mut prompt = Password::with_theme(self.theme)
+            .with_prompt("Passphrase for SSH passwords in the cloud config");
+        if confirm {
+            prompt = prompt.with_confirmation("Repeat passphrase", "Passphrases don't match");
+        }
+        let passphrase = prompt.interact()?;
+        self.passphrase = Some(passphrase.clone());
+        Ok(passphrase)
+    }
+
+    fn passphrase_key(&mut self, salt: &[u8], confirm: bool) -> Result<Key<Aes256Gcm>> {
+        if let Some(key) = self.derived.get(salt) {
+            return Ok(*key);
+        }
+        let passphrase = self.passphrase(confirm)?;
+        let mut key = Key::<Aes256Gcm>::default();
+        pbkdf2::pbkdf2_hmac::<Sha256>(passphrase.as_bytes(), salt, PBKDF2_ROUNDS, &mut key);
+        self.derived.insert(salt.to_vec(), key);
+        Ok(key)
+    }
+
+    fn keyring_key(create: bool) -> Option<Key<Aes256Gcm>> {
+        let entry = keyring::Entry::new(KEYRING_SERVICE, ENCRYPTION_KEY_USER).ok()?;
+        if let Ok(encoded) = entry.get_password() {
+            let bytes = BASE64.decode(encoded).ok()?;
+            return (bytes.len() == 32).then(|| *Key::<Aes256Gcm>::from_slice(&bytes));
+        }
+        if !create {
+            return None;
+        }
+        let key = Aes256Gcm::generate_key(OsRng);
+        entry.set_password(&BASE64.encode(key)).ok()?;
+        Some(key)
+    }
+
+    fn encrypt(&mut self, password: &str) -> Result<String> {
+        let keyring_key = match self.passphrase {
+            Some(_) => None,
+            None => Self::keyring_key(true),
+        };
+        let nonce = Aes256Gcm::generate_nonce(OsRng);
+        let (source, mut payload, key) = match keyring_key {
+            Some(key) => ("keyring", Vec::new(), key),
+            None => {
+                let salt = *self.salt.get_or_insert_with(|| {
+                    let mut salt = [0u8; SALT_LEN];
+                    OsRng.fill_bytes(&mut salt);
+                    salt
+                });
+                (
+                    "passphrase",
+                    salt.to_vec(),
+                    self.passphrase_key(&salt, true)?,
+                )
+            }
+        };
+        let ciphertext = Aes256Gcm::new(&key)
+            .encrypt(&nonce, password.as_bytes())
+            .map_err(|_| anyhow!("Failed to encrypt SSH password"))?;
+        payload.extend_from_slice(&nonce);
+        payload.extend_from_slice(&ciphertext);
+        Ok(format!(
+            "{}{}:{}",
+            ENCRYPTED_PREFIX,
+            source,
+            BASE64.encode(payload)
+        ))
+    }
+
+    fn decrypt(&mut self, value: &str) -> Result<String> {
+        let Some(encrypted) = value.strip_prefix(ENCRYPTED_PREFIX) else {
+            return Ok(value.to_string());
+        };
+        let (source, encoded) = encrypted
+            .split_once(':')
+            .context("Malformed encrypted SSH password")?;
+        let payload = BASE64
+            .decode(encoded)
+            .context("Malformed encrypted SSH password")?;
+        let (key, rest) = match source {
+            "keyring" => (
+                Self::keyring_key(false).context(
+                    "The key SSH passwords were encrypted with isn't in this machine's keyring. \
+                     Re-enter them with 'omni config edit-host'.",
+                )?,
+                &payload[..],
+            ),
+            "passphrase" if payload.len() > SALT_LEN => {
+                let (salt, rest) = payload.split_at(SALT_LEN);
+                (self.passphrase_key(salt, false)?, rest)
+            }
+            _ => return Err(anyhow!("Malformed encrypted SSH password")),
+        };
+        if rest.len() <= NONCE_LEN {
+            return Err(anyhow!("Malformed encrypted SSH password"));
+        }
+        let (nonce, ciphertext) = rest.split_at(NONCE_LEN);
+        let plaintext = Aes256Gcm::new(&key)
+            .decrypt(Nonce::from_slice(nonce), ciphertext)
+            .map_err(|_| anyhow!("Couldn't decrypt SSH passwords: wrong passphrase or key"))?;
+        String::from_utf8(plaintext).context("Decrypted SSH password isn't valid UTF-8")
+    }
+}
+
+impl PremiumUI {
+    // Encrypt plaintext SSH passwords before the hosts are written to disk.
+    // Already encrypted passwords and ${...} references are left alone.
+    pub fn encrypt_host_passwords(&self, hosts: &mut [SshHost]) -> Result<()> {
+        let mut cipher = PasswordCipher::new(&self.theme);
+        for host in hosts {
+            if let Some(password) = &host.password {
+                if password.is_empty()
+                    || password.starts_with(ENCRYPTED_PREFIX)
+                    || password.contains("${")
+                {
+                    continue;
+                }
+                host.password = Some(cipher.encrypt(password)?);
+            }
+        }
+        Ok(())
+    }
+
+    // Copies of `hosts` with their SSH passwords decrypted, for sending to
+    // the platform
+    pub fn decrypt_host_passwords(&self, hosts: &[SshHost]) -> Result<Vec<SshHost>> {
+        let mut cipher = PasswordCipher::new(&self.theme);
+        hosts
+            .iter()
+            .map(|host| {
+                let mut host = host.clone();
+                if let Some(password) = &host.password {
+                    host.password = Some(
+                        cipher
+                            .decrypt(password)
+                            .with_context(|| format!("Failed to decrypt the SSH password of host {}", host.name))?,
+                    );
+                }
+                Ok(host)
+            })
+            .collect()
+    }
+
+    fn secret_names(&self) -> Vec<String> {
+        self.api_client
+            .get_setting_or(SECRET_NAMES_SETTING, Vec::new())
+    }
+
+    // Store a secret in the system keyring for configs to reference. The
+    // value is prompted for when not given, keeping it out of shell history.
+    pub async fn config_set_secret(&mut self, name: &str, value: Option<&str>) -> Result<()> {
+        validate_secret_name(name)?;
+        let value = match value {
+            Some(value) => value.to_string(),
+            None => Password::with_theme(&self.theme)
+                .with_prompt(format!("Value for {}", name))
+                .interact()?,
+        };
+
+        keyring::Entry::new(KEYRING_SERVICE, name)
+            .and_then(|entry| entry.set_password(&value))
+            .map_err(|err| {
+                anyhow!(
+                    "Couldn't store the secret in the system keyring ({}). \
+                     Use a ${{VAR}} environment reference instead.",
+                    err
+                )
+            })?;
+
+        let mut names = self.secret_names();
+        if !names.iter().any(|known| known == name) {
+            names.push(name.to_string());
+            names.sort();
+            self.api_client.set_setting(SECRET_NAMES_SETTING, &names)?;
+        }
+
+        println!(
+            "{}",
+            style(format!(
+                "✅ Secret '{}' stored. Reference it in the config as \"${{{}{}}}\".",
+                name, SECRET_REF_PREFIX, name
+            ))
+            .green()
+        );
+        Ok(())
+    }
+
+    pub async fn config_unset_secret(&mut self, name: &str) -> Result<()> {
+        let mut names = self.secret_names();
+        let known = names.iter().position(|known| known == name);
+        let deleted = keyring::Entry::new(KEYRING_SERVICE, name)
+            .and_then(|entry| entry.delete_credential())
+            .is_ok();
+        if known.is_none() && !deleted {
+            return Err(anyhow!("No secret named '{}'", name));
+        }
+        if let Some(index) = known {
+            names.remove(index);
+            self.api_client.set_setting(SECRET_NAMES_SETTING, &names)?;
+        }
+
+        println!("{}", style(format!("✅ Secret '{}' removed", name)).green());
+        Ok(())
+    }
+
+    // Names of stored secrets; values are never printed
+    pub async fn config_list_secrets(&self) -> Result<()> {
+        let names = self.secret_names();
+        if self.output.is_structured() {
+            let rows: Vec<serde_json::Value> = names
+                .iter()
+                .map(|name| {
+                    serde_json::json!({ "name": name, "stored": lookup_secret(name).is_some() })
+                })
+                .collect();
+            println!("{}", self.output.render(&rows)?.trim_end());
+            return Ok(());
+        }
+        if names.is_empty() {
+            println!(
+                "{}",
+                style("No secrets stored. Add one with 'omni config set-secret <name>'.").yellow()
+            );
+            return Ok(());
+        }
+
+        println!("\n{}", style("🔑 Stored Secrets").cyan().bold());
+        for name in &names {
+            let status = if lookup_secret(name).is_some() {
+                style("stored".to_string()).green()
+            } else {
+                style("missing from keyring".to_string()).red()
+            };
+            println!("  {} ({})", name, status);
+        }
+        Ok(())
+    }
+}
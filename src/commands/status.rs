@@ -1,51 +1,123 @@
-use crate::models::ComponentStatus;
+use crate::commands::init_env::{ApiResponse, HostDeploymentStatus, StatusData};
 use crate::ui::PremiumUI;
-use anyhow::Result;
-use console::style;
-use std::{thread, time::Duration};
-use tabled::Table;
+use anyhow::{Context, Result};
+use console::{measure_text_width, style, Term};
+use std::time::Duration;
 
 impl PremiumUI {
     pub async fn status_interactive(&self) -> Result<()> {
-        let mut spinner = self.create_spinner("Fetching application status...");
-        thread::sleep(Duration::from_secs(1));
-
-        let status = vec![
-            ComponentStatus {
-                name: "Web Frontend".into(),
-                status: "Healthy".into(),
-                replicas: "3/3".into(),
-                cpu: "65%".into(),
-                memory: "78%".into(),
-            },
-            ComponentStatus {
-                name: "API Backend".into(),
-                status: "Healthy".into(),
-                replicas: "2/2".into(),
-                cpu: "45%".into(),
-                memory: "52%".into(),
-            },
-            ComponentStatus {
-                name: "Database".into(),
-                status: "Healthy".into(),
-                replicas: "1/1".into(),
-                cpu: "35%".into(),
-                memory: "60%".into(),
-            },
-        ];
+        if self.output.is_structured() {
+            return self.print_platform_status().await;
+        }
+        if !self.cloud_config_path().exists() {
+            anyhow::bail!("No cloud configuration found. Run 'omni init' first.");
+        }
+        let config = self.load_cloud_config()?;
 
+        let mut spinner = self.create_spinner("Fetching platform status...");
+        let response = self
+            .api_client
+            .get::<ApiResponse>(&format!("/platforms/{}/status", config.cloud_name))
+            .await;
         spinner.stop();
+        let response = response.context("Failed to get status from API")?;
 
-        println!("\n{}", style("📊 System Status").cyan().bold());
-        println!("{}", Table::new(status).to_string());
+        println!("\n{}", style("📊 Platform Status").cyan().bold());
+        match self.parse_status_data::<Vec<HostDeploymentStatus>>(response.data) {
+            StatusData::Available(host_statuses) => {
+                print!("{}", self.render_service_status(&host_statuses, &config));
+            }
+            StatusData::Missing => println!(
+                "{}",
+                style("Status unavailable: the server returned no host data.").yellow()
+            ),
+            StatusData::Malformed => anyhow::bail!(
+                "Unexpected server response: host status data could not be read."
+            ),
+        }
 
-        println!("\n{}", style("🔍 System Metrics").cyan().bold());
-        println!("Uptime:        {}", style("15d 7h 23m").green());
-        println!("Response Time: {}", style("145ms").green());
-        println!("Error Rate:    {}", style("0.02%").green());
-        println!("CPU Usage:     {}", style("48.3%").green());
-        println!("Memory Usage:  {}", style("63.5%").green());
+        Ok(())
+    }
+
+    // `status --watch`: re-fetch the platform status every `interval` and
+    // redraw the host and service tables in place until Ctrl-C
+    pub async fn status_watch(&self, interval: Duration) -> Result<()> {
+        let config = self.load_cloud_config()?;
+        let endpoint = format!("/platforms/{}/status", config.cloud_name);
+        let term = Term::stdout();
+        if term.is_term() {
+            let _ = term.hide_cursor();
+        }
+
+        // One Ctrl-C listener for the whole loop, so a signal that arrives
+        // mid-request isn't lost
+        let ctrl_c = tokio::signal::ctrl_c();
+        tokio::pin!(ctrl_c);
+
+        let mut prev_lines = 0;
+        loop {
+            let response = tokio::select! {
+                response = self.api_client.get::<ApiResponse>(&endpoint) => response,
+                _ = &mut ctrl_c => break,
+            };
+            let body = match response {
+                Ok(response) => {
+                    match self.parse_status_data::<Vec<HostDeploymentStatus>>(response.data) {
+                        StatusData::Available(host_statuses) => {
+                            self.render_service_status(&host_statuses, &config)
+                        }
+                        StatusData::Missing => format!(
+                            "{}\n",
+                            style("Status unavailable: the server returned no host data.").yellow()
+                        ),
+                        StatusData::Malformed => {
+                            format!(
+                                "{}\n",
+                                style(
+                                    "Unexpected server response: host status data is unreadable."
+                                )
+                                .red()
+                            )
+                        }
+                    }
+                }
+                Err(err) => format!(
+                    "{}\n",
+                    style(format!("Failed to get status from API: {}", err)).red()
+                ),
+            };
+            let frame = format!(
+                "{} {}\n{}",
+                style("📊 Platform Status").cyan().bold(),
+                style(format!(
+                    "{} · refreshing every {}s · Ctrl-C to exit",
+                    chrono::Local::now().format("%H:%M:%S"),
+                    interval.as_secs()
+                ))
+                .dim(),
+                body
+            );
+
+            // Clear the previous frame, counting lines the terminal wrapped
+            if prev_lines > 0 {
+                print!("\x1B[{}A\x1B[J", prev_lines);
+            }
+            print!("{}", frame);
+            let width = (term.size().1 as usize).max(1);
+            prev_lines = frame
+                .lines()
+                .map(|line| measure_text_width(line).max(1).div_ceil(width))
+                .sum();
+
+            tokio::select! {
+                _ = tokio::time::sleep(interval) => {}
+                _ = &mut ctrl_c => break,
+            }
+        }
 
+        if term.is_term() {
+            let _ = term.show_cursor();
+        }
         Ok(())
     }
 }
@@ -1,168 +1,1060 @@
-use crate::models::ComponentStatus;
-use crate::ui::PremiumUI;
+use crate::commands::backup::file_sha256;
+use crate::commands::deploy_log::{
+    local_identity, source_revision, DeployLock, DeploymentRecord, DEPLOY_METADATA_DIR,
+};
+use crate::api_client::{retry_delay, ApiError};
+use crate::commands::init_env::{ApiResponse, StatusData};
+use crate::models::{AppState, ComponentStatus, ReleaseStatus};
+use crate::ui::{expand_home, PremiumUI};
 use anyhow::anyhow;
 use anyhow::{Context, Result};
-use console::style;
-use dialoguer::{Confirm, Input, Select};
+use clap::ArgMatches;
+use console::{style, StyledObject};
+use dialoguer::{Confirm, Input, MultiSelect, Select};
 use flate2::write::GzEncoder;
 use flate2::Compression;
 use ignore::WalkBuilder;
+use indicatif::HumanBytes;
 use pathdiff;
+use futures::{StreamExt, TryStreamExt};
+use reqwest::header::CONTENT_TYPE;
 use reqwest::multipart::{Form, Part};
+use reqwest::{Body, StatusCode};
 use serde::Deserialize;
 use serde::Serialize;
+use std::collections::BTreeMap;
 use std::path::PathBuf;
+use std::io;
 use std::{fs::File, path::Path};
-use std::{thread, time::Duration};
+use std::time::Duration;
 use tabled::Table;
 use tar::Builder;
 use tempfile::env::temp_dir;
+use std::io::SeekFrom;
+use tokio::io::AsyncSeekExt;
 use tokio::{fs, task};
+use tokio_util::io::ReaderStream;
+
+// An upload that was started but didn't finish, kept in the settings so
+// `up --resume` can continue it
+#[derive(Debug, Serialize, Deserialize)]
+struct PendingUpload {
+    release: String,
+    tarball: String,
+    sha256: String,
+    algo: CompressionAlgo,
+}
+
+// Setting that holds the pending upload of a project to an environment
+fn pending_upload_key(project: &str, environment: &str) -> String {
+    format!("deploy.pending_upload.{}.{}", project, environment)
+}
+
+// Default for the `deploy.build_timeout_seconds` setting
+const DEFAULT_BUILD_TIMEOUT_SECONDS: u64 = 30 * 60;
+// Files read at once while building the tarball
+const TARBALL_READ_CONCURRENCY: usize = 16;
+// Seconds between polls of a release's status while it builds
+const RELEASE_POLL_INTERVAL_SECONDS: u64 = 2;
+// Rollout phases reported by /apps/{app}/releases/{release}/status, in order
+const RELEASE_PHASES: &[(&str, &str)] = &[
+    ("build", "Building containers"),
+    ("push", "Pushing to registry"),
+    ("configure", "Configuring services"),
+    ("start", "Starting components"),
+];
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct DeployPermissions {
     max_file_count: u64,
+    // Largest request body the server (or its proxy) accepts, if advertised
+    #[serde(default)]
+    max_upload_bytes: Option<u64>,
+    // Tarball compression algorithms the server can unpack; gzip if absent
+    #[serde(default)]
+    supported_compression: Vec<String>,
+}
+
+// Compression applied to the deploy tarball
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CompressionAlgo {
+    Gzip,
+    Zstd,
+}
+
+impl CompressionAlgo {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            CompressionAlgo::Gzip => "gzip",
+            CompressionAlgo::Zstd => "zstd",
+        }
+    }
+
+    fn extension(self) -> &'static str {
+        match self {
+            CompressionAlgo::Gzip => "tar.gz",
+            CompressionAlgo::Zstd => "tar.zst",
+        }
+    }
+
+    fn mime_type(self) -> &'static str {
+        match self {
+            CompressionAlgo::Gzip => "application/gzip",
+            CompressionAlgo::Zstd => "application/zstd",
+        }
+    }
+}
+
+// The compressed stream the tar archive is written into
+enum TarballEncoder {
+    Gzip(GzEncoder<File>),
+    Zstd(zstd::Encoder<'static, File>),
+}
+
+impl TarballEncoder {
+    // zstd levels run to 22, but the gzip scale (0-9) is reused for both; 0
+    // means zstd's own default
+    fn new(file: File, algo: CompressionAlgo, compression: Compression) -> io::Result<Self> {
+        Ok(match algo {
+            CompressionAlgo::Gzip => TarballEncoder::Gzip(GzEncoder::new(file, compression)),
+            CompressionAlgo::Zstd => {
+                TarballEncoder::Zstd(zstd::Encoder::new(file, compression.level() as i32)?)
+            }
+        })
+    }
+
+    fn finish(self) -> io::Result<()> {
+        match self {
+            TarballEncoder::Gzip(encoder) => encoder.finish().map(|_| ()),
+            TarballEncoder::Zstd(encoder) => encoder.finish().map(|_| ()),
+        }
+    }
+}
+
+impl io::Write for TarballEncoder {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        match self {
+            TarballEncoder::Gzip(encoder) => encoder.write(buf),
+            TarballEncoder::Zstd(encoder) => encoder.write(buf),
+        }
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        match self {
+            TarballEncoder::Gzip(encoder) => encoder.flush(),
+            TarballEncoder::Zstd(encoder) => encoder.flush(),
+        }
+    }
+}
+
+// Turn a rejected upload into an actionable error. The server's size limit
+// is read from a JSON body's `max_bytes` or `limit` field when present.
+fn upload_failure(status: StatusCode, body: &str, tarball_bytes: u64) -> anyhow::Error {
+    let message = serde_json::from_str::<serde_json::Value>(body).ok();
+    let field = |name: &str| {
+        message.as_ref().and_then(|message| {
+            message
+                .get("data")
+                .and_then(|data| data.get(name))
+                .or_else(|| message.get(name))
+                .cloned()
+        })
+    };
+
+    match status {
+        StatusCode::PAYLOAD_TOO_LARGE => {
+            let limit = field("max_bytes")
+                .or_else(|| field("limit"))
+                .map(|limit| match limit.as_u64() {
+                    Some(bytes) => HumanBytes(bytes).to_string(),
+                    None => limit.as_str().map_or(limit.to_string(), String::from),
+                });
+            anyhow!(
+                "Deployment exceeds the server's size limit: the tarball is {}{}. \
+                 Add large directories to .omniignore to shrink it.",
+                HumanBytes(tarball_bytes),
+                limit.map_or(String::new(), |limit| format!(", the limit is {}", limit))
+            )
+        }
+        StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => anyhow!(
+            "The server rejected the upload's credentials ({}). \
+             Check that the api_key setting holds a valid key.",
+            status
+        ),
+        _ => {
+            let detail = field("message")
+                .and_then(|message| message.as_str().map(String::from))
+                .unwrap_or_else(|| body.trim().to_string());
+            if detail.is_empty() {
+                anyhow!("Upload failed: {}", status)
+            } else {
+                anyhow!("Upload failed: {} - {}", status, detail)
+            }
+        }
+    }
+}
+
+// Environment the deploy picker pre-selects, set by `config set-default-env`
+pub const DEFAULT_ENVIRONMENT_SETTING: &str = "deploy.default_environment";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeployEnvironment {
+    pub name: String,
+    // Protected environments require the user to type the environment name
+    #[serde(default)]
+    pub protected: bool,
+    // Environments that only need a yes/no confirmation
+    #[serde(default)]
+    requires_confirmation: bool,
+}
+
+impl DeployEnvironment {
+    fn new(name: &str, protected: bool) -> Self {
+        Self {
+            name: name.to_string(),
+            protected,
+            requires_confirmation: false,
+        }
+    }
+}
+
+// Match a requested environment by name (case-insensitive) or unique prefix,
+// so `--env prod` selects "Production"
+pub fn find_environment<'a>(
+    environments: &'a [DeployEnvironment],
+    requested: &str,
+) -> Result<&'a DeployEnvironment> {
+    let wanted = requested.to_lowercase();
+    if let Some(environment) = environments
+        .iter()
+        .find(|e| e.name.to_lowercase() == wanted)
+    {
+        return Ok(environment);
+    }
+
+    let candidates: Vec<&DeployEnvironment> = environments
+        .iter()
+        .filter(|e| e.name.to_lowercase().starts_with(&wanted))
+        .collect();
+    match candidates.as_slice() {
+        [environment] => Ok(environment),
+        _ => {
+            let names: Vec<&str> = environments.iter().map(|e| e.name.as_str()).collect();
+            Err(anyhow!(
+                "Unknown environment '{}'. Available environments: {}",
+                requested,
+                names.join(", ")
+            ))
+        }
+    }
+}
+
+#[derive(Debug, Serialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+enum ChangeKind {
+    Added,
+    Removed,
+    Updated,
+    Unchanged,
+}
+
+#[derive(Debug, Serialize)]
+struct ComponentChange {
+    name: String,
+    change: ChangeKind,
+    previous_version: Option<String>,
+    new_version: Option<String>,
+    previous_replicas: Option<u32>,
+    new_replicas: Option<u32>,
+}
+
+// Change report shown at the end of `omni up`, comparing the app's state
+// before the upload with its state afterwards
+#[derive(Debug, Serialize)]
+struct DeploySummary {
+    app: String,
+    environment: String,
+    previous_release: Option<String>,
+    new_release: String,
+    components: Vec<ComponentChange>,
+    endpoints: BTreeMap<String, String>,
+    new_endpoints: Vec<String>,
+}
+
+impl DeploySummary {
+    fn new(
+        app: &str,
+        environment: &str,
+        release: &str,
+        before: &AppState,
+        after: &AppState,
+    ) -> Self {
+        let mut components = Vec::new();
+        for current in &after.components {
+            let previous = before.components.iter().find(|c| c.name == current.name);
+            let change = match previous {
+                None => ChangeKind::Added,
+                Some(previous)
+                    if previous.version != current.version
+                        || previous.replicas != current.replicas =>
+                {
+                    ChangeKind::Updated
+                }
+                Some(_) => ChangeKind::Unchanged,
+            };
+            components.push(ComponentChange {
+                name: current.name.clone(),
+                change,
+                previous_version: previous.and_then(|c| c.version.clone()),
+                new_version: current.version.clone(),
+                previous_replicas: previous.map(|c| c.replicas),
+                new_replicas: Some(current.replicas),
+            });
+        }
+        for previous in &before.components {
+            if !after.components.iter().any(|c| c.name == previous.name) {
+                components.push(ComponentChange {
+                    name: previous.name.clone(),
+                    change: ChangeKind::Removed,
+                    previous_version: previous.version.clone(),
+                    new_version: None,
+                    previous_replicas: Some(previous.replicas),
+                    new_replicas: None,
+                });
+            }
+        }
+
+        let new_endpoints = after
+            .endpoints
+            .iter()
+            .filter(|(name, url)| before.endpoints.get(*name) != Some(*url))
+            .map(|(name, _)| name.clone())
+            .collect();
+
+        Self {
+            app: app.to_string(),
+            environment: environment.to_string(),
+            previous_release: before.release.clone(),
+            new_release: after.release.clone().unwrap_or_else(|| release.to_string()),
+            components,
+            endpoints: after.endpoints.clone(),
+            new_endpoints,
+        }
+    }
+}
+
+// One-line health rollup of the deployed components, e.g.
+// "3 components, 6/6 replicas Running, 0 failed", colored by health
+fn components_rollup(state: &AppState) -> StyledObject<String> {
+    let replicas: u32 = state.components.iter().map(|c| c.replicas).sum();
+    let ready: u32 = state.components.iter().map(|c| c.ready_replicas).sum();
+    let failed = state.components.iter().filter(|c| c.is_failed()).count();
+
+    let line = style(format!(
+        "{} component{}, {}/{} replicas Running, {} failed",
+        state.components.len(),
+        if state.components.len() == 1 { "" } else { "s" },
+        ready,
+        replicas,
+        failed
+    ));
+    if failed > 0 {
+        line.red().bold()
+    } else if ready < replicas {
+        line.yellow().bold()
+    } else {
+        line.green().bold()
+    }
+}
+
+// Directories that are almost never meant to be deployed
+const HEAVY_DIRECTORIES: &[&str] = &[
+    "node_modules",
+    "target",
+    ".git",
+    "dist",
+    "build",
+    "vendor",
+    "__pycache__",
+    ".venv",
+    "venv",
+    ".next",
+];
+
+// Parse a gzip level for `up --compression`: 0-9, `fast`, `best` or `default`
+pub fn parse_compression_level(value: &str) -> std::result::Result<Compression, String> {
+    match value {
+        "fast" => Ok(Compression::fast()),
+        "best" => Ok(Compression::best()),
+        "default" => Ok(Compression::default()),
+        level => match level.parse::<u32>() {
+            Ok(level) if level <= 9 => Ok(Compression::new(level)),
+            _ => Err(format!(
+                "invalid compression level '{}' (expected 0-9, fast or best)",
+                value
+            )),
+        },
+    }
+}
+
+// Walk the files a deploy includes: gitignore rules apply, plus `.omniignore`
+fn project_walker(project_path: &Path) -> ignore::Walk {
+    WalkBuilder::new(project_path)
+        .hidden(false)
+        .git_ignore(true)
+        .git_global(true)
+        .git_exclude(true)
+        .add_custom_ignore_filename(".omniignore")
+        .filter_entry(|entry| entry.file_name() != DEPLOY_METADATA_DIR)
+        .build()
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+struct GroupStats {
+    files: u64,
+    bytes: u64,
+}
+
+// Files a deploy would include, grouped by their first path component
+#[derive(Debug, Default)]
+struct FileBreakdown {
+    total: u64,
+    total_bytes: u64,
+    by_top_level: BTreeMap<String, GroupStats>,
+}
+
+impl FileBreakdown {
+    fn scan(project_path: &Path) -> Self {
+        let mut breakdown = FileBreakdown::default();
+        for entry in project_walker(project_path).filter_map(|e| e.ok()) {
+            if !entry.file_type().is_some_and(|ft| ft.is_file()) {
+                continue;
+            }
+            let bytes = entry.metadata().map_or(0, |metadata| metadata.len());
+            breakdown.total += 1;
+            breakdown.total_bytes += bytes;
+
+            let relative = entry.path().strip_prefix(project_path).unwrap_or(entry.path());
+            let mut components = relative.components();
+            let group = match (components.next(), components.next()) {
+                (Some(first), Some(_)) => format!("{}/", first.as_os_str().to_string_lossy()),
+                _ => "(top-level files)".to_string(),
+            };
+            let stats = breakdown.by_top_level.entry(group).or_default();
+            stats.files += 1;
+            stats.bytes += bytes;
+        }
+        breakdown
+    }
+
+    // Groups with the most files first
+    fn largest(&self, limit: usize) -> Vec<(&str, GroupStats)> {
+        let mut groups: Vec<(&str, GroupStats)> = self
+            .by_top_level
+            .iter()
+            .map(|(group, stats)| (group.as_str(), *stats))
+            .collect();
+        groups.sort_by(|a, b| b.1.files.cmp(&a.1.files).then_with(|| a.0.cmp(b.0)));
+        groups.truncate(limit);
+        groups
+    }
+}
+
+// One line per group, aligned for printing
+fn file_group_lines(groups: &[(&str, GroupStats)]) -> Vec<String> {
+    let width = groups.iter().map(|(group, _)| group.len()).max().unwrap_or(0);
+    groups
+        .iter()
+        .map(|(group, stats)| {
+            format!(
+                "  {:<width$}  {:>8} files  {:>10}",
+                group,
+                stats.files,
+                HumanBytes(stats.bytes).to_string(),
+                width = width
+            )
+        })
+        .collect()
 }
 
 impl PremiumUI {
-    pub async fn deploy_interactive(&self) -> Result<()> {
-        // Get project path
-        let project_path: String = Input::with_theme(&self.theme)
-            .with_prompt("Enter project path")
-            .default(".".into())
-            .interact_text()?;
-        let project_path = PathBuf::from(project_path);
-        let project_path = project_path.canonicalize().context("Failed to canonicalize project path")?;
+    pub async fn deploy_interactive(&mut self, matches: &ArgMatches) -> Result<()> {
+        // Get project path, prompting only when --path wasn't given
+        let project_path: String = match matches.get_one::<String>("path") {
+            Some(path) => path.clone(),
+            None => Input::with_theme(&self.theme)
+                .with_prompt("Enter project path")
+                .default(".".into())
+                .interact_text()?,
+        };
+        let project_path = self.resolve_path(project_path);
+        let project_path = project_path
+            .canonicalize()
+            .context("Failed to canonicalize project path")?;
 
         // Validate project path
         if !Path::new(&project_path).exists() {
-            println!("{}", style("Error: Project path does not exist.").red());
+            self.notice(style("Error: Project path does not exist.").red());
             return Ok(());
         }
+        if !project_path.is_dir() {
+            return Err(anyhow!(
+                "Project path {} is not a directory",
+                project_path.display()
+            ));
+        }
+
+        // Held until this function returns
+        let _lock = DeployLock::acquire(&project_path, matches.get_flag("force-unlock"))?;
+
+        if matches.get_flag("dry-run") {
+            return self.deploy_dry_run(&project_path, matches).await;
+        }
 
         // Environment selection
-        let environments = vec!["Development", "Staging", "Production"];
-        let env_selection = Select::with_theme(&self.theme)
-            .with_prompt("Select deployment environment")
-            .items(&environments)
-            .default(0)
-            .interact()?;
+        let environments = self.deploy_environments().await;
+        let environment = match matches.get_one::<String>("environment") {
+            Some(requested) => find_environment(&environments, requested)?,
+            None => {
+                let environment_names: Vec<&str> =
+                    environments.iter().map(|e| e.name.as_str()).collect();
+                // A default that no longer matches an environment falls back to the first
+                let default_index = self
+                    .api_client
+                    .get_setting::<String>(DEFAULT_ENVIRONMENT_SETTING)
+                    .and_then(|default| find_environment(&environments, &default).ok())
+                    .and_then(|default| {
+                        environments.iter().position(|e| e.name == default.name)
+                    })
+                    .unwrap_or(0);
+                let env_selection = Select::with_theme(&self.theme)
+                    .with_prompt("Select deployment environment")
+                    .items(&environment_names)
+                    .default(default_index)
+                    .interact()?;
+                &environments[env_selection]
+            }
+        };
 
-        // Production confirmation
-        if environments[env_selection] == "Production" {
-            let confirm = Confirm::with_theme(&self.theme)
-                .with_prompt("⚠️  You're deploying to production. Are you sure?")
+        // Confirmation driven by the environment's metadata, unless --yes
+        let confirmed = if matches.get_flag("yes") {
+            true
+        } else if environment.protected {
+            self.confirm_typed(
+                &format!(
+                    "⚠️  You're deploying to {}, a protected environment.",
+                    environment.name
+                ),
+                &environment.name,
+            )?
+        } else if environment.requires_confirmation {
+            Confirm::with_theme(&self.theme)
+                .with_prompt(format!(
+                    "⚠️  You're deploying to {}. Are you sure?",
+                    environment.name
+                ))
                 .default(false)
-                .interact()?;
-            if !confirm {
-                println!("{}", style("Deployment cancelled.").yellow());
-                return Ok(());
-            }
+                .interact()?
+        } else {
+            true
+        };
+        if !confirmed {
+            self.progress(style("Deployment cancelled.").yellow());
+            return Ok(());
         }
 
-        println!("\n{}", style("🚀 Initializing deployment...").cyan().bold());
-        // Create tarball
-        println!("{}", style("🗜️  Creating tarball...").cyan().bold());
-        let tarball_path = self
-            .create_tarball(&project_path.to_string_lossy())
-            .await
-            .context("Failed to create tarball")?;
-        println!("{}", style("🗜️  uploading").cyan().bold());
-        let path = Path::new(&project_path);
-        if !path.is_dir() {
-            print!("{}", style("Error: Not a directory").red());
-            return Err(anyhow!("Invalid project path"));
-        }
-        let project_path = Path::new(&project_path)
-            .canonicalize()
-            .expect("Failed to canonicalize path");
         let project_name: String = project_path
             .file_name()
             .and_then(|s| s.to_str())
             .map(String::from)
-            .expect("Unable to determine folder name"); // Upload tarball
-        self.upload_tarball(
-            &tarball_path,
-            environments[env_selection],
-            project_name.as_str(),
-        )
-        .await
-        .context("Failed to upload tarball")?;
+            .context("Unable to determine folder name")?;
 
-        // Clean up tarball
-        fs::remove_file(&tarball_path)
-            .await
-            .context("Failed to clean up tarball")?;
-
-        let steps = [
-            ("Analyzing project", 20),
-            ("Building containers", 40),
-            ("Pushing to registry", 30),
-            ("Configuring services", 25),
-            ("Starting components", 35),
-        ];
-
-        for (step, duration) in steps.iter() {
-            let pb = self.create_progress_bar(*duration, step);
-            for i in 0..*duration {
-                pb.inc(1);
-                thread::sleep(Duration::from_millis(100));
-
-                match i {
-                    5 => pb.set_message(format!("{} (scanning dependencies)", step)),
-                    15 => pb.set_message(format!("{} (optimizing)", step)),
-                    25 => pb.set_message(format!("{} (finalizing)", step)),
-                    _ => {}
+        // Snapshot the app before uploading so the summary can show what changed
+        let previous_state = self.fetch_app_state(&project_name).await;
+
+        // An upload that doesn't finish is recorded so `--resume` can pick it
+        // up under the same release id
+        let pending_key = pending_upload_key(&project_name, &environment.name);
+        let pending = if matches.get_flag("resume") {
+            let pending = self.api_client.get_setting::<PendingUpload>(&pending_key);
+            if pending.is_none() {
+                self.progress(
+                    style("No interrupted upload to resume, starting a new deployment.").yellow()
+                );
+            }
+            pending
+        } else {
+            None
+        };
+        // The tarball left behind is only reused if it hasn't changed since
+        let reusable = pending.as_ref().filter(|pending| {
+            file_sha256(Path::new(&pending.tarball)).is_ok_and(|sha| sha == pending.sha256)
+        });
+        if let (Some(pending), None) = (&pending, reusable) {
+            self.progress(
+                style(format!(
+                    "The tarball of release {} is missing or changed, starting a new upload.",
+                    pending.release
+                ))
+                .yellow()
+            );
+        }
+
+        self.progress(format!("\n{}", style("🚀 Initializing deployment...").cyan().bold()));
+        let (tarball_path, algo, max_upload_bytes) = match reusable {
+            Some(pending) => {
+                self.progress(
+                    style(format!("📦 Resuming the upload of release {}", pending.release))
+                        .cyan()
+                        .bold()
+                );
+                (pending.tarball.clone(), pending.algo, None)
+            }
+            None => {
+                self.progress(style("🗜️  Creating tarball...").cyan().bold());
+                let (compression, algo) = self.tarball_compression(matches)?;
+                match self
+                    .create_tarball(&project_path.to_string_lossy(), compression, algo)
+                    .await
+                    .context("Failed to create tarball")?
+                {
+                    Some(tarball) => tarball,
+                    None => return Ok(()),
                 }
             }
-            pb.finish_with_message(format!("{} ✓", step));
+        };
+        let keep_tarball = matches.get_flag("keep-tarball");
+
+        // Fail fast on a tarball the server would reject partway through
+        let tarball_bytes = fs::metadata(&tarball_path).await.map_or(0, |m| m.len());
+        if let Some((limit, source)) = self.upload_limit(max_upload_bytes) {
+            if tarball_bytes > limit {
+                self.discard_tarball(&tarball_path, keep_tarball).await?;
+                return Err(anyhow!(
+                    "Deployment exceeds {} of {}: the tarball is {}. \
+                     Add large directories to .omniignore to shrink it.",
+                    source,
+                    HumanBytes(limit),
+                    HumanBytes(tarball_bytes)
+                ));
+            }
         }
 
-        let status_table = Table::new(vec![
-            ComponentStatus {
-                name: "Web Frontend".into(),
-                status: "Running".into(),
-                replicas: "3/3".into(),
-                cpu: "150m".into(),
-                memory: "256Mi".into(),
+        // A rebuilt tarball starts over from offset 0, so it gets a fresh
+        // release id rather than the half-uploaded one
+        let release = reusable
+            .map(|pending| pending.release.clone())
+            .unwrap_or_else(|| format!("u-{}", uuid::Uuid::new_v4()));
+        let tarball_sha256 = file_sha256(Path::new(&tarball_path))?;
+        self.api_client.set_setting(
+            &pending_key,
+            PendingUpload {
+                release: release.clone(),
+                tarball: tarball_path.clone(),
+                sha256: tarball_sha256.clone(),
+                algo,
             },
-            ComponentStatus {
-                name: "API Backend".into(),
-                status: "Running".into(),
-                replicas: "2/2".into(),
-                cpu: "200m".into(),
-                memory: "512Mi".into(),
+        )?;
+
+        let offset = match reusable {
+            Some(_) => {
+                self.upload_resume_offset(&project_name, &release, tarball_bytes)
+                    .await
+            }
+            None => 0,
+        };
+        let uploaded = if offset >= tarball_bytes {
+            self.progress(style("The server already has the whole upload ✓").green());
+            Ok(())
+        } else {
+            self.progress(style("🗜️  uploading").cyan().bold());
+            self.upload_tarball(
+                &tarball_path,
+                algo,
+                &environment.name,
+                &project_name,
+                &release,
+                offset,
+            )
+            .await
+            .context("Failed to upload tarball")
+        };
+
+        // A failed upload keeps its tarball so it can be resumed
+        if let Err(err) = uploaded {
+            eprintln!(
+                "{}",
+                style(format!(
+                    "Run 'omni up --env {} --resume' to continue the upload of release {}.",
+                    environment.name, release
+                ))
+                .yellow()
+            );
+            return Err(err);
+        }
+        self.api_client.remove_setting(&pending_key);
+        self.discard_tarball(&tarball_path, keep_tarball).await?;
+
+
+        // A build that hangs or is interrupted keeps running server-side
+        // unless it's cancelled, so offer to before bailing out
+        let build_timeout = self.build_timeout();
+        let mut confirmed_live = false;
+        let interrupted = tokio::select! {
+            result = tokio::time::timeout(
+                build_timeout,
+                self.wait_for_build(&project_name, &release),
+            ) => match result {
+                Ok(built) => {
+                    confirmed_live = built?;
+                    None
+                }
+                Err(_) => Some(format!(
+                    "Build timed out after {}s",
+                    build_timeout.as_secs()
+                )),
             },
-            ComponentStatus {
-                name: "Database".into(),
-                status: "Running".into(),
-                replicas: "1/1".into(),
-                cpu: "500m".into(),
-                memory: "1Gi".into(),
+            _ = tokio::signal::ctrl_c() => Some("Build interrupted".to_string()),
+        };
+        if let Some(reason) = interrupted {
+            self.progress(format!("\n{}", style(format!("⚠️  {}.", reason)).yellow()));
+            self.offer_build_cancel(&project_name, &release).await;
+            return Err(anyhow!("{} (release {})", reason, release));
+        }
+
+        let current_state = self.fetch_app_state(&project_name).await;
+        let summary = DeploySummary::new(
+            &project_name,
+            &environment.name,
+            &release,
+            &previous_state.unwrap_or_default(),
+            current_state.as_ref().unwrap_or(&AppState::default()),
+        );
+
+        let (source_commit, source_dirty) = source_revision(&project_path);
+        self.record_deployment(
+            &project_path,
+            &DeploymentRecord {
+                timestamp: chrono::Utc::now().to_rfc3339(),
+                app: project_name.clone(),
+                release: release.clone(),
+                environment: environment.name.clone(),
+                source_commit,
+                source_dirty,
+                tarball_sha256,
+                deployed_by: local_identity(&project_path),
             },
-        ])
-        .to_string();
-
-        println!("\n{}", style("📊 Deployment Status").cyan().bold());
-        println!("{}", status_table);
-        println!("\n{}", style("🌍 Application Endpoints").cyan().bold());
-        println!("Frontend: {}", style("https://app.example.com").green());
-        println!("API:      {}", style("https://api.example.com").green());
-        println!("Metrics:  {}", style("https://metrics.example.com").green());
+        );
+
+        if matches.get_flag("json") {
+            println!("{}", serde_json::to_string_pretty(&summary)?);
+            return Ok(());
+        }
+
+        self.print_deploy_summary(&summary);
+        match &current_state {
+            Some(state) if !state.components.is_empty() => {
+                let status_table = self
+                    .render_table(Table::new(state.components.iter().map(ComponentStatus::from)));
+                println!("\n{}", style("📊 Deployment Status").cyan().bold());
+                println!("{}", status_table);
+                println!("{}", components_rollup(state));
+            }
+            Some(_) => {}
+            None => println!(
+                "{}",
+                style("⚠️  Couldn't fetch the app's state after deploying.").yellow()
+            ),
+        }
+        if confirmed_live {
+            println!(
+                "\n{}",
+                style("✨ Deployment completed successfully!")
+                    .green()
+                    .bold()
+            );
+        } else {
+            println!(
+                "\n{}",
+                style("📦 Release uploaded; the server didn't confirm its rollout.")
+                    .yellow()
+                    .bold()
+            );
+        }
+        self.info("Run 'omni status' to monitor your deployment.");
+        Ok(())
+    }
+
+    // `up --dry-run`: build the tarball exactly as a deploy would, report
+    // what went into it, then clean up without uploading
+    async fn deploy_dry_run(&self, project_path: &Path, matches: &ArgMatches) -> Result<()> {
         println!(
             "\n{}",
-            style("✨ Deployment completed successfully!")
-                .green()
-                .bold()
+            style("🔍 Dry run: nothing will be uploaded").cyan().bold()
         );
+        let (compression, algo) = self.tarball_compression(matches)?;
+        let Some((tarball_path, algo, max_upload_bytes)) = self
+            .create_tarball(&project_path.to_string_lossy(), compression, algo)
+            .await
+            .context("Failed to create tarball")?
+        else {
+            return Ok(());
+        };
+        let tarball_bytes = fs::metadata(&tarball_path).await.map_or(0, |m| m.len());
+
+        let mut files = Vec::new();
+        for entry in project_walker(project_path).filter_map(|e| e.ok()) {
+            if !entry.file_type().is_some_and(|ft| ft.is_file()) {
+                continue;
+            }
+            let relative = entry.path().strip_prefix(project_path).unwrap_or(entry.path());
+            let bytes = entry.metadata().map_or(0, |metadata| metadata.len());
+            files.push((relative.display().to_string(), bytes));
+        }
+        files.sort();
+
+        println!("\n{}", style("📄 Files included").cyan().bold());
+        for (file, bytes) in &files {
+            println!("  {:>10}  {}", HumanBytes(*bytes).to_string(), file);
+        }
+        let total_bytes: u64 = files.iter().map(|(_, bytes)| bytes).sum();
         println!(
-            "{}",
-            style("Run 'omni status' to monitor your deployment.").dim()
+            "\n{} files ({}) packed into a {} {} tarball",
+            style(files.len()).green(),
+            HumanBytes(total_bytes),
+            style(HumanBytes(tarball_bytes)).green(),
+            algo.as_str()
         );
-        Ok(())
+        if let Some((limit, source)) = self.upload_limit(max_upload_bytes) {
+            if tarball_bytes > limit {
+                println!(
+                    "{}",
+                    style(format!(
+                        "⚠️  The tarball exceeds {} of {}; a deploy would be refused.",
+                        source,
+                        HumanBytes(limit)
+                    ))
+                    .yellow()
+                );
+            }
+        }
+
+        self.discard_tarball(&tarball_path, matches.get_flag("keep-tarball"))
+            .await
+    }
+
+    // Remove the tarball once it's no longer needed, or with --keep-tarball
+    // just say where it is
+    async fn discard_tarball(&self, tarball_path: &str, keep: bool) -> Result<()> {
+        if keep {
+            self.notice(style(format!("📦 Tarball kept at {}", tarball_path)).dim());
+            return Ok(());
+        }
+        fs::remove_file(tarball_path)
+            .await
+            .context("Failed to clean up tarball")
     }
 
-    async fn create_tarball(&self, project_path: &str) -> Result<String> {
+    // Bytes of `release` the server already holds from an interrupted
+    // upload. 0 means start over; the full size means it got everything.
+    async fn upload_resume_offset(&self, app: &str, release: &str, tarball_bytes: u64) -> u64 {
+        let endpoint = format!("/apps/{}/releases/{}/status", app, release);
+        let status = match self.api_client.get::<ApiResponse>(&endpoint).await {
+            Ok(response) => match self.parse_status_data::<ReleaseStatus>(response.data) {
+                StatusData::Available(status) => status,
+                StatusData::Missing | StatusData::Malformed => return 0,
+            },
+            Err(_) => return 0,
+        };
+        match (status.phase.as_str(), status.received_bytes) {
+            ("uploading", Some(received)) => received.min(tarball_bytes),
+            ("uploading" | "failed", _) => 0,
+            // Any later phase means the upload already completed
+            _ => tarball_bytes,
+        }
+    }
+
+    // Compression level and algorithm for the deploy tarball
+    fn tarball_compression(
+        &self,
+        matches: &ArgMatches,
+    ) -> Result<(Compression, CompressionAlgo)> {
+        // --compression wins over the `deploy.compression` setting
+        let compression_setting = self
+            .api_client
+            .get_setting::<serde_json::Value>("deploy.compression")
+            .map(|value| match value {
+                serde_json::Value::String(level) => level,
+                other => other.to_string(),
+            });
+        let compression = match matches
+            .get_one::<String>("compression")
+            .or(compression_setting.as_ref())
+        {
+            Some(level) => parse_compression_level(level).map_err(|err| anyhow!(err))?,
+            None => Compression::default(),
+        };
+        let algo = match matches
+            .get_one::<String>("compression-algo")
+            .cloned()
+            .or_else(|| self.api_client.get_setting::<String>("deploy.compression_algo"))
+            .as_deref()
+        {
+            Some("zstd") => CompressionAlgo::Zstd,
+            Some("gzip") | None => CompressionAlgo::Gzip,
+            Some(other) => return Err(anyhow!("Unknown compression algorithm '{}'", other)),
+        };
+        Ok((compression, algo))
+    }
+
+    // Fetch the app's deployed state; `None` if it doesn't exist yet or the
+    // server couldn't be reached
+    pub async fn fetch_app_state(&self, app: &str) -> Option<AppState> {
+        match self
+            .api_client
+            .get::<ApiResponse>(&format!("/apps/{}", app))
+            .await
+        {
+            Ok(response) => match self.parse_status_data::<AppState>(response.data) {
+                StatusData::Available(state) => Some(state),
+                StatusData::Missing | StatusData::Malformed => None,
+            },
+            Err(err) => {
+                if self.debug {
+                    self.notice(
+                        style(format!("Failed to fetch state for app {}: {}", app, err)).dim(),
+                    );
+                }
+                None
+            }
+        }
+    }
+
+    fn print_deploy_summary(&self, summary: &DeploySummary) {
+        println!("\n{}", style("📋 Deployment Summary").cyan().bold());
+        println!(
+            "Release:     {} → {}",
+            style(summary.previous_release.as_deref().unwrap_or("(none)")).dim(),
+            style(&summary.new_release).green()
+        );
+        println!("Environment: {}", style(&summary.environment).green());
+
+        println!("\n{}", style("Components").bold());
+        let mut unchanged = 0;
+        for component in &summary.components {
+            let version = |v: &Option<String>| v.clone().unwrap_or_else(|| "-".to_string());
+            match component.change {
+                ChangeKind::Added => println!(
+                    "  {} {} {}",
+                    style("+").green(),
+                    component.name,
+                    style(format!(
+                        "({}, {} replicas)",
+                        version(&component.new_version),
+                        component.new_replicas.unwrap_or_default()
+                    ))
+                    .dim()
+                ),
+                ChangeKind::Removed => {
+                    println!("  {} {}", style("-").red(), component.name)
+                }
+                ChangeKind::Updated => {
+                    let mut changes = Vec::new();
+                    if component.previous_version != component.new_version {
+                        changes.push(format!(
+                            "version {} → {}",
+                            version(&component.previous_version),
+                            version(&component.new_version)
+                        ));
+                    }
+                    if component.previous_replicas != component.new_replicas {
+                        changes.push(format!(
+                            "replicas {} → {}",
+                            component.previous_replicas.unwrap_or_default(),
+                            component.new_replicas.unwrap_or_default()
+                        ));
+                    }
+                    println!(
+                        "  {} {}: {}",
+                        style("~").yellow(),
+                        component.name,
+                        changes.join(", ")
+                    );
+                }
+                ChangeKind::Unchanged => unchanged += 1,
+            }
+        }
+        if summary.components.is_empty() {
+            println!("  {}", style("No components reported").dim());
+        } else if unchanged > 0 {
+            println!(
+                "  {}",
+                style(format!("{} component(s) unchanged", unchanged)).dim()
+            );
+        }
+
+        if !summary.endpoints.is_empty() {
+            println!("\n{}", style("🌍 Application Endpoints").cyan().bold());
+            for (name, url) in &summary.endpoints {
+                let marker = if summary.new_endpoints.contains(name) {
+                    style(" (new)").yellow().to_string()
+                } else {
+                    String::new()
+                };
+                println!("{}: {}{}", name, style(url).green(), marker);
+            }
+        }
+    }
+
+    // Resolve the environments offered by the deploy picker: a local
+    // `deploy.environments` setting wins, then the server's list, then the
+    // built-in defaults
+    pub async fn deploy_environments(&self) -> Vec<DeployEnvironment> {
+        if let Some(environments) = self
+            .api_client
+            .get_setting::<Vec<DeployEnvironment>>("deploy.environments")
+        {
+            if !environments.is_empty() {
+                return environments;
+            }
+        }
+
+        match self
+            .api_client
+            .get_cached::<Vec<DeployEnvironment>>("/environments")
+            .await
+        {
+            Ok(environments) if !environments.is_empty() => environments,
+            _ => vec![
+                DeployEnvironment::new("Development", false),
+                DeployEnvironment::new("Staging", false),
+                DeployEnvironment::new("Production", true),
+            ],
+        }
+    }
+
+    // Where tarballs are written: the `deploy.tarball_dir` setting, or the
+    // system temp directory. Created if it doesn't exist yet.
+    fn tarball_dir(&self) -> Result<PathBuf> {
+        let Some(dir) = self.api_client.get_setting::<String>("deploy.tarball_dir") else {
+            return Ok(temp_dir());
+        };
+        let dir = self.resolve_path(expand_home(Path::new(&dir))?);
+        std::fs::create_dir_all(&dir)
+            .with_context(|| format!("Failed to create tarball directory {}", dir.display()))?;
+        Ok(dir)
+    }
+
+    // Pack the project into a tarball. `None` means the user cancelled; the
+    // caller returns normally so the deploy lock and audit entry unwind.
+    async fn create_tarball(
+        &self,
+        project_path: &str,
+        compression: Compression,
+        algo: CompressionAlgo,
+    ) -> Result<Option<(String, CompressionAlgo, Option<u64>)>> {
+        let started = std::time::Instant::now();
         // Canonicalize the project path first
         let project_path = fs::canonicalize(project_path)
             .await
@@ -181,27 +1073,21 @@ impl PremiumUI {
             })
             .to_string();
 
-        // Create tarball filename in temp directory
-        let temp_dir = temp_dir();
-        let tar_gz_path = temp_dir.join(format!("{}.tar.gz", project_name));
-
-        // Create a file for the tarball
-        let tar_gz = File::create(&tar_gz_path)?;
-        let enc = GzEncoder::new(tar_gz, Compression::default());
-        let builder = std::sync::Arc::new(std::sync::Mutex::new(Builder::new(enc)));
-
         // Count total files first
-        let mut total_files = 0;
-        let walker = WalkBuilder::new(&project_path)
-            .hidden(false)
-            .git_ignore(true)
-            .git_global(true)
-            .git_exclude(true)
-            .build();
-
-        for entry in walker.filter_map(|e| e.ok()) {
-            if entry.file_type().map_or(false, |ft| ft.is_file()) {
-                total_files += 1;
+        let mut breakdown = FileBreakdown::scan(&project_path);
+        if self.debug || self.verbosity >= 1 {
+            self.progress(format!(
+                "\n{}",
+                style(format!(
+                    "📁 Deploying {} files ({})",
+                    breakdown.total,
+                    HumanBytes(breakdown.total_bytes)
+                ))
+                .cyan()
+                .bold()
+            ));
+            for line in file_group_lines(&breakdown.largest(usize::MAX)) {
+                self.progress(line);
             }
         }
         
@@ -209,20 +1095,42 @@ impl PremiumUI {
         let permissions_url = self.api_client.base_url.clone() + "/deploy/permissions";
         let max_file_count = self.api_client.get::<DeployPermissions>("/deploy/permissions").await;
         
-        match max_file_count {
+        let (algo, max_upload_bytes) = match max_file_count {
             Ok(permissions) => {
-                if total_files > permissions.max_file_count {
+                // Let the user trim the project with .omniignore and re-check
+                while breakdown.total > permissions.max_file_count {
                     let too_many_files: i64 =
-                        total_files as i64 - permissions.max_file_count as i64;
-                    println!("{}",style(format!("The server had denied your deployment request. Your project contains {} too many files. ({}/{})",too_many_files,total_files,permissions.max_file_count)).red());
-                    std::process::exit(0);
+                        breakdown.total as i64 - permissions.max_file_count as i64;
+                    self.notice(style(format!("The server had denied your deployment request. Your project contains {} too many files. ({}/{})",too_many_files,breakdown.total,permissions.max_file_count)).red());
+                    if !self.offer_omniignore_fix(&project_path, &breakdown)? {
+                        self.notice(style("Canceling upload operation").bold().blue());
+                        return Ok(None);
+                    }
+                    breakdown = FileBreakdown::scan(&project_path);
                 }
+                
+                // Only use an algorithm other than gzip if the server can unpack it
+                let supported = permissions
+                    .supported_compression
+                    .iter()
+                    .any(|supported| supported == algo.as_str());
+                let algo = if algo == CompressionAlgo::Gzip || supported {
+                    algo
+                } else {
+                    self.progress(
+                        style(format!(
+                            "The server doesn't support {} tarballs, falling back to gzip",
+                            algo.as_str()
+                        ))
+                        .yellow()
+                    );
+                    CompressionAlgo::Gzip
+                };
+                (algo, permissions.max_upload_bytes)
             },
-            Err(e) => {
-                eprintln!("{}", style(format!("Deployment failed: {e}",)).red().bold());
-                std::process::exit(0);
-            }
-        }
+            Err(e) => return Err(e.context("Deployment failed")),
+        };
+        let total_files = breakdown.total;
         
         if total_files > 5000 {
             let path_str = format!("{}", project_path.display());
@@ -243,147 +1151,431 @@ Are you sure you would like to deploy it? This make take significant amounts of
                 .show_default(true)
                 .interact()?;
             if !confirm {
-                println!("{}", style("Canceling upload operation").bold().blue());
-                std::process::exit(0)
+                self.notice(style("Canceling upload operation").bold().blue());
+                return Ok(None);
             }
         }
 
+        // Create tarball filename in the tarball directory
+        let tarball_dir = self.tarball_dir()?;
+        let tar_gz_path = tarball_dir.join(format!("{}.{}", project_name, algo.extension()));
+
+        // Create a file for the tarball
+        let tar_gz = File::create(&tar_gz_path)?;
+        let enc = TarballEncoder::new(tar_gz, algo, compression)?;
+        let builder = std::sync::Arc::new(std::sync::Mutex::new(Builder::new(enc)));
+
         let pb = self.create_progress_bar(total_files, "Creating tarball");
         pb.set_message("Initializing tarball creation");
 
-        // Process files
-        let mut files_processed = 0;
-        let walker = WalkBuilder::new(&project_path)
-            .hidden(false)
-            .git_ignore(true)
-            .git_global(true)
-            .git_exclude(true)
-            .build();
-
-        for entry in walker.filter_map(|e| e.ok()) {
-            if let Some(file_type) = entry.file_type() {
-                let entry_path = entry.path().to_path_buf();
-
-                // Convert the entry path to a relative path using path difference
-                let relative_path = pathdiff::diff_paths(&entry_path, &project_path)
-                    .ok_or_else(|| anyhow::anyhow!("Failed to compute relative path"))?;
-
-                // Skip root directory
-                if relative_path.as_os_str().is_empty() {
-                    continue;
-                }
+        // The walk is collected first so files can be read ahead concurrently
+        // while entries still go into the archive in walk order, keeping the
+        // tarball the same from run to run
+        let mut entries = Vec::new();
+        for entry in project_walker(&project_path).filter_map(|e| e.ok()) {
+            let Some(file_type) = entry.file_type() else {
+                continue;
+            };
+            let entry_path = entry.path().to_path_buf();
 
-                if file_type.is_dir() {
-                    pb.set_message(format!("Adding directory: {}", relative_path.display()));
+            // Convert the entry path to a relative path using path difference
+            let relative_path = pathdiff::diff_paths(&entry_path, &project_path)
+                .ok_or_else(|| anyhow::anyhow!("Failed to compute relative path"))?;
 
-                    let builder = std::sync::Arc::clone(&builder);
-                    let relative_path = relative_path.clone();
+            // Skip root directory
+            if relative_path.as_os_str().is_empty() {
+                continue;
+            }
 
-                    task::spawn_blocking(move || -> Result<()> {
-                        let mut builder = builder.lock().unwrap();
-                        let mut header = tar::Header::new_ustar();
+            if file_type.is_dir() {
+                entries.push((relative_path, None));
+            } else if file_type.is_file() {
+                entries.push((relative_path, Some(entry_path)));
+            }
+        }
+
+        // Directories have nothing to read, so they carry no contents
+        let mut reads = futures::stream::iter(entries)
+            .map(|(relative_path, entry_path)| {
+                task::spawn(async move {
+                    let contents = match entry_path {
+                        Some(entry_path) => Some(
+                            fs::read(&entry_path)
+                                .await
+                                .with_context(|| format!("Failed to read file: {:?}", entry_path))?,
+                        ),
+                        None => None,
+                    };
+                    Ok::<_, anyhow::Error>((relative_path, contents))
+                })
+            })
+            .buffered(TARBALL_READ_CONCURRENCY);
+        while let Some(read) = reads.next().await {
+            let (relative_path, contents) = read??;
+            let builder = std::sync::Arc::clone(&builder);
+            let pb = pb.clone();
+
+            task::spawn_blocking(move || -> Result<()> {
+                let mut builder = builder.lock().unwrap();
+                let mut header = tar::Header::new_ustar();
+                match contents {
+                    None => {
+                        pb.set_message(format!("Adding directory: {}", relative_path.display()));
                         header.set_entry_type(tar::EntryType::Directory);
                         header.set_mode(0o755);
                         header.set_size(0);
-                        builder.append_data(&mut header, relative_path, &[][..])?;
-                        Ok(())
-                    })
-                    .await??;
-                } else if file_type.is_file() {
-                    let file_contents = fs::read(&entry_path)
-                        .await
-                        .with_context(|| format!("Failed to read file: {:?}", entry_path))?;
-
-                    let builder = std::sync::Arc::clone(&builder);
-                    let relative_path_clone = relative_path.clone();
-
-                    task::spawn_blocking(move || -> Result<()> {
-                        let mut builder = builder.lock().unwrap();
-                        let mut header = tar::Header::new_ustar();
+                        builder.append_data(&mut header, &relative_path, &[][..])?;
+                    }
+                    Some(file_contents) => {
                         header.set_size(file_contents.len() as u64);
                         header.set_mode(0o644);
-                        builder.append_data(
-                            &mut header,
-                            relative_path_clone,
-                            &file_contents[..],
-                        )?;
-                        Ok(())
-                    })
-                    .await??;
-
-                    files_processed += 1;
-                    pb.set_position(files_processed);
-                    pb.set_message(format!("Adding file: {}", relative_path.display()));
+                        builder.append_data(&mut header, &relative_path, &file_contents[..])?;
+                        pb.inc(1);
+                        pb.set_message(format!("Adding file: {}", relative_path.display()));
+                    }
                 }
-
-                tokio::time::sleep(Duration::from_millis(1)).await;
-            }
+                Ok(())
+            })
+            .await??;
         }
 
         // Finalize the tarball
         pb.set_message("Finalizing tarball");
 
         task::spawn_blocking(move || -> Result<()> {
-            let mut builder = builder.lock().unwrap();
-            builder.finish()?;
+            let builder = std::sync::Arc::try_unwrap(builder)
+                .map_err(|_| anyhow!("Tarball builder is still in use"))?
+                .into_inner()
+                .unwrap();
+            builder.into_inner()?.finish()?;
             Ok(())
         })
         .await??;
 
         pb.finish_with_message("Tarball created successfully ✓");
 
-        Ok(tar_gz_path.to_string_lossy().into_owned())
+        let tarball_size = fs::metadata(&tar_gz_path).await.map_or(0, |m| m.len());
+        self.info(format!(
+            "Tarball is {} ({} level {}), built in {:.1}s",
+            HumanBytes(tarball_size),
+            algo.as_str(),
+            compression.level(),
+            started.elapsed().as_secs_f64()
+        ));
+
+        Ok(Some((tar_gz_path.to_string_lossy().into_owned(), algo, max_upload_bytes)))
+    }
+
+    // Show where the files are and offer to exclude the biggest directories
+    // via `.omniignore`. Returns false if the user declines.
+    fn offer_omniignore_fix(
+        &self,
+        project_path: &Path,
+        breakdown: &FileBreakdown,
+    ) -> Result<bool> {
+        let largest = breakdown.largest(8);
+        self.notice(format!(
+            "\n{}",
+            style("Largest directories by file count:").cyan().bold()
+        ));
+        for line in file_group_lines(&largest) {
+            self.notice(line);
+        }
+
+        let candidates: Vec<&str> = largest
+            .iter()
+            .map(|(group, _)| *group)
+            .filter(|group| group.ends_with('/'))
+            .collect();
+        if candidates.is_empty() {
+            return Ok(false);
+        }
+        let defaults: Vec<bool> = candidates
+            .iter()
+            .map(|dir| HEAVY_DIRECTORIES.contains(&dir.trim_end_matches('/')))
+            .collect();
+        let selected = MultiSelect::with_theme(&self.theme)
+            .with_prompt("Add directories to .omniignore (space to toggle, enter to confirm, none to cancel)")
+            .items(&candidates)
+            .defaults(&defaults)
+            .interact()?;
+        if selected.is_empty() {
+            return Ok(false);
+        }
+
+        let ignore_path = project_path.join(".omniignore");
+        let existing = std::fs::read_to_string(&ignore_path).unwrap_or_default();
+        let mut additions = String::new();
+        if !existing.is_empty() && !existing.ends_with('\n') {
+            additions.push('\n');
+        }
+        for index in &selected {
+            additions.push_str(&format!("/{}\n", candidates[*index]));
+        }
+        std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&ignore_path)
+            .and_then(|mut file| std::io::Write::write_all(&mut file, additions.as_bytes()))
+            .context("Failed to update .omniignore")?;
+
+        self.notice(
+            style(format!(
+                "Added {} pattern(s) to {}, re-checking...",
+                selected.len(),
+                ignore_path.display()
+            ))
+            .green(),
+        );
+        Ok(true)
+    }
+
+    // Largest tarball worth uploading: the limit the server advertises in
+    // /deploy/permissions, or else the `deploy.max_upload_bytes` setting
+    fn upload_limit(&self, advertised: Option<u64>) -> Option<(u64, &'static str)> {
+        advertised
+            .map(|limit| (limit, "the server's upload limit"))
+            .or_else(|| {
+                self.api_client
+                    .get_setting::<u64>("deploy.max_upload_bytes")
+                    .map(|limit| (limit, "the deploy.max_upload_bytes limit"))
+            })
+    }
+
+    // How long to wait for the server-side build: the
+    // `deploy.build_timeout_seconds` setting, or 30 minutes
+    fn build_timeout(&self) -> Duration {
+        Duration::from_secs(self.api_client.get_setting_or(
+            "deploy.build_timeout_seconds",
+            DEFAULT_BUILD_TIMEOUT_SECONDS,
+        ))
+    }
+
+    // Follow the release through the server's build and rollout phases
+    // until it goes live or fails. Returns false when the server doesn't
+    // report release progress, so the outcome is unknown.
+    async fn wait_for_build(&self, app: &str, release: &str) -> Result<bool> {
+        let endpoint = format!("/apps/{}/releases/{}/status", app, release);
+        let pb = self.create_progress_bar(RELEASE_PHASES.len() as u64, "Waiting for the build");
+        let mut current_phase: Option<usize> = None;
+
+        loop {
+            let status = match self.api_client.get::<ApiResponse>(&endpoint).await {
+                Ok(response) => match self.parse_status_data::<ReleaseStatus>(response.data) {
+                    StatusData::Available(status) => Some(status),
+                    StatusData::Missing | StatusData::Malformed => None,
+                },
+                Err(err)
+                    if matches!(
+                        ApiError::status_of(&err),
+                        Some(
+                            StatusCode::NOT_FOUND
+                                | StatusCode::METHOD_NOT_ALLOWED
+                                | StatusCode::NOT_IMPLEMENTED
+                        )
+                    ) =>
+                {
+                    pb.finish_and_clear();
+                    self.progress(
+                        style(format!(
+                            "⚠️  This server doesn't report build progress. Release {} was \
+                             uploaded; run 'omni status' to follow its rollout.",
+                            release
+                        ))
+                        .yellow()
+                    );
+                    return Ok(false);
+                }
+                Err(err) => {
+                    pb.abandon();
+                    return Err(err.context("Failed to fetch the release status"));
+                }
+            };
+
+            if let Some(status) = status {
+                match status.phase.as_str() {
+                    "succeeded" => {
+                        let remaining = &RELEASE_PHASES[current_phase.unwrap_or(0)..];
+                        for (_, label) in remaining {
+                            pb.println(format!("{} ✓", label));
+                        }
+                        pb.set_position(RELEASE_PHASES.len() as u64);
+                        pb.finish_with_message("Release is live ✓");
+                        return Ok(true);
+                    }
+                    "failed" => {
+                        pb.abandon_with_message("Release failed ✗");
+                        return Err(anyhow!(
+                            "Release {} failed{}: {}",
+                            release,
+                            current_phase
+                                .map(|index| {
+                                    format!(" while {}", RELEASE_PHASES[index].1.to_lowercase())
+                                })
+                                .unwrap_or_default(),
+                            status.message.as_deref().unwrap_or("no reason given")
+                        ));
+                    }
+                    phase => match RELEASE_PHASES.iter().position(|(name, _)| *name == phase) {
+                        Some(index) => {
+                            // Mark the phases the server has moved past
+                            let passed = RELEASE_PHASES.get(current_phase.unwrap_or(0)..index);
+                            for (_, label) in passed.unwrap_or_default() {
+                                pb.println(format!("{} ✓", label));
+                            }
+                            current_phase = Some(index);
+                            pb.set_position(index as u64);
+                            let label = RELEASE_PHASES[index].1;
+                            pb.set_message(match &status.message {
+                                Some(message) => format!("{} ({})", label, message),
+                                None => label.to_string(),
+                            });
+                        }
+                        None => pb.set_message(phase.to_string()),
+                    },
+                }
+            }
+            tokio::time::sleep(Duration::from_secs(RELEASE_POLL_INTERVAL_SECONDS)).await;
+        }
+    }
+
+    // Ask whether to abort a stuck or interrupted build on the server. Without
+    // a terminal to ask on, the build is left running.
+    async fn offer_build_cancel(&self, name: &str, release: &str) {
+        let cancel = Confirm::with_theme(&self.theme)
+            .with_prompt(format!("Cancel the build of release {} on the server?", release))
+            .default(true)
+            .interact()
+            .unwrap_or(false);
+        if !cancel {
+            self.info(format!("Release {} is still building on the server.", release));
+            return;
+        }
+
+        match self
+            .api_client
+            .post::<_, ApiResponse>(
+                &format!("/apps/{}/releases/{}/cancel", name, release),
+                &(),
+            )
+            .await
+        {
+            Ok(response) => {
+                self.progress(style("Build cancelled ✓").green());
+                if !response.message.is_empty() {
+                    self.progress(style(format!("API response: {}", response.message)).dim());
+                }
+            }
+            Err(err) => eprintln!(
+                "{}",
+                style(format!("Failed to cancel the build: {}", err)).red()
+            ),
+        }
     }
 
+    // Upload the tarball as `release`, starting `offset` bytes in when the
+    // server already holds the beginning of it
     async fn upload_tarball(
         &self,
         tarball_path: &str,
+        algo: CompressionAlgo,
         environment: &str,
         name: &str,
+        release: &str,
+        offset: u64,
     ) -> Result<()> {
         let path = PathBuf::from(tarball_path);
         if !path.is_file() {
             return Err(anyhow!("Path is not a file"));
         }
-        let uuid = uuid::Uuid::new_v4();
-        let uuid_str = format!("u-{}", uuid.to_string());
 
         // Use the base URL from the API client
         let api_url = format!("{}/apps/{}/releases/{}/upload", 
-            self.api_client.base_url, name, uuid_str);
-
-        let file_content = fs::read(tarball_path).await?;
-
-        // Create the part with the correct field name "media" to match server expectations
-        let part = Part::bytes(file_content)
-            .file_name(name.to_string())
-            .mime_str("application/gzip")?;
-
-        // Use "media" as the field name to match the server's expected field
-        let form = Form::new()
-            .part("media", part)
-            .text("environment", environment.to_string());
-
-        let pb = self.create_progress_bar(100, "Uploading project");
-
-        // Use the API client's underlying client to send the request
-        let response = self.api_client.client
-            .post(&api_url)
-            .headers(self.api_client.headers.clone())
-            .multipart(form)
-            .send()
-            .await?;
-
-        if !response.status().is_success() {
-            pb.abandon_with_message("Upload failed!");
-            anyhow::bail!(
-                "Failed to upload tarball: {} - {}",
-                response.status(),
-                response
-                    .text()
-                    .await
-                    .unwrap_or_else(|_| "No error message".to_string())
-            );
+            self.api_client.base_url, name, release);
+
+        let tarball_bytes = fs::metadata(&path).await?.len();
+        let pb = self.create_bytes_progress_bar(tarball_bytes, "Uploading project");
+
+        // The form sets its own multipart Content-Type
+        let mut headers = self.api_client.headers.clone();
+        headers.remove(CONTENT_TYPE);
+
+        // The release id is fixed up front, so repeating the upload is safe.
+        // Server errors and timeouts are retried like other API requests.
+        let retry_status_codes = self.api_client.retry_status_codes();
+        let max_attempts = self.api_client.max_attempts();
+        // The shared client's timeout covers the whole request, which a large
+        // tarball can't upload within. Bound the connect, and each read of
+        // the response, instead.
+        let timeout = Duration::from_secs(self.api_client.config.timeout_seconds);
+        let client = reqwest::Client::builder()
+            .connect_timeout(timeout)
+            .read_timeout(timeout)
+            .build()?;
+        let mut attempt = 1;
+        loop {
+            // Stream the tarball from disk, advancing the bar as chunks are sent
+            pb.set_position(offset);
+            let mut file = fs::File::open(&path)
+                .await
+                .context("Failed to open tarball")?;
+            file.seek(SeekFrom::Start(offset)).await?;
+            let progress = pb.clone();
+            let stream = ReaderStream::new(file)
+                .inspect_ok(move |chunk| progress.inc(chunk.len() as u64));
+
+            // Create the part with the correct field name "media" to match server expectations
+            let part = Part::stream_with_length(Body::wrap_stream(stream), tarball_bytes - offset)
+                .file_name(name.to_string())
+                .mime_str(algo.mime_type())?;
+
+            // Use "media" as the field name to match the server's expected field
+            let mut form = Form::new()
+                .part("media", part)
+                .text("environment", environment.to_string())
+                .text("compression", algo.as_str());
+            if offset > 0 {
+                form = form.text("offset", offset.to_string());
+            }
+
+            let sent = client
+                .post(&api_url)
+                .headers(headers.clone())
+                .multipart(form)
+                .send()
+                .await;
+
+            let retry_reason = match sent {
+                Ok(response) if response.status().is_success() => break,
+                Ok(response) => {
+                    let status = response.status();
+                    let retryable = retry_status_codes.contains(&status);
+                    if !retryable || attempt >= max_attempts {
+                        pb.abandon_with_message("Upload failed!");
+                        let body = response.text().await.unwrap_or_default();
+                        return Err(upload_failure(status, &body, tarball_bytes));
+                    }
+                    status.to_string()
+                }
+                Err(err)
+                    if (err.is_timeout() || err.is_connect()) && attempt < max_attempts =>
+                {
+                    err.to_string()
+                }
+                Err(err) => {
+                    pb.abandon_with_message("Upload failed!");
+                    return Err(anyhow!("Upload failed: {}", err));
+                }
+            };
+
+            pb.set_message(format!(
+                "Uploading project (retrying after {}, attempt {}/{})",
+                retry_reason,
+                attempt + 1,
+                max_attempts
+            ));
+            tokio::time::sleep(retry_delay(attempt)).await;
+            attempt += 1;
         }
 
         pb.finish_with_message("Upload completed successfully ✓");
@@ -406,4 +1598,58 @@ Are you sure you would like to deploy it? This make take significant amounts of
             }
         }
     }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::init_env::tests::{serve, test_ui};
+
+    // Upload a small tarball to a server that answers with `status` and
+    // `body`, without retrying, and return the resulting error
+    async fn rejected_upload(status: u16, body: &'static str) -> anyhow::Error {
+        let mut ui = test_ui();
+        ui.api_client.base_url = serve(vec![("/apps/web/releases/r1/upload", status, body)]).await;
+        ui.api_client.config.max_retries = 0;
+
+        let tarball = tempfile::NamedTempFile::new().unwrap();
+        std::fs::write(tarball.path(), vec![0u8; 2048]).unwrap();
+        ui.upload_tarball(
+            tarball.path().to_str().unwrap(),
+            CompressionAlgo::Gzip,
+            "production",
+            "web",
+            "r1",
+            0,
+        )
+        .await
+        .unwrap_err()
+    }
+
+    #[tokio::test]
+    async fn payload_too_large_reports_the_server_limit() {
+        let err = rejected_upload(413, r#"{"data": {"max_bytes": 1024}}"#).await;
+        let message = err.to_string();
+        assert!(message.contains("exceeds the server's size limit"), "{}", message);
+        assert!(message.contains("the tarball is 2.00 KiB"), "{}", message);
+        assert!(message.contains("the limit is 1.00 KiB"), "{}", message);
+    }
+
+    #[tokio::test]
+    async fn rejected_credentials_point_at_the_api_key() {
+        for status in [401, 403] {
+            let message = rejected_upload(status, "{}").await.to_string();
+            assert!(message.contains("rejected the upload's credentials"), "{}", message);
+            assert!(message.contains("api_key"), "{}", message);
+        }
+    }
+
+    #[tokio::test]
+    async fn server_errors_include_the_server_message() {
+        let message = rejected_upload(503, r#"{"message": "builder is restarting"}"#)
+            .await
+            .to_string();
+        assert!(message.starts_with("Upload failed: 503"), "{}", message);
+        assert!(message.contains("builder is restarting"), "{}", message);
+    }
 }
\ No newline at end of file
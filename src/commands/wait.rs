@@ -0,0 +1,143 @@
+use crate::api_client::AppConfig;
+use crate::ui::PremiumUI;
+use console::style;
+use serde::Serialize;
+use std::fmt;
+use std::time::{Duration, Instant};
+
+// The kinds of wait whose limit can be configured, each read from its own
+// `AppConfig` field. --wait-timeout takes precedence over all of them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WaitKind {
+    Bootstrap,
+    Service,
+    Backup,
+    Rollback,
+    Push,
+}
+
+impl WaitKind {
+    const ALL: [WaitKind; 5] = [
+        WaitKind::Bootstrap,
+        WaitKind::Service,
+        WaitKind::Backup,
+        WaitKind::Rollback,
+        WaitKind::Push,
+    ];
+
+    // Name of the config field, as used by `config set/get/unset`
+    pub fn key(self) -> &'static str {
+        match self {
+            WaitKind::Bootstrap => "bootstrap_timeout_seconds",
+            WaitKind::Service => "service_timeout_seconds",
+            WaitKind::Backup => "backup_timeout_seconds",
+            WaitKind::Rollback => "rollback_timeout_seconds",
+            WaitKind::Push => "push_timeout_seconds",
+        }
+    }
+
+    pub fn from_key(key: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|kind| kind.key() == key)
+    }
+
+    pub fn configured(self, config: &AppConfig) -> Option<u64> {
+        match self {
+            WaitKind::Bootstrap => config.bootstrap_timeout_seconds,
+            WaitKind::Service => config.service_timeout_seconds,
+            WaitKind::Backup => config.backup_timeout_seconds,
+            WaitKind::Rollback => config.rollback_timeout_seconds,
+            WaitKind::Push => config.push_timeout_seconds,
+        }
+    }
+
+    pub fn configured_mut(self, config: &mut AppConfig) -> &mut Option<u64> {
+        match self {
+            WaitKind::Bootstrap => &mut config.bootstrap_timeout_seconds,
+            WaitKind::Service => &mut config.service_timeout_seconds,
+            WaitKind::Backup => &mut config.backup_timeout_seconds,
+            WaitKind::Rollback => &mut config.rollback_timeout_seconds,
+            WaitKind::Push => &mut config.push_timeout_seconds,
+        }
+    }
+}
+
+// A wait that ran out of time, carrying the last status the server reported
+// so automation can see how far the operation got
+#[derive(Debug, Serialize)]
+pub struct WaitTimedOut {
+    pub operation: String,
+    pub waited_seconds: u64,
+    pub last_status: Option<serde_json::Value>,
+}
+
+impl fmt::Display for WaitTimedOut {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Timed out locally after {}s waiting for {}. The server hasn't reported a failure \
+             and may still be working on it.",
+            self.waited_seconds, self.operation
+        )
+    }
+}
+
+impl std::error::Error for WaitTimedOut {}
+
+impl PremiumUI {
+    // --wait-timeout, or else the wait's configured limit
+    fn wait_timeout_for(&self, kind: WaitKind) -> Option<Duration> {
+        self.wait_timeout.or_else(|| {
+            kind.configured(&self.api_client.config)
+                .map(Duration::from_secs)
+        })
+    }
+
+    // Deadline for waits that otherwise poll until the server finishes
+    pub fn wait_deadline(&self, kind: WaitKind) -> Option<Instant> {
+        self.wait_timeout_for(kind)
+            .map(|timeout| Instant::now() + timeout)
+    }
+
+    // Deadline for waits with a limit of their own, used when neither
+    // --wait-timeout nor the config sets one
+    pub fn wait_deadline_or(&self, kind: WaitKind, default: Duration) -> Instant {
+        Instant::now() + self.wait_timeout_for(kind).unwrap_or(default)
+    }
+
+    // Report the last observed status of a timed-out wait (as JSON with
+    // --json) and build the error the wait should return
+    pub fn wait_timed_out<T: Serialize>(
+        &self,
+        operation: &str,
+        waited: Duration,
+        last_status: Option<&T>,
+    ) -> anyhow::Error {
+        let timeout = WaitTimedOut {
+            operation: operation.to_string(),
+            waited_seconds: waited.as_secs(),
+            last_status: last_status.and_then(|status| serde_json::to_value(status).ok()),
+        };
+
+        if self.wait_json {
+            if let Ok(json) = serde_json::to_string_pretty(&timeout) {
+                println!("{}", json);
+            }
+        } else {
+            match &timeout.last_status {
+                Some(status) => {
+                    println!("{}", style("Last observed status:").yellow());
+                    println!(
+                        "{}",
+                        style(serde_json::to_string_pretty(status).unwrap_or_default()).dim()
+                    );
+                }
+                None => println!(
+                    "{}",
+                    style("No status was received from the server before the timeout.").dim()
+                ),
+            }
+        }
+
+        anyhow::Error::new(timeout)
+    }
+}
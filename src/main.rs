@@ -1,24 +1,138 @@
 // main.rs
-use crate::ui::PremiumUI;
-use clap::{Arg, Command};
+use crate::api_client::{normalize_base_url, ApiClient};
+use crate::commands::audit::{audited_command, is_mutating_command, leaf_command};
+use crate::commands::notify::long_running_command;
+use crate::selector::Selector;
+use crate::ui::{expand_home, OutputMode, PremiumUI};
+use clap::{Arg, ArgMatches, Command};
+use clap_complete::Shell;
 use console::style;
+use std::path::PathBuf;
 
 mod api_client;
 mod commands;
 mod models;
+mod selector;
 mod ui;
 
-#[tokio::main]
-async fn main() -> anyhow::Result<()> {
-    let ui = PremiumUI::new();
-
-    let cli = Command::new("omni")
+fn build_cli() -> Command {
+    Command::new("omni")
         .about(format!(
             "{}",
             style("OmniOrchestrator - Self-Hosted Cloud Platform CLI")
                 .cyan()
                 .bold()
         ))
+        .arg(
+            Arg::new("debug")
+                .long("debug")
+                .help("Show diagnostic details for unexpected server responses")
+                .global(true)
+                .action(clap::ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("verbose")
+                .short('v')
+                .long("verbose")
+                .help(
+                    "Show more detail: -v requests, -vv headers and bodies (redacted), \
+                     -vvv timing and retries. OMNI_DEBUG=1 implies -vv",
+                )
+                .global(true)
+                .action(clap::ArgAction::Count),
+        )
+        .arg(
+            Arg::new("quiet")
+                .short('q')
+                .long("quiet")
+                .help("Only print errors and results, without status chatter or progress bars")
+                .conflicts_with("verbose")
+                .global(true)
+                .action(clap::ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("output")
+                .short('o')
+                .long("output")
+                .help("Output format for list commands; `wide` adds extra columns to hosts and status, json and yaml print plain machine-readable data")
+                .value_parser(["table", "wide", "json", "yaml"])
+                .default_value("table")
+                .global(true),
+        )
+        .arg(
+            Arg::new("selector")
+                .long("selector")
+                .value_name("EXPR")
+                .help("Only show rows matching <field><op><value> (=, !=, >, <, contains); repeatable")
+                .action(clap::ArgAction::Append)
+                .global(true),
+        )
+        .arg(
+            Arg::new("config-path")
+                .long("config")
+                .value_name("PATH")
+                .help("Cloud config file to use instead of config/cloud-config.json")
+                .value_parser(clap::value_parser!(PathBuf))
+                .global(true),
+        )
+        .arg(
+            Arg::new("cwd")
+                .long("cwd")
+                .help("Resolve relative paths as if omni were run from this directory")
+                .long_help(
+                    "Resolve relative paths (the cloud config file and the default project \
+                     path) as if omni were run from this directory. The process working \
+                     directory itself is left unchanged.",
+                )
+                .value_parser(clap::value_parser!(PathBuf))
+                .global(true),
+        )
+        .arg(
+            Arg::new("no-audit")
+                .long("no-audit")
+                .help("Don't record this command in the local audit log")
+                .global(true)
+                .action(clap::ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("no-config-write")
+                .long("no-config-write")
+                .help("Never create or write config files; changes last for this run only")
+                .long_help(
+                    "Never create or write config files (settings, audit log, metadata cache); \
+                     changes last for this run only. Also enabled by setting \
+                     OMNI_NO_CONFIG_WRITE.",
+                )
+                .global(true)
+                .action(clap::ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("explain")
+                .long("explain")
+                .help("Describe the API calls a command would make and their effect, without running it")
+                .global(true)
+                .action(clap::ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("notify")
+                .long("notify")
+                .help("Ring the bell (and run the notify_command setting) when a long operation finishes")
+                .global(true)
+                .action(clap::ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("api-url")
+                .long("api-url")
+                .help("Use this API base URL for this invocation instead of the configured one")
+                .global(true),
+        )
+        .arg(
+            Arg::new("refresh-metadata")
+                .long("refresh-metadata")
+                .help("Ignore cached server metadata and fetch it again")
+                .global(true)
+                .action(clap::ArgAction::SetTrue),
+        )
         .subcommand(
             Command::new("init")
                 .about(format!(
@@ -31,7 +145,43 @@ async fn main() -> anyhow::Result<()> {
                         .help("Force re-initialization even if config exists")
                         .required(false)
                         .action(clap::ArgAction::SetTrue),
-                ),
+                )
+                .arg(
+                    Arg::new("output-config")
+                        .long("output-config")
+                        .value_name("PATH")
+                        .help("Write the generated config here instead of config/cloud-config.json")
+                        .required(false),
+                )
+                .arg(
+                    Arg::new("config-format")
+                        .long("config-format")
+                        .help("Write config/cloud-config as JSON or YAML (--output-config goes by its extension)")
+                        .value_parser(["json", "yaml"])
+                        .conflicts_with("output-config"),
+                )
+                .arg(
+                    Arg::new("max-parallel-hosts")
+                        .long("max-parallel-hosts")
+                        .value_name("N")
+                        .help("Bootstrap at most N hosts at a time (default: server decides)")
+                        .value_parser(clap::value_parser!(u32).range(1..)),
+                )
+                .arg(
+                    Arg::new("from")
+                        .long("from")
+                        .value_name("FILE")
+                        .help("Import SSH hosts from a CSV or JSON file instead of adding them one by one")
+                        .conflicts_with("validate-only"),
+                )
+                .arg(
+                    Arg::new("validate-only")
+                        .long("validate-only")
+                        .visible_alias("check")
+                        .help("Check config, hosts, region, API and auth without bootstrapping")
+                        .action(clap::ArgAction::SetTrue),
+                )
+                .args(wait_flags()),
         )
         .subcommand(Command::new("version").about(format!(
             "{}",
@@ -41,12 +191,91 @@ async fn main() -> anyhow::Result<()> {
             Command::new("welcome").about(format!("{}", style("Display welcome message").green())),
         )
         .subcommand(
-            Command::new("hosts").about(format!("{}", style("List configured SSH hosts").green())),
+            Command::new("hosts")
+                .about(format!("{}", style("List configured SSH hosts").green())),
         )
-        .subcommand(Command::new("status").about(format!(
+        .subcommand(
+            Command::new("host")
+                .about(format!(
+                    "{}",
+                    style("Add, remove or edit SSH hosts in the cloud config").green()
+                ))
+                .subcommand(Command::new("add").about("Add an SSH host"))
+                .subcommand(
+                    Command::new("remove")
+                        .about("Remove an SSH host")
+                        .arg(Arg::new("name").required(true))
+                        .arg(
+                            Arg::new("yes")
+                                .short('y')
+                                .long("yes")
+                                .help("Skip the confirmation prompt")
+                                .action(clap::ArgAction::SetTrue),
+                        ),
+                )
+                .subcommand(
+                    Command::new("edit")
+                        .about("Edit an SSH host, prompting with its current values")
+                        .arg(Arg::new("name").required(true)),
+                ),
+        )
+        .subcommand(
+            Command::new("login")
+                .about(format!("{}", style("Log in to the Omni API").green()))
+                .arg(
+                    Arg::new("token")
+                        .long("token")
+                        .help("Store this API token instead of logging in with a password"),
+                )
+                .arg(
+                    Arg::new("username")
+                        .long("username")
+                        .short('u')
+                        .help("Username to log in as [prompted]")
+                        .conflicts_with("token"),
+                ),
+        )
+        .subcommand(Command::new("logout").about(format!(
             "{}",
-            style("Check OmniOrchestrator status").green()
+            style("Forget the stored API token").green()
         )))
+        .subcommand(
+            Command::new("status")
+                .about(format!(
+                    "{}",
+                    style("Check OmniOrchestrator status").green()
+                ))
+                .arg(
+                    Arg::new("watch")
+                        .short('w')
+                        .long("watch")
+                        .help("Keep refreshing the host and service tables until Ctrl-C")
+                        .action(clap::ArgAction::SetTrue),
+                )
+                .arg(
+                    Arg::new("interval")
+                        .long("interval")
+                        .value_name("SECS")
+                        .help("Seconds between refreshes with --watch")
+                        .requires("watch")
+                        .value_parser(clap::value_parser!(u64).range(1..))
+                        .default_value("2"),
+                ),
+        )
+        .subcommand(
+            Command::new("health")
+                .about(format!(
+                    "{}",
+                    style("Summarize platform and app health (exit 0 healthy, 1 degraded, 2 down)")
+                        .green()
+                ))
+                .arg(
+                    Arg::new("json")
+                        .long("json")
+                        .help("Print the health report as JSON")
+                        .action(clap::ArgAction::SetTrue),
+                ),
+        )
         .subcommand(
             Command::new("up")
                 .about(format!(
@@ -61,6 +290,67 @@ async fn main() -> anyhow::Result<()> {
                             style("[dev/staging/prod]").yellow()
                         ))
                         .required(false),
+                )
+                .arg(
+                    Arg::new("yes")
+                        .short('y')
+                        .long("yes")
+                        .help("Deploy to the --env environment without confirming")
+                        .requires("environment")
+                        .action(clap::ArgAction::SetTrue),
+                )
+                .arg(
+                    Arg::new("path")
+                        .long("path")
+                        .help("Project directory to deploy (skips the path prompt)")
+                        .required(false),
+                )
+                .arg(
+                    Arg::new("json")
+                        .long("json")
+                        .help("Print the deployment summary as JSON")
+                        .action(clap::ArgAction::SetTrue),
+                )
+                .arg(
+                    Arg::new("keep-tarball")
+                        .long("keep-tarball")
+                        .help("Keep the uploaded tarball and print its path")
+                        .action(clap::ArgAction::SetTrue),
+                )
+                .arg(
+                    Arg::new("resume")
+                        .long("resume")
+                        .help("Continue the last interrupted upload to this environment")
+                        .conflicts_with("dry-run")
+                        .action(clap::ArgAction::SetTrue),
+                )
+                .arg(
+                    Arg::new("dry-run")
+                        .long("dry-run")
+                        .help("Build the tarball and list its files without deploying")
+                        .conflicts_with("json")
+                        .action(clap::ArgAction::SetTrue),
+                )
+                .arg(
+                    Arg::new("force-unlock")
+                        .long("force-unlock")
+                        .help("Take over the project's deploy lock even if another deploy holds it")
+                        .action(clap::ArgAction::SetTrue),
+                )
+                .arg(
+                    Arg::new("compression")
+                        .long("compression")
+                        .value_name("LEVEL")
+                        .help("Tarball gzip level: 0-9, fast or best (default: deploy.compression setting)")
+                        .value_parser(|value: &str| {
+                            commands::up::parse_compression_level(value).map(|_| value.to_string())
+                        }),
+                )
+                .arg(
+                    Arg::new("compression-algo")
+                        .long("compression-algo")
+                        .help("Tarball compression; falls back to gzip if the server lacks zstd (default: deploy.compression_algo setting)")
+                        .value_parser(["gzip", "zstd"]),
                 ),
         )
         .subcommand(
@@ -74,11 +364,197 @@ async fn main() -> anyhow::Result<()> {
                         .long("tag")
                         .help(&format!("Image tag {}", style("[latest]").yellow()))
                         .required(false),
+                )
+                .arg(
+                    Arg::new("registry")
+                        .long("registry")
+                        .help("Registry to push to (overrides the environment mapping and default)")
+                        .required(false),
+                )
+                .arg(
+                    Arg::new("app")
+                        .long("app")
+                        .help("App whose image to push (prompts when omitted)")
+                        .required(false),
+                )
+                .arg(
+                    Arg::new("environment")
+                        .long("env")
+                        .help("Target environment, used to pick its mapped registry")
+                        .required(false),
+                )
+                .args(wait_flags()),
+        )
+        .subcommand(
+            Command::new("apps")
+                .about(format!("{}", style("Manage deployed applications").green()))
+                .subcommand(
+                    Command::new("rename")
+                        .about("Rename a deployed app")
+                        .arg(Arg::new("old").required(true))
+                        .arg(Arg::new("new").required(true))
+                        .arg(
+                            Arg::new("yes")
+                                .short('y')
+                                .long("yes")
+                                .help("Skip the confirmation prompt")
+                                .action(clap::ArgAction::SetTrue),
+                        ),
+                )
+                .subcommand(
+                    Command::new("restart")
+                        .about("Restart every service running an app")
+                        .arg(Arg::new("app").required(true))
+                        .arg(
+                            Arg::new("component")
+                                .long("component")
+                                .help("Only restart services of this component"),
+                        )
+                        .arg(
+                            Arg::new("rolling")
+                                .long("rolling")
+                                .help("Restart one service at a time, waiting for each to be running again")
+                                .action(clap::ArgAction::SetTrue),
+                        )
+                        .arg(
+                            Arg::new("yes")
+                                .short('y')
+                                .long("yes")
+                                .help("Skip the confirmation prompt")
+                                .action(clap::ArgAction::SetTrue),
+                        )
+                        .args(wait_flags()),
+                )
+                .subcommand(
+                    Command::new("logs")
+                        .about("Show logs from every service running an app")
+                        .arg(Arg::new("app").required(true))
+                        .arg(
+                            Arg::new("component")
+                                .long("component")
+                                .help("Only show logs of this component"),
+                        )
+                        .arg(
+                            Arg::new("tail")
+                                .long("tail")
+                                .help("Number of lines to show per service")
+                                .value_parser(clap::value_parser!(usize))
+                                .default_value("100"),
+                        )
+                        .arg(
+                            Arg::new("parallel")
+                                .long("parallel")
+                                .help("Maximum number of services to fetch logs from at once")
+                                .value_parser(clap::value_parser!(usize))
+                                .default_value("8"),
+                        ),
+                ),
+        )
+        .subcommand(
+            Command::new("context")
+                .about(format!(
+                    "{}",
+                    style("Show which cloud, API and identity are in use").green()
+                ))
+                .subcommand(
+                    Command::new("info").about("Summarize the active config, API URL, identity and versions"),
+                ),
+        )
+        .subcommand(
+            Command::new("prune")
+                .about(format!(
+                    "{}",
+                    style("Delete old releases and backups").green()
+                ))
+                .subcommand(
+                    Command::new("releases")
+                        .about("Delete all but the most recent releases of an app")
+                        .arg(Arg::new("app").required(true))
+                        .arg(
+                            Arg::new("keep")
+                                .long("keep")
+                                .value_name("N")
+                                .help("Number of recent releases to keep, including the active one")
+                                .value_parser(clap::value_parser!(usize))
+                                .required(true),
+                        )
+                        .args(prune_flags()),
+                )
+                .subcommand(
+                    Command::new("backups")
+                        .about("Delete old backups")
+                        .arg(
+                            Arg::new("keep")
+                                .long("keep")
+                                .value_name("N")
+                                .help("Number of recent backups to keep")
+                                .value_parser(clap::value_parser!(usize)),
+                        )
+                        .arg(
+                            Arg::new("older-than")
+                                .long("older-than")
+                                .value_name("AGE")
+                                .help("Only delete backups older than this, e.g. 30d, 12h, 2w")
+                                .value_parser(commands::prune::parse_age),
+                        )
+                        .group(
+                            clap::ArgGroup::new("criteria")
+                                .args(["keep", "older-than"])
+                                .multiple(true)
+                                .required(true),
+                        )
+                        .args(prune_flags()),
+                ),
+        )
+        .subcommand(
+            Command::new("releases")
+                .about(format!("{}", style("Inspect application releases").green()))
+                .subcommand(
+                    Command::new("diff")
+                        .about("Show what changed between two releases of an app")
+                        .arg(Arg::new("app").required(true))
+                        .arg(Arg::new("release-a").required(true))
+                        .arg(Arg::new("release-b").required(true))
+                        .arg(
+                            Arg::new("json")
+                                .long("json")
+                                .help("Print the differences as JSON")
+                                .action(clap::ArgAction::SetTrue),
+                        ),
+                ),
+        )
+        .subcommand(
+            Command::new("registry")
+                .about(format!(
+                    "{}",
+                    style("Manage container registry credentials").green()
+                ))
+                .subcommand(
+                    Command::new("login")
+                        .about("Store credentials for a registry")
+                        .arg(Arg::new("url").required(true)),
+                )
+                .subcommand(
+                    Command::new("logout")
+                        .about("Remove stored credentials for a registry")
+                        .arg(Arg::new("url").required(true)),
+                )
+                .subcommand(Command::new("list").about("List registries with stored credentials"))
+                .subcommand(
+                    Command::new("set-default")
+                        .about("Use a registry when none is given or mapped")
+                        .arg(Arg::new("url").required(true)),
                 ),
         )
         .subcommand(
             Command::new("scale")
                 .about(format!("{}", style("Scale application components").green()))
+                .arg(
+                    Arg::new("app")
+                        .long("app")
+                        .help("App to scale [prompted]")
+                        .required(false),
+                )
                 .arg(
                     Arg::new("component")
                         .long("component")
@@ -92,7 +568,22 @@ async fn main() -> anyhow::Result<()> {
                     Arg::new("replicas")
                         .long("replicas")
                         .help(&format!("Number of replicas {}", style("[1-10]").yellow()))
+                        .value_parser(clap::value_parser!(u32).range(
+                            commands::scale::MIN_REPLICAS as i64
+                                ..=commands::scale::MAX_REPLICAS as i64,
+                        ))
                         .required(false),
+                )
+                .subcommand(
+                    Command::new("show")
+                        .about("Show current replicas and autoscaling without changing them")
+                        .arg(Arg::new("app").required(true))
+                        .arg(
+                            Arg::new("json")
+                                .long("json")
+                                .help("Print the scaling state as JSON")
+                                .action(clap::ArgAction::SetTrue),
+                        ),
                 ),
         )
         .subcommand(
@@ -115,6 +606,95 @@ async fn main() -> anyhow::Result<()> {
                         .long("tail")
                         .help("Number of lines to show")
                         .default_value("100"),
+                )
+                .arg(
+                    Arg::new("parallel")
+                        .long("parallel")
+                        .help("Maximum number of services to fetch logs from at once")
+                        .value_parser(clap::value_parser!(usize))
+                        .default_value("8"),
+                )
+                .arg(
+                    Arg::new("follow")
+                        .short('f')
+                        .long("follow")
+                        .help("Stream new lines of --host/--service as they're written, until the stream closes")
+                        .requires_all(["host", "service"])
+                        .conflicts_with_all(["watch", "export"])
+                        .action(clap::ArgAction::SetTrue),
+                )
+                .arg(
+                    Arg::new("watch")
+                        .long("watch")
+                        .help("Keep following --host/--service, reconnecting across restarts")
+                        .requires_all(["host", "service"])
+                        .action(clap::ArgAction::SetTrue),
+                )
+                .arg(
+                    Arg::new("since-release")
+                        .long("since-release")
+                        .help("Only show logs since the latest release started")
+                        .action(clap::ArgAction::SetTrue),
+                )
+                .arg(
+                    Arg::new("since")
+                        .long("since")
+                        .value_name("TIME")
+                        .help("Only show logs after TIME (RFC 3339, or an age like 30m, 2h, 1d)")
+                        .value_parser(commands::logs::parse_log_time)
+                        .conflicts_with("since-release"),
+                )
+                .arg(
+                    Arg::new("until")
+                        .long("until")
+                        .value_name("TIME")
+                        .help("Only export logs before TIME (RFC 3339, or an age like 30m, 2h, 1d)")
+                        .value_parser(commands::logs::parse_log_time)
+                        .requires("export"),
+                )
+                .arg(
+                    Arg::new("grep")
+                        .long("grep")
+                        .value_name("PATTERN")
+                        .help("Only show lines containing PATTERN")
+                        .conflicts_with("export"),
+                )
+                .arg(
+                    Arg::new("context")
+                        .short('C')
+                        .long("context")
+                        .value_name("N")
+                        .help("Show N lines before and after each --grep match")
+                        .value_parser(clap::value_parser!(usize))
+                        .requires("grep"),
+                )
+                .arg(
+                    Arg::new("export")
+                        .long("export")
+                        .value_name("FORMAT")
+                        .help("Export the full log range of --host/--service to a file")
+                        .value_parser(["json", "ndjson"])
+                        .requires_all(["host", "service"])
+                        .conflicts_with("watch"),
+                )
+                .arg(
+                    Arg::new("output")
+                        .long("output")
+                        .value_name("PATH")
+                        .help("File to write the --export bundle to")
+                        .requires("export"),
+                )
+                .arg(
+                    Arg::new("app")
+                        .long("app")
+                        .help("App whose latest release --since-release uses (defaults to the current directory name)"),
+                )
+                .arg(
+                    Arg::new("output-format")
+                        .long("output-format")
+                        .help("How to print log records")
+                        .value_parser(["text", "json", "logfmt"])
+                        .default_value("text"),
                 ),
         )
         .subcommand(
@@ -127,40 +707,151 @@ async fn main() -> anyhow::Result<()> {
                     Command::new("restart")
                         .about("Restart a service")
                         .arg(Arg::new("host").required(true))
-                        .arg(Arg::new("service").required(true)),
+                        .arg(Arg::new("service").required(true))
+                        .args(wait_flags()),
                 )
                 .subcommand(
                     Command::new("stop")
                         .about("Stop a service")
                         .arg(Arg::new("host").required(true))
-                        .arg(Arg::new("service").required(true)),
+                        .arg(Arg::new("service").required(true))
+                        .args(wait_flags()),
                 )
                 .subcommand(
                     Command::new("start")
                         .about("Start a service")
                         .arg(Arg::new("host").required(true))
-                        .arg(Arg::new("service").required(true)),
+                        .arg(Arg::new("service").required(true))
+                        .args(wait_flags()),
                 ),
         )
         .subcommand(
             Command::new("backup")
                 .about(format!("{}", style("Manage backup operations").green()))
-                .subcommand(Command::new("now").about("Trigger an immediate backup"))
+                .subcommand(
+                    Command::new("now")
+                        .about("Trigger an immediate backup")
+                        .args(wait_flags()),
+                )
                 .subcommand(Command::new("list").about("List available backups"))
                 .subcommand(
                     Command::new("restore")
                         .about("Restore from a backup")
-                        .arg(Arg::new("id").required(true)),
+                        .arg(Arg::new("id").required(true))
+                        .args(wait_flags()),
+                )
+                .subcommand(
+                    Command::new("download")
+                        .about("Download a backup archive, resuming if interrupted")
+                        .arg(Arg::new("id").required(true))
+                        .arg(
+                            Arg::new("output")
+                                .long("output")
+                                .short('o')
+                                .help("Where to save the archive [backup-<id>.tar.gz]")
+                                .required(false),
+                        ),
+                )
+                .subcommand(
+                    Command::new("verify")
+                        .about("Check that a backup is intact and restorable")
+                        .arg(Arg::new("id").required(true))
+                        .arg(
+                            Arg::new("file")
+                                .long("file")
+                                .help(
+                                    "Also check a downloaded archive against the backup's checksum",
+                                )
+                                .required(false),
+                        )
+                        .args(wait_flags()),
                 ),
         )
         .subcommand(
             Command::new("rollback")
                 .about(format!("{}", style("Rollback to previous version").green()))
+                .arg(
+                    Arg::new("app")
+                        .long("app")
+                        .help("App to roll back [prompted]")
+                        .required(false),
+                )
                 .arg(
                     Arg::new("version")
                         .long("version")
                         .help("Version to rollback to")
                         .required(false),
+                )
+                .args(wait_flags()),
+        )
+        .subcommand(Command::new("doctor").about(format!(
+            "{}",
+            style("Diagnose config, connectivity and login problems").green()
+        )))
+        .subcommand(
+            Command::new("ping")
+                .about(format!(
+                    "{}",
+                    style("Measure API round-trip latency").green()
+                ))
+                .arg(
+                    Arg::new("count")
+                        .long("count")
+                        .short('c')
+                        .help("Number of requests to send")
+                        .value_parser(clap::value_parser!(u32).range(1..))
+                        .default_value("4"),
+                ),
+        )
+        .subcommand(
+            Command::new("watch")
+                .about(format!(
+                    "{}",
+                    style("Re-run a read-only command on an interval").green()
+                ))
+                .arg(
+                    Arg::new("interval")
+                        .long("interval")
+                        .short('n')
+                        .help("Seconds between runs")
+                        .value_parser(clap::value_parser!(u64).range(1..))
+                        .default_value("2"),
+                )
+                .arg(
+                    Arg::new("command")
+                        .help("Command to watch, e.g. `omni watch status`")
+                        .required(true)
+                        .num_args(1..)
+                        .trailing_var_arg(true)
+                        .allow_hyphen_values(true),
+                ),
+        )
+        .subcommand(
+            Command::new("completions")
+                .about(format!(
+                    "{}",
+                    style("Print a shell completion script").green()
+                ))
+                .arg(
+                    Arg::new("shell")
+                        .required(true)
+                        .value_parser(["bash", "zsh", "fish", "powershell"]),
+                ),
+        )
+        .subcommand(
+            Command::new("audit")
+                .about(format!("{}", style("Inspect the local audit log").green()))
+                .subcommand(
+                    Command::new("log")
+                        .about("Show recently audited operations")
+                        .arg(
+                            Arg::new("limit")
+                                .long("limit")
+                                .short('n')
+                                .help("Number of entries to show")
+                                .value_parser(clap::value_parser!(usize))
+                                .default_value("50"),
+                        ),
                 ),
         )
         .subcommand(
@@ -171,31 +862,450 @@ async fn main() -> anyhow::Result<()> {
                 ))
                 .subcommand(Command::new("view").about("View current configuration"))
                 .subcommand(Command::new("edit").about("Edit configuration"))
-                .subcommand(Command::new("reset").about("Reset configuration to defaults")),
+                .subcommand(Command::new("reset").about("Reset configuration to defaults"))
+                .subcommand(
+                    Command::new("edit-host")
+                        .about("Edit a single SSH host in cloud-config.json")
+                        .arg(Arg::new("name").required(true)),
+                )
+                .subcommand(
+                    Command::new("set")
+                        .about("Set a configuration value (JSON values keep their type)")
+                        .arg(Arg::new("key").required(true))
+                        .arg(Arg::new("value").required(true)),
+                )
+                .subcommand(
+                    Command::new("get")
+                        .about("Print a configuration value")
+                        .arg(Arg::new("key").required(true)),
+                )
+                .subcommand(
+                    Command::new("unset")
+                        .about("Remove a stored setting")
+                        .arg(Arg::new("key").required(true)),
+                )
+                .subcommand(
+                    Command::new("clear-settings")
+                        .about("Remove stored settings")
+                        .visible_alias("unset-all")
+                        .arg(
+                            Arg::new("prefix")
+                                .long("prefix")
+                                .help("Only remove settings whose key starts with this prefix")
+                                .required(false),
+                        ),
+                )
+                .subcommand(
+                    Command::new("set-default-env")
+                        .about("Set the environment `omni up` pre-selects")
+                        .arg(Arg::new("env").required(true))
+                        .arg(
+                            Arg::new("allow-prod-default")
+                                .long("allow-prod-default")
+                                .help("Allow a protected environment to be the default")
+                                .action(clap::ArgAction::SetTrue),
+                        ),
+                )
+                .subcommand(
+                    Command::new("set-secret")
+                        .about("Store a secret in the system keyring for ${secret:NAME} references")
+                        .arg(Arg::new("name").required(true))
+                        .arg(
+                            // Named so the audit log redacts it
+                            Arg::new("secret-value")
+                                .value_name("VALUE")
+                                .help("Secret value [prompted, keeping it out of shell history]")
+                                .required(false),
+                        ),
+                )
+                .subcommand(
+                    Command::new("unset-secret")
+                        .about("Remove a stored secret")
+                        .arg(Arg::new("name").required(true)),
+                )
+                .subcommand(Command::new("list-secrets").about("List stored secret names"))
+                .subcommand(
+                    Command::new("push")
+                        .about("Apply cloud-config.json to the running platform")
+                        .arg(
+                            Arg::new("dry-run")
+                                .long("dry-run")
+                                .help("Show the server's plan without applying it")
+                                .action(clap::ArgAction::SetTrue),
+                        )
+                        .arg(
+                            Arg::new("yes")
+                                .short('y')
+                                .long("yes")
+                                .help("Apply without asking for confirmation")
+                                .action(clap::ArgAction::SetTrue),
+                        ),
+                )
+                .subcommand(
+                    Command::new("schema")
+                        .about("Print the JSON schema for cloud-config.json")
+                        .arg(
+                            Arg::new("out")
+                                .long("out")
+                                .help("Write the schema to a file instead of stdout")
+                                .required(false),
+                        ),
+                ),
         )
-        .get_matches();
+}
+
+// `--dry-run` and `--yes`, shared by the prune subcommands
+fn prune_flags() -> [Arg; 2] {
+    [
+        Arg::new("dry-run")
+            .long("dry-run")
+            .help("List what would be deleted without deleting it")
+            .action(clap::ArgAction::SetTrue),
+        Arg::new("yes")
+            .short('y')
+            .long("yes")
+            .help("Delete without asking for confirmation")
+            .action(clap::ArgAction::SetTrue),
+    ]
+}
+
+// `--wait-timeout` and `--json`, shared by commands that wait on the server
+fn wait_flags() -> [Arg; 2] {
+    [
+        Arg::new("wait-timeout")
+            .long("wait-timeout")
+            .visible_alias("timeout")
+            .value_name("SECS")
+            .help("Give up waiting after this many seconds and show the last observed status")
+            .value_parser(clap::value_parser!(u64).range(1..)),
+        Arg::new("wait-json")
+            .long("json")
+            .help("If the wait times out, print the last observed status as JSON")
+            .action(clap::ArgAction::SetTrue),
+    ]
+}
+
+// Commands that print their data as json or yaml with `--output`
+const STRUCTURED_OUTPUT_COMMANDS: &[&str] = &[
+    "hosts",
+    "status",
+    "up",
+    "backup list",
+    "registry list",
+    "audit log",
+    "scale show",
+    "config list-secrets",
+];
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    let cli = build_cli().get_matches();
+
+    // Completions only need the CLI definition, not a config or API client
+    if let Some(("completions", completion_matches)) = cli.subcommand() {
+        let shell = match completion_matches
+            .get_one::<String>("shell")
+            .unwrap()
+            .as_str()
+        {
+            "bash" => Shell::Bash,
+            "zsh" => Shell::Zsh,
+            "fish" => Shell::Fish,
+            _ => Shell::PowerShell,
+        };
+        clap_complete::generate(shell, &mut build_cli(), "omni", &mut std::io::stdout());
+        return Ok(());
+    }
+
+    let cwd = cli.get_one::<PathBuf>("cwd").cloned();
+    if let Some(dir) = &cwd {
+        if !dir.is_dir() {
+            anyhow::bail!("--cwd {} is not a directory", dir.display());
+        }
+    }
+
+    let (leaf_name, leaf_matches) = leaf_command(&cli);
+    let output = match cli.subcommand() {
+        Some(("up", sub)) if sub.get_flag("json") => OutputMode::Json,
+        _ => OutputMode::from_arg(cli.get_one::<String>("output").map(String::as_str)),
+    };
+    // Only the host and service tables have wide columns so far, and only
+    // list commands have data to print as json or yaml
+    let supported = match output {
+        OutputMode::Table => true,
+        OutputMode::Wide => matches!(leaf_name.as_str(), "" | "hosts" | "status" | "watch"),
+        OutputMode::Json | OutputMode::Yaml => {
+            STRUCTURED_OUTPUT_COMMANDS.contains(&leaf_name.as_str())
+        }
+    };
+    if !supported {
+        anyhow::bail!(
+            "--output {} isn't supported by 'omni {}'",
+            cli.get_one::<String>("output").unwrap(),
+            leaf_name
+        );
+    }
+    let watching = matches!(leaf_matches.try_get_one::<bool>("watch"), Ok(Some(true)));
+    if output.is_structured() && watching {
+        anyhow::bail!("--watch redraws tables, so it can't be combined with --output json or yaml");
+    }
+    let wait_timeout = leaf_matches
+        .try_get_one::<u64>("wait-timeout")
+        .ok()
+        .flatten()
+        .map(|secs| std::time::Duration::from_secs(*secs));
+    let wait_json = matches!(
+        leaf_matches.try_get_one::<bool>("wait-json"),
+        Ok(Some(true))
+    );
+
+    let config_path = cli
+        .get_one::<PathBuf>("config-path")
+        .map(|path| expand_home(path))
+        .transpose()?;
 
+    let selectors = cli
+        .get_many::<String>("selector")
+        .unwrap_or_default()
+        .map(|expr| Selector::parse(expr))
+        .collect::<anyhow::Result<Vec<_>>>()?;
+
+    // -vv and up include the diagnostics --debug shows. OMNI_DEBUG=1 counts
+    // as -vv, for runs where adding flags is awkward.
+    let debug_env = std::env::var_os("OMNI_DEBUG").is_some_and(|value| value != "0");
+    let verbosity = cli.get_count("verbose").max(if debug_env { 2 } else { 0 });
+    // `doctor` only diagnoses, so it never rewrites a config it can't parse
+    let no_config_write = cli.get_flag("no-config-write")
+        || cli.subcommand_name() == Some("doctor")
+        || std::env::var_os("OMNI_NO_CONFIG_WRITE").is_some_and(|value| value != "0");
+    let api_client = if no_config_write {
+        ApiClient::without_config_writes()
+    } else {
+        ApiClient::new()
+    };
+    let mut ui = PremiumUI::new(api_client)
+        .with_debug(cli.get_flag("debug") || verbosity >= 2)
+        .with_verbosity(verbosity)
+        .with_cwd(cwd)
+        .with_config_path(config_path)
+        .with_output(output)
+        .with_selectors(selectors)
+        .with_wait(wait_timeout, wait_json)
+        .with_quiet(cli.get_flag("quiet"));
+    if ui.api_client.config_loaded && !ui.quiet {
+        if let Some(path) = &ui.api_client.config_path {
+            eprintln!("Loaded configuration from {:?}", path);
+        }
+    }
+    if let Some(api_url) = cli.get_one::<String>("api-url") {
+        ui.api_client.base_url = normalize_base_url(api_url)?;
+    }
+    if cli.get_flag("refresh-metadata") {
+        ui.api_client.refresh_metadata();
+    }
+
+    // Nothing runs with --explain, so there's nothing to audit or notify about
+    if cli.get_flag("explain") {
+        return ui.explain_command(&cli);
+    }
+
+    let audited = if cli.get_flag("no-audit") {
+        None
+    } else {
+        audited_command(&cli)
+    };
+    let notify = cli.get_flag("notify") || ui.api_client.get_setting_or("notify", false);
+    let result = run(&mut ui, &cli).await;
+    if let Some(audited) = audited {
+        ui.record_audit(audited, &result);
+    }
+    if let Some(command) = long_running_command(&cli).filter(|_| notify) {
+        ui.notify_completion(&command, &result);
+    }
+    // Exiting only here, once the audit and notification are done
+    let exit_code = result?;
+    if exit_code != 0 {
+        std::process::exit(exit_code);
+    }
+    Ok(())
+}
+
+// Run the command in `cli`, returning the process exit code it asks for
+async fn run(ui: &mut PremiumUI, cli: &ArgMatches) -> anyhow::Result<i32> {
+    let mut exit_code = 0;
     match cli.subcommand() {
         // OmniOrchestrator commands
-        Some(("init", _)) => ui.init_environment().await?,
+        Some(("init", init_matches)) => ui.init_environment(init_matches).await?,
         Some(("hosts", _)) => ui.list_ssh_hosts().await?,
+        Some(("status", status_matches)) if status_matches.get_flag("watch") => {
+            let interval = *status_matches.get_one::<u64>("interval").unwrap();
+            ui.status_watch(std::time::Duration::from_secs(interval))
+                .await?
+        }
         Some(("status", _)) => ui.status_interactive().await?,
+        Some(("login", login_matches)) => {
+            ui.login(
+                login_matches.get_one::<String>("token").map(String::as_str),
+                login_matches
+                    .get_one::<String>("username")
+                    .map(String::as_str),
+            )
+            .await?
+        }
+        Some(("logout", _)) => ui.logout()?,
+        Some(("health", health_matches)) => {
+            let health = ui.health(health_matches.get_flag("json")).await?;
+            exit_code = health.exit_code();
+        }
 
         // Application deployment commands
-        Some(("up", _)) => ui.deploy_interactive().await?,
-        Some(("push", _)) => ui.push_interactive().await?,
-        Some(("scale", _)) => ui.scale_interactive().await?,
-        Some(("logs", _)) => ui.logs_interactive().await?,
-        Some(("rollback", _)) => ui.rollback_interactive().await?,
+        Some(("up", up_matches)) => ui.deploy_interactive(up_matches).await?,
+        Some(("push", push_matches)) => ui.push_interactive(push_matches).await?,
+        Some(("scale", scale_matches)) => match scale_matches.subcommand() {
+            Some(("show", show_matches)) => {
+                ui.scale_show(
+                    show_matches.get_one::<String>("app").unwrap(),
+                    show_matches.get_flag("json"),
+                )
+                .await?
+            }
+            _ => {
+                ui.scale_interactive(
+                    scale_matches.get_one::<String>("app").map(String::as_str),
+                    scale_matches
+                        .get_one::<String>("component")
+                        .map(String::as_str),
+                    scale_matches.get_one::<u32>("replicas").copied(),
+                )
+                .await?
+            }
+        },
+        Some(("apps", subcommand)) => match subcommand.subcommand() {
+            Some(("rename", rename_matches)) => {
+                ui.apps_rename(
+                    rename_matches.get_one::<String>("old").unwrap(),
+                    rename_matches.get_one::<String>("new").unwrap(),
+                    rename_matches.get_flag("yes"),
+                )
+                .await?
+            }
+            Some(("restart", restart_matches)) => {
+                ui.apps_restart(
+                    restart_matches.get_one::<String>("app").unwrap(),
+                    restart_matches
+                        .get_one::<String>("component")
+                        .map(String::as_str),
+                    restart_matches.get_flag("rolling"),
+                    restart_matches.get_flag("yes"),
+                )
+                .await?
+            }
+            Some(("logs", logs_matches)) => {
+                ui.apps_logs(
+                    logs_matches.get_one::<String>("app").unwrap(),
+                    logs_matches
+                        .get_one::<String>("component")
+                        .map(String::as_str),
+                    *logs_matches.get_one::<usize>("tail").unwrap(),
+                    *logs_matches.get_one::<usize>("parallel").unwrap(),
+                )
+                .await?
+            }
+            _ => println!(
+                "{}",
+                style("Use 'omni apps --help' for available commands").yellow()
+            ),
+        },
+        Some(("context", subcommand)) => match subcommand.subcommand() {
+            Some(("info", _)) | None => ui.context_info().await?,
+            _ => println!(
+                "{}",
+                style("Use 'omni context --help' for available commands").yellow()
+            ),
+        },
+        Some(("prune", subcommand)) => match subcommand.subcommand() {
+            Some(("releases", prune_matches)) => {
+                ui.prune_releases(
+                    prune_matches.get_one::<String>("app").unwrap(),
+                    *prune_matches.get_one::<usize>("keep").unwrap(),
+                    prune_matches.get_flag("dry-run"),
+                    prune_matches.get_flag("yes"),
+                )
+                .await?
+            }
+            Some(("backups", prune_matches)) => {
+                ui.prune_backups(
+                    prune_matches.get_one::<usize>("keep").copied(),
+                    prune_matches
+                        .get_one::<chrono::Duration>("older-than")
+                        .copied(),
+                    prune_matches.get_flag("dry-run"),
+                    prune_matches.get_flag("yes"),
+                )
+                .await?
+            }
+            _ => println!(
+                "{}",
+                style("Use 'omni prune --help' for available commands").yellow()
+            ),
+        },
+        Some(("releases", subcommand)) => match subcommand.subcommand() {
+            Some(("diff", diff_matches)) => {
+                ui.releases_diff(
+                    diff_matches.get_one::<String>("app").unwrap(),
+                    diff_matches.get_one::<String>("release-a").unwrap(),
+                    diff_matches.get_one::<String>("release-b").unwrap(),
+                    diff_matches.get_flag("json"),
+                )
+                .await?
+            }
+            _ => println!(
+                "{}",
+                style("Use 'omni releases --help' for available commands").yellow()
+            ),
+        },
+        Some(("registry", subcommand)) => match subcommand.subcommand() {
+            Some(("login", login_matches)) => {
+                ui.registry_login(login_matches.get_one::<String>("url").unwrap())
+                    .await?
+            }
+            Some(("logout", logout_matches)) => {
+                ui.registry_logout(logout_matches.get_one::<String>("url").unwrap())
+                    .await?
+            }
+            Some(("list", _)) => ui.registry_list().await?,
+            Some(("set-default", default_matches)) => {
+                ui.registry_set_default(default_matches.get_one::<String>("url").unwrap())
+                    .await?
+            }
+            _ => println!(
+                "{}",
+                style("Use 'omni registry --help' for available commands").yellow()
+            ),
+        },
+        Some(("logs", logs_matches)) => ui.logs_interactive(logs_matches).await?,
+        Some(("rollback", rollback_matches)) => {
+            ui.rollback_interactive(
+                rollback_matches
+                    .get_one::<String>("app")
+                    .map(String::as_str),
+                rollback_matches
+                    .get_one::<String>("version")
+                    .map(String::as_str),
+            )
+            .await?
+        }
 
         // Service management
         Some(("service", subcommand)) => match subcommand.subcommand() {
-            Some(("restart", _)) => {
-                println!("{}", style("Service restart not yet implemented").yellow())
-            }
-            Some(("stop", _)) => println!("{}", style("Service stop not yet implemented").yellow()),
-            Some(("start", _)) => {
-                println!("{}", style("Service start not yet implemented").yellow())
+            Some((action @ ("restart" | "stop" | "start"), service_matches)) => {
+                let host = service_matches.get_one::<String>("host").unwrap();
+                let service = service_matches.get_one::<String>("service").unwrap();
+                match action {
+                    "restart" => ui.restart_service(host, service).await?,
+                    "stop" => ui.stop_service(host, service).await?,
+                    _ => ui.start_service(host, service).await?,
+                }
             }
             _ => println!(
                 "{}",
@@ -205,10 +1315,27 @@ async fn main() -> anyhow::Result<()> {
 
         // Backup management
         Some(("backup", subcommand)) => match subcommand.subcommand() {
-            Some(("now", _)) => println!("{}", style("Backup now not yet implemented").yellow()),
-            Some(("list", _)) => println!("{}", style("Backup list not yet implemented").yellow()),
-            Some(("restore", _)) => {
-                println!("{}", style("Backup restore not yet implemented").yellow())
+            Some(("now", _)) => ui.trigger_backup().await?,
+            Some(("list", _)) => ui.backup_list().await?,
+            Some(("restore", restore_matches)) => {
+                ui.restore_backup(restore_matches.get_one::<String>("id").unwrap())
+                    .await?
+            }
+            Some(("download", download_matches)) => {
+                ui.download_backup(
+                    download_matches.get_one::<String>("id").unwrap(),
+                    download_matches
+                        .get_one::<String>("output")
+                        .map(String::as_str),
+                )
+                .await?
+            }
+            Some(("verify", verify_matches)) => {
+                ui.verify_backup(
+                    verify_matches.get_one::<String>("id").unwrap(),
+                    verify_matches.get_one::<String>("file").map(String::as_str),
+                )
+                .await?
             }
             _ => println!(
                 "{}",
@@ -216,11 +1343,112 @@ async fn main() -> anyhow::Result<()> {
             ),
         },
 
+        Some(("doctor", _)) => ui.doctor().await?,
+
+        // Connectivity check
+        Some(("ping", ping_matches)) => {
+            ui.ping(*ping_matches.get_one::<u32>("count").unwrap())
+                .await?
+        }
+
+        // Periodically re-run another command
+        Some(("watch", watch_matches)) => watch(ui, watch_matches).await?,
+
+        // Audit log
+        Some(("audit", subcommand)) => match subcommand.subcommand() {
+            Some(("log", log_matches)) => {
+                ui.audit_log(*log_matches.get_one::<usize>("limit").unwrap())
+                    .await?
+            }
+            _ => println!(
+                "{}",
+                style("Use 'omni audit --help' for available commands").yellow()
+            ),
+        },
+
+        Some(("host", subcommand)) => match subcommand.subcommand() {
+            Some(("add", _)) => ui.host_add().await?,
+            Some(("remove", remove_matches)) => {
+                ui.host_remove(
+                    remove_matches.get_one::<String>("name").unwrap(),
+                    remove_matches.get_flag("yes"),
+                )
+                .await?
+            }
+            Some(("edit", edit_matches)) => {
+                ui.config_edit_host(edit_matches.get_one::<String>("name").unwrap())
+                    .await?
+            }
+            _ => println!(
+                "{}",
+                style("Use 'omni host --help' for available commands").yellow()
+            ),
+        },
+
         // Configuration management
         Some(("config", subcommand)) => match subcommand.subcommand() {
             Some(("view", _)) => ui.config_view().await?,
             Some(("edit", _)) => ui.config_edit().await?,
             Some(("reset", _)) => ui.config_reset().await?,
+            Some(("edit-host", edit_host_matches)) => {
+                ui.config_edit_host(edit_host_matches.get_one::<String>("name").unwrap())
+                    .await?
+            }
+            Some(("set", set_matches)) => {
+                ui.config_set(
+                    set_matches.get_one::<String>("key").unwrap(),
+                    set_matches.get_one::<String>("value").unwrap(),
+                )
+                .await?
+            }
+            Some(("get", get_matches)) => {
+                ui.config_get(get_matches.get_one::<String>("key").unwrap())
+                    .await?
+            }
+            Some(("unset", unset_matches)) => {
+                ui.config_unset(unset_matches.get_one::<String>("key").unwrap())
+                    .await?
+            }
+            Some(("clear-settings", clear_matches)) => {
+                ui.config_clear_settings(
+                    clear_matches
+                        .get_one::<String>("prefix")
+                        .map(String::as_str),
+                )
+                .await?
+            }
+            Some(("set-default-env", default_env_matches)) => {
+                ui.config_set_default_env(
+                    default_env_matches.get_one::<String>("env").unwrap(),
+                    default_env_matches.get_flag("allow-prod-default"),
+                )
+                .await?
+            }
+            Some(("set-secret", secret_matches)) => {
+                ui.config_set_secret(
+                    secret_matches.get_one::<String>("name").unwrap(),
+                    secret_matches
+                        .get_one::<String>("secret-value")
+                        .map(String::as_str),
+                )
+                .await?
+            }
+            Some(("unset-secret", secret_matches)) => {
+                ui.config_unset_secret(secret_matches.get_one::<String>("name").unwrap())
+                    .await?
+            }
+            Some(("list-secrets", _)) => ui.config_list_secrets().await?,
+            Some(("push", push_matches)) => {
+                ui.config_push(
+                    push_matches.get_flag("dry-run"),
+                    push_matches.get_flag("yes"),
+                )
+                .await?
+            }
+            Some(("schema", schema_matches)) => {
+                ui.config_schema(schema_matches.get_one::<String>("out").map(String::as_str))
+                    .await?
+            }
             _ => ui.config_view().await?,
         },
 
@@ -238,6 +1466,14 @@ async fn main() -> anyhow::Result<()> {
             ui.display_welcome()?;
         }
 
+        // Guided menu for interactive sessions, unless turned off with the
+        // `ui.interactive_menu` setting
+        None if console::user_attended()
+            && ui.api_client.get_setting_or("ui.interactive_menu", true) =>
+        {
+            exit_code = main_menu(ui, cli).await?
+        }
+
         // Help menu
         _ => {
             ui.display_welcome()?;
@@ -256,11 +1492,21 @@ async fn main() -> anyhow::Result<()> {
                 style("hosts").cyan(),
                 style("List configured SSH hosts").dim()
             );
+            println!(
+                "  {} {}",
+                style("host").cyan(),
+                style("Add, remove or edit SSH hosts").dim()
+            );
             println!(
                 "  {} {}",
                 style("status").cyan(),
                 style("Check OmniOrchestrator status").dim()
             );
+            println!(
+                "  {} {}",
+                style("health").cyan(),
+                style("Summarize platform and app health").dim()
+            );
             println!(
                 "  {} {}",
                 style("service").cyan(),
@@ -271,6 +1517,31 @@ async fn main() -> anyhow::Result<()> {
                 style("backup").cyan(),
                 style("Manage backup operations").dim()
             );
+            println!(
+                "  {} {}",
+                style("context").cyan(),
+                style("Show which cloud, API and identity are in use").dim()
+            );
+            println!(
+                "  {} {}",
+                style("doctor").cyan(),
+                style("Diagnose config, connectivity and login problems").dim()
+            );
+            println!(
+                "  {} {}",
+                style("login").cyan(),
+                style("Log in to the Omni API").dim()
+            );
+            println!(
+                "  {} {}",
+                style("prune").cyan(),
+                style("Delete old releases and backups").dim()
+            );
+            println!(
+                "  {} {}",
+                style("audit").cyan(),
+                style("Inspect the local audit log").dim()
+            );
 
             println!("\n{}", style("APPLICATION COMMANDS:").magenta().bold());
             println!(
@@ -278,6 +1549,16 @@ async fn main() -> anyhow::Result<()> {
                 style("up").cyan(),
                 style("Deploy your application").dim()
             );
+            println!(
+                "  {} {}",
+                style("apps").cyan(),
+                style("Manage deployed applications").dim()
+            );
+            println!(
+                "  {} {}",
+                style("releases").cyan(),
+                style("Inspect application releases").dim()
+            );
             println!(
                 "  {} {}",
                 style("push").cyan(),
@@ -303,6 +1584,11 @@ async fn main() -> anyhow::Result<()> {
                 style("config").cyan(),
                 style("Manage application configuration").dim()
             );
+            println!(
+                "  {} {}",
+                style("completions").cyan(),
+                style("Print a shell completion script").dim()
+            );
             println!(
                 "\n{}",
                 style("Use --help with any command for more information.").yellow()
@@ -310,5 +1596,96 @@ async fn main() -> anyhow::Result<()> {
         }
     }
 
+    Ok(exit_code)
+}
+
+// Re-run a read-only command every `--interval` seconds, redrawing the
+// screen between runs, until Ctrl-C
+async fn watch(ui: &mut PremiumUI, matches: &ArgMatches) -> anyhow::Result<()> {
+    let interval = *matches.get_one::<u64>("interval").unwrap();
+    let args: Vec<String> = matches
+        .get_many::<String>("command")
+        .unwrap()
+        .cloned()
+        .collect();
+    let watched = build_cli()
+        .try_get_matches_from(std::iter::once("omni".to_string()).chain(args.iter().cloned()))?;
+
+    match watched.subcommand_name() {
+        None | Some("watch") => anyhow::bail!("'{}' can't be watched", args.join(" ")),
+        _ if is_mutating_command(&watched) => {
+            anyhow::bail!("Refusing to watch '{}': it changes state", args.join(" "))
+        }
+        _ => {}
+    }
+
+    loop {
+        ui.term.clear_screen()?;
+        println!(
+            "{}",
+            style(format!(
+                "Every {}s: omni {}    {}",
+                interval,
+                args.join(" "),
+                chrono::Local::now().format("%Y-%m-%d %H:%M:%S")
+            ))
+            .dim()
+        );
+
+        if let Err(err) = Box::pin(run(ui, &watched)).await {
+            println!("{}", style(format!("Error: {}", err)).red());
+        }
+
+        tokio::select! {
+            _ = tokio::time::sleep(std::time::Duration::from_secs(interval)) => {}
+            _ = tokio::signal::ctrl_c() => break,
+        }
+    }
+
     Ok(())
 }
+
+// Top-level commands offered by the interactive menu, with the arguments
+// each one is launched with
+const MENU_COMMANDS: &[(&str, &[&str])] = &[
+    ("Initialize cloud environment", &["init"]),
+    ("Deploy your application", &["up"]),
+    ("Check OmniOrchestrator status", &["status"]),
+    ("List configured SSH hosts", &["hosts"]),
+    ("Add an SSH host", &["host", "add"]),
+    ("View application logs", &["logs"]),
+    ("Scale application components", &["scale"]),
+    ("Push images to container registry", &["push"]),
+    ("Rollback to previous version", &["rollback"]),
+    ("View configuration", &["config", "view"]),
+];
+
+// Let the user pick a command and launch its interactive flow
+async fn main_menu(ui: &mut PremiumUI, cli: &ArgMatches) -> anyhow::Result<i32> {
+    ui.display_welcome()?;
+
+    let mut items: Vec<&str> = MENU_COMMANDS.iter().map(|(label, _)| *label).collect();
+    items.push("Exit");
+    let selection = dialoguer::Select::with_theme(&ui.theme)
+        .with_prompt("What would you like to do?")
+        .items(&items)
+        .default(0)
+        .interact()?;
+
+    let Some((_, args)) = MENU_COMMANDS.get(selection) else {
+        return Ok(0);
+    };
+    let chosen =
+        build_cli().try_get_matches_from(std::iter::once("omni").chain(args.iter().copied()))?;
+
+    let audited = if cli.get_flag("no-audit") {
+        None
+    } else {
+        audited_command(&chosen)
+    };
+    let result = Box::pin(run(ui, &chosen)).await;
+    if let Some(audited) = audited {
+        ui.record_audit(audited, &result);
+    }
+    result
+}
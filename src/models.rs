@@ -1,6 +1,8 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use tabled::Tabled;
 
-#[derive(Debug, Tabled)]
+#[derive(Debug, Serialize, Tabled)]
 pub struct ComponentStatus {
     #[tabled(rename = "Component")]
     pub name: String,
@@ -13,3 +15,147 @@ pub struct ComponentStatus {
     #[tabled(rename = "Memory")]
     pub memory: String,
 }
+
+// Deployed state of an app as reported by `GET /apps/{name}`
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AppState {
+    #[serde(default)]
+    pub release: Option<String>,
+    #[serde(default)]
+    pub components: Vec<AppComponent>,
+    #[serde(default)]
+    pub endpoints: BTreeMap<String, String>,
+}
+
+// Progress of a release as reported by
+// `GET /apps/{name}/releases/{release}/status`
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ReleaseStatus {
+    // uploading, build, push, configure or start, then succeeded or failed
+    pub phase: String,
+    #[serde(default)]
+    pub message: Option<String>,
+    // While uploading, the bytes received so far
+    #[serde(default)]
+    pub received_bytes: Option<u64>,
+}
+
+// Progress of an image push as reported by
+// `GET /apps/{name}/images/{tag}/push/status`
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PushStatus {
+    // pushing, then succeeded or failed
+    pub status: String,
+    #[serde(default)]
+    pub message: Option<String>,
+    #[serde(default)]
+    pub layers: Vec<LayerProgress>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct LayerProgress {
+    pub id: String,
+    #[serde(default)]
+    pub status: String,
+    #[serde(default)]
+    pub current_bytes: u64,
+    #[serde(default)]
+    pub total_bytes: Option<u64>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AppComponent {
+    pub name: String,
+    #[serde(default)]
+    pub version: Option<String>,
+    #[serde(default)]
+    pub status: String,
+    #[serde(default)]
+    pub replicas: u32,
+    #[serde(default)]
+    pub ready_replicas: u32,
+    #[serde(default)]
+    pub cpu: String,
+    #[serde(default)]
+    pub memory: String,
+    // Where the component's replicas run
+    #[serde(default)]
+    pub placements: Vec<ComponentPlacement>,
+}
+
+impl AppComponent {
+    // Whether the component's status reports a failure, e.g. "CrashLoop"
+    pub fn is_failed(&self) -> bool {
+        let status = self.status.to_lowercase();
+        ["fail", "error", "crash"].iter().any(|marker| status.contains(marker))
+    }
+}
+
+// A host/service running (part of) a component
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ComponentPlacement {
+    pub host: String,
+    pub service: String,
+}
+
+// An entry of `GET /apps`
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AppSummary {
+    pub name: String,
+    #[serde(default)]
+    pub status: String,
+}
+
+// A release of an app as listed by `GET /apps/{name}/releases`
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Release {
+    pub id: String,
+    #[serde(default)]
+    pub version: Option<String>,
+    #[serde(default)]
+    pub status: String,
+    #[serde(default)]
+    pub created_at: Option<String>,
+    #[serde(default)]
+    pub started_at: Option<String>,
+}
+
+// Scaling state of an app as reported by `GET /apps/{name}/scale`
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AppScale {
+    #[serde(default)]
+    pub components: Vec<ComponentScale>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ComponentScale {
+    pub name: String,
+    #[serde(default)]
+    pub desired_replicas: u32,
+    #[serde(default)]
+    pub ready_replicas: u32,
+    #[serde(default)]
+    pub autoscaling: Option<AutoscalingPolicy>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AutoscalingPolicy {
+    pub min_replicas: u32,
+    pub max_replicas: u32,
+    #[serde(default)]
+    pub target_cpu_percent: Option<u32>,
+    #[serde(default)]
+    pub target_memory_percent: Option<u32>,
+}
+
+impl From<&AppComponent> for ComponentStatus {
+    fn from(component: &AppComponent) -> Self {
+        Self {
+            name: component.name.clone(),
+            status: component.status.clone(),
+            replicas: format!("{}/{}", component.ready_replicas, component.replicas),
+            cpu: component.cpu.clone(),
+            memory: component.memory.clone(),
+        }
+    }
+}
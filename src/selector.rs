@@ -0,0 +1,107 @@
+// selector.rs
+// Client-side row filtering for list output, e.g. `--selector status=error`
+use anyhow::{bail, Result};
+use serde_json::Value;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SelectorOp {
+    Eq,
+    NotEq,
+    Gt,
+    Lt,
+    Contains,
+}
+
+// A single `<field><op><value>` expression
+#[derive(Debug, Clone)]
+pub struct Selector {
+    field: String,
+    op: SelectorOp,
+    value: String,
+}
+
+impl Selector {
+    pub fn parse(expr: &str) -> Result<Self> {
+        // `!=` is checked before `=` so it isn't read as an equality on "field!"
+        let operators = [
+            (" contains ", SelectorOp::Contains),
+            ("!=", SelectorOp::NotEq),
+            ("=", SelectorOp::Eq),
+            (">", SelectorOp::Gt),
+            ("<", SelectorOp::Lt),
+        ];
+
+        for (token, op) in operators {
+            if let Some((field, value)) = expr.split_once(token) {
+                let field = field.trim();
+                if field.is_empty() {
+                    break;
+                }
+                return Ok(Self {
+                    field: field.to_string(),
+                    op,
+                    value: value.trim().to_string(),
+                });
+            }
+        }
+
+        bail!(
+            "Invalid selector '{}': expected <field><op><value> with =, !=, >, < or contains, e.g. status=error",
+            expr
+        )
+    }
+
+    fn matches(&self, row: &Value) -> bool {
+        let Some(actual) = lookup(row, &self.field) else {
+            // A row without the field can only satisfy a negative match
+            return self.op == SelectorOp::NotEq;
+        };
+        let actual = as_text(actual);
+
+        match self.op {
+            SelectorOp::Eq => actual.eq_ignore_ascii_case(&self.value),
+            SelectorOp::NotEq => !actual.eq_ignore_ascii_case(&self.value),
+            SelectorOp::Contains => actual.to_lowercase().contains(&self.value.to_lowercase()),
+            SelectorOp::Gt | SelectorOp::Lt => {
+                let ordering = match (leading_number(&actual), leading_number(&self.value)) {
+                    (Some(a), Some(b)) => a.partial_cmp(&b),
+                    _ => Some(actual.as_str().cmp(self.value.as_str())),
+                };
+                match self.op {
+                    SelectorOp::Gt => ordering == Some(std::cmp::Ordering::Greater),
+                    _ => ordering == Some(std::cmp::Ordering::Less),
+                }
+            }
+        }
+    }
+}
+
+// Follow a dotted path (e.g. `resources.cpu`) into a row
+fn lookup<'a>(row: &'a Value, field: &str) -> Option<&'a Value> {
+    field
+        .split('.')
+        .try_fold(row, |value, key| value.as_object()?.get(key))
+}
+
+fn as_text(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        Value::Null => String::new(),
+        other => other.to_string(),
+    }
+}
+
+// Numeric prefix of a value, so "65%" and "3/3" compare as 65 and 3
+fn leading_number(text: &str) -> Option<f64> {
+    let text = text.trim();
+    let end = text
+        .char_indices()
+        .find(|(i, c)| !(c.is_ascii_digit() || *c == '.' || (*i == 0 && *c == '-')))
+        .map_or(text.len(), |(i, _)| i);
+    text[..end].parse().ok()
+}
+
+// Whether an already-serialized row satisfies every selector
+pub fn matches_all(row: &Value, selectors: &[Selector]) -> bool {
+    selectors.iter().all(|selector| selector.matches(row))
+}
@@ -1,10 +1,42 @@
 use anyhow::Result;
 use console::{style, Term};
-use dialoguer::theme::ColorfulTheme;
-use indicatif::{MultiProgress, ProgressBar, ProgressStyle};
-use spinners::{Spinner, Spinners};
+use serde::Serialize;
+use dialoguer::{theme::ColorfulTheme, Input};
+use indicatif::{MultiProgress, ProgressBar, ProgressDrawTarget, ProgressStyle};
+use spinners::{Spinner, Spinners, Stream};
+use std::path::{Path, PathBuf};
 use std::{thread, time::Duration};
+use tabled::settings::{peaker::PriorityMax, Format, Width};
+use tabled::Table;
 use crate::api_client::ApiClient;
+use crate::selector::Selector;
+
+// Make text safe to place in a table cell: ANSI escape sequences are
+// dropped, line breaks and tabs collapse to a single space, and any other
+// control characters are shown escaped
+pub fn sanitize_cell(text: &str) -> String {
+    let stripped = console::strip_ansi_codes(text);
+    let mut sanitized = String::with_capacity(stripped.len());
+    let mut pending_space = false;
+    for c in stripped.chars() {
+        if matches!(c, '\n' | '\r' | '\t') {
+            pending_space = !sanitized.is_empty();
+            continue;
+        }
+        if pending_space {
+            if !sanitized.ends_with(' ') && c != ' ' {
+                sanitized.push(' ');
+            }
+            pending_space = false;
+        }
+        if c.is_control() {
+            sanitized.extend(c.escape_default());
+        } else {
+            sanitized.push(c);
+        }
+    }
+    sanitized
+}
 
 const LOGO: &str = r#"
     __                      _           _____ __                   __
@@ -17,20 +49,180 @@ const LOGO: &str = r#"
 // Gradient colors (simulated with different shades of blue)
 const GRADIENT_COLORS: [&str; 5] = ["#00c6ff", "#0072ff", "#0057ff", "#0053d4", "#00c6ff"];
 
+// Output chosen with the global -o/--output
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OutputMode {
+    #[default]
+    Table,
+    // Extra columns from extended status fields
+    Wide,
+    Json,
+    Yaml,
+}
+
+impl OutputMode {
+    pub fn from_arg(value: Option<&str>) -> Self {
+        match value {
+            Some("wide") => OutputMode::Wide,
+            Some("json") => OutputMode::Json,
+            Some("yaml") => OutputMode::Yaml,
+            _ => OutputMode::Table,
+        }
+    }
+
+    pub fn is_structured(self) -> bool {
+        matches!(self, OutputMode::Json | OutputMode::Yaml)
+    }
+
+    // Serialize for stdout; plain text only, so it pipes cleanly into jq/yq
+    pub fn render<T: Serialize + ?Sized>(self, value: &T) -> Result<String> {
+        Ok(match self {
+            OutputMode::Yaml => serde_yaml::to_string(value)?,
+            _ => serde_json::to_string_pretty(value)?,
+        })
+    }
+}
+
+// Expand a leading `~` to the home directory
+pub fn expand_home(path: &Path) -> Result<PathBuf> {
+    match path.strip_prefix("~") {
+        Ok(rest) => Ok(dirs::home_dir()
+            .ok_or_else(|| anyhow::anyhow!("Could not determine the home directory"))?
+            .join(rest)),
+        Err(_) => Ok(path.to_path_buf()),
+    }
+}
+
+// Spinner shown while waiting on the server. It draws on stderr so it never
+// ends up in output piped from stdout, and with --quiet it draws nothing.
+pub struct UiSpinner(Option<Spinner>);
+
+impl UiSpinner {
+    pub fn stop(&mut self) {
+        if let Some(spinner) = &mut self.0 {
+            spinner.stop();
+        }
+    }
+
+    pub fn stop_with_message(&mut self, message: String) {
+        if let Some(spinner) = &mut self.0 {
+            spinner.stop_with_message(message);
+        }
+    }
+}
+
 pub struct PremiumUI {
     pub term: Term,
     pub multi_progress: MultiProgress,
     pub theme: ColorfulTheme,
     pub api_client: ApiClient,
+    pub debug: bool,
+    // Count of -v flags; see ApiClient::verbosity
+    pub verbosity: u8,
+    // --quiet: only errors and results are printed
+    pub quiet: bool,
+    pub cwd: Option<PathBuf>,
+    // Cloud config file from --config; None means config/cloud-config.json
+    pub config_path: Option<PathBuf>,
+    pub output: OutputMode,
+    pub selectors: Vec<Selector>,
+    // --wait-timeout and --json for commands that wait on the server
+    pub wait_timeout: Option<Duration>,
+    pub wait_json: bool,
 }
 
 impl PremiumUI {
-    pub fn new() -> Self {
+    pub fn new(api_client: ApiClient) -> Self {
         Self {
             term: Term::stdout(),
             multi_progress: MultiProgress::new(),
             theme: ColorfulTheme::default(),
-            api_client: ApiClient::new(),
+            api_client,
+            debug: false,
+            verbosity: 0,
+            quiet: false,
+            cwd: None,
+            config_path: None,
+            output: OutputMode::default(),
+            selectors: Vec::new(),
+            wait_timeout: None,
+            wait_json: false,
+        }
+    }
+
+    pub fn with_debug(mut self, debug: bool) -> Self {
+        self.debug = debug;
+        self
+    }
+
+    pub fn with_verbosity(mut self, verbosity: u8) -> Self {
+        self.verbosity = verbosity;
+        self.api_client = self.api_client.with_verbosity(verbosity);
+        self
+    }
+
+    pub fn with_quiet(mut self, quiet: bool) -> Self {
+        self.quiet = quiet;
+        if quiet {
+            self.multi_progress.set_draw_target(ProgressDrawTarget::hidden());
+        }
+        self
+    }
+
+    // Warnings and prompts' context, shown even with --quiet. While stdout
+    // carries JSON or YAML output they go to stderr, so it stays parseable.
+    pub fn notice(&self, message: impl std::fmt::Display) {
+        if self.output.is_structured() {
+            eprintln!("{}", message);
+        } else {
+            println!("{}", message);
+        }
+    }
+
+    // Progress lines of a command: notices that --quiet leaves out
+    pub fn progress(&self, message: impl std::fmt::Display) {
+        if !self.quiet {
+            self.notice(message);
+        }
+    }
+
+    // Informational status lines, left out with --quiet
+    pub fn info(&self, message: impl std::fmt::Display) {
+        self.progress(style(message).dim());
+    }
+
+    pub fn with_cwd(mut self, cwd: Option<PathBuf>) -> Self {
+        self.cwd = cwd;
+        self
+    }
+
+    pub fn with_config_path(mut self, config_path: Option<PathBuf>) -> Self {
+        self.config_path = config_path;
+        self
+    }
+
+    pub fn with_output(mut self, output: OutputMode) -> Self {
+        self.output = output;
+        self
+    }
+
+    pub fn with_selectors(mut self, selectors: Vec<Selector>) -> Self {
+        self.selectors = selectors;
+        self
+    }
+
+    pub fn with_wait(mut self, timeout: Option<Duration>, json: bool) -> Self {
+        self.wait_timeout = timeout;
+        self.wait_json = json;
+        self
+    }
+
+    // Resolve a relative path against --cwd, leaving absolute paths untouched
+    pub fn resolve_path(&self, path: impl AsRef<Path>) -> PathBuf {
+        let path = path.as_ref();
+        match &self.cwd {
+            Some(cwd) if path.is_relative() => cwd.join(path),
+            _ => path.to_path_buf(),
         }
     }
 
@@ -103,8 +295,39 @@ impl PremiumUI {
         Ok(())
     }
 
-    pub fn create_spinner(&self, message: &str) -> Spinner {
-        Spinner::with_timer(Spinners::Dots12, message.into())
+    // Ask the user to type `expected` back before a sensitive operation
+    pub fn confirm_typed(&self, prompt: &str, expected: &str) -> Result<bool> {
+        let input: String = Input::with_theme(&self.theme)
+            .with_prompt(format!("{} Type '{}' to confirm", prompt, expected))
+            .allow_empty(true)
+            .interact_text()?;
+        Ok(input.trim() == expected)
+    }
+
+    // Render a table capped to the terminal width, shrinking the widest
+    // columns first. Output that isn't going to a terminal is left as is.
+    // Cells are sanitized first since they often hold server-provided text.
+    pub fn render_table(&self, mut table: Table) -> String {
+        table.with(Format::content(sanitize_cell));
+        if let Some((_, width)) = self.term.size_checked() {
+            table.with(
+                Width::truncate(width as usize)
+                    .suffix("…")
+                    .priority(PriorityMax::right()),
+            );
+        }
+        table.to_string()
+    }
+
+    pub fn create_spinner(&self, message: &str) -> UiSpinner {
+        if self.quiet {
+            return UiSpinner(None);
+        }
+        UiSpinner(Some(Spinner::with_timer_and_stream(
+            Spinners::Dots12,
+            message.into(),
+            Stream::Stderr,
+        )))
     }
 
     pub fn create_progress_bar(&self, len: u64, message: &str) -> ProgressBar {
@@ -117,6 +340,17 @@ impl PremiumUI {
         pb
     }
 
+    // Progress bar for transfers where the total size in bytes is known
+    pub fn create_bytes_progress_bar(&self, total_bytes: u64, message: &str) -> ProgressBar {
+        let pb = self.multi_progress.add(ProgressBar::new(total_bytes));
+        pb.set_style(ProgressStyle::default_bar()
+            .template("{spinner:.green} [{elapsed_precise}] {bar:40.cyan/blue} {bytes}/{total_bytes} ({bytes_per_sec}, ETA {eta}) {msg}")
+            .unwrap()
+            .progress_chars("=>-"));
+        pb.set_message(message.to_string());
+        pb
+    }
+
     // New method for displaying cloud-themed progress
     pub fn deploy_with_progress(&self, steps: u64) -> Result<()> {
         let pb = self.create_progress_bar(steps, "Deploying to cloud");
@@ -140,3 +374,36 @@ impl PremiumUI {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tabled::Tabled;
+
+    #[derive(Tabled)]
+    struct ServiceRow {
+        name: String,
+        status: String,
+    }
+
+    #[test]
+    fn sanitize_cell_collapses_line_breaks_and_strips_ansi() {
+        assert_eq!(sanitize_cell("api\nworker"), "api worker");
+        assert_eq!(sanitize_cell("failed:\r\n\tdisk full\n"), "failed: disk full");
+        assert_eq!(sanitize_cell("\x1b[31mDown\x1b[0m"), "Down");
+        assert_eq!(sanitize_cell("bell\x07"), "bell\\u{7}");
+    }
+
+    #[test]
+    fn table_with_an_embedded_newline_keeps_one_line_per_row() {
+        let mut table = Table::new(vec![ServiceRow {
+            name: "api\nserver".to_string(),
+            status: "Running\r\n".to_string(),
+        }]);
+        table.with(Format::content(sanitize_cell));
+        let rendered = table.to_string();
+        // Top border, header, separator, row, bottom border
+        assert_eq!(rendered.lines().count(), 5, "{}", rendered);
+        assert!(rendered.contains("api server"), "{}", rendered);
+    }
+}